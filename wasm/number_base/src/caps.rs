@@ -0,0 +1,256 @@
+//! Softcaps and hardcaps, which slow down or stop the growth of a value past a threshold.
+//!
+//! A [`Cap`] maps a raw value, such as production or a multiplier before balancing, to the
+//! effective value the game uses. Every cap leaves values up to its threshold unchanged, is
+//! continuous, and never decreases, so a larger raw value is never worth less. Each one can
+//! also be inverted, to show which raw value an effective value needs or to search for
+//! purchases by their effect.
+//!
+//! Caps compose with [`Cap::then`] and [`Tiered`]. A later cap sees the output of the earlier
+//! ones, so its threshold is an effective value.
+//!
+//! ```ignore
+//! use number_base::caps::{Cap, Hardcap, PowerSoftcap};
+//!
+//! let cap = PowerSoftcap::new(Decimal::new(1e3), Decimal::new(0.5))
+//!     .then(Hardcap::new(Decimal::new(1e6)));
+//! let effective = cap.apply(&Decimal::new(1e5)); // 1e4
+//! let raw = cap.invert(&effective); // 1e5
+//! ```
+
+use crate::BaseNumber;
+
+/// A continuous, non-decreasing function from raw values to effective values.
+pub trait Cap<N: BaseNumber> {
+    /// Returns the effective value of a raw value.
+    fn apply(&self, value: &N) -> N;
+
+    /// Returns the smallest raw value whose effective value is `value`, or infinity if no raw
+    /// value reaches it.
+    fn invert(&self, value: &N) -> N;
+
+    /// Returns a cap which applies this cap and then `next` to its result.
+    fn then<C: Cap<N>>(self, next: C) -> Chain<Self, C>
+    where
+        Self: Sized,
+    {
+        Chain {
+            first: self,
+            second: next,
+        }
+    }
+}
+
+impl<N: BaseNumber, C: Cap<N> + ?Sized> Cap<N> for Box<C> {
+    fn apply(&self, value: &N) -> N {
+        (**self).apply(value)
+    }
+
+    fn invert(&self, value: &N) -> N {
+        (**self).invert(value)
+    }
+}
+
+/// Stops a value at a limit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hardcap<N> {
+    /// The largest effective value.
+    pub limit: N,
+}
+
+impl<N: BaseNumber> Hardcap<N> {
+    pub fn new(limit: N) -> Self {
+        Hardcap { limit }
+    }
+}
+
+impl<N: BaseNumber> Cap<N> for Hardcap<N> {
+    fn apply(&self, value: &N) -> N {
+        if BaseNumber::gt(value, &self.limit) {
+            self.limit.clone()
+        } else {
+            value.clone()
+        }
+    }
+
+    fn invert(&self, value: &N) -> N {
+        if BaseNumber::gt(value, &self.limit) {
+            N::INFINITY
+        } else {
+            value.clone()
+        }
+    }
+}
+
+/// Raises the part of a value past `start` to a power, as `start * (value / start)^power`.
+///
+/// A power of 0.5 turns every 100× of raw growth past the start into 10×. The power must be
+/// in `(0, 1]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PowerSoftcap<N> {
+    /// The value past which the softcap starts, which must be positive.
+    pub start: N,
+    /// The power the growth past the start is raised to.
+    pub power: N,
+}
+
+impl<N: BaseNumber> PowerSoftcap<N> {
+    pub fn new(start: N, power: N) -> Self {
+        PowerSoftcap { start, power }
+    }
+}
+
+impl<N: BaseNumber> Cap<N> for PowerSoftcap<N> {
+    fn apply(&self, value: &N) -> N {
+        if BaseNumber::lte(value, &self.start) {
+            return value.clone();
+        }
+
+        let capped = (value.clone() / &self.start).pow(&self.power) * &self.start;
+        at_least(capped, &self.start)
+    }
+
+    fn invert(&self, value: &N) -> N {
+        if BaseNumber::lte(value, &self.start) {
+            return value.clone();
+        }
+
+        let raw = (value.clone() / &self.start).pow(&self.power.recip()) * &self.start;
+        at_least(raw, &self.start)
+    }
+}
+
+/// Slows a value past `start` to logarithmic growth, as `start * (1 + ln(value / start))`.
+///
+/// The growth starts out at the same rate as the raw value's, so the softcap has no kink.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogSoftcap<N> {
+    /// The value past which the softcap starts, which must be positive.
+    pub start: N,
+}
+
+impl<N: BaseNumber> LogSoftcap<N> {
+    pub fn new(start: N) -> Self {
+        LogSoftcap { start }
+    }
+}
+
+impl<N: BaseNumber> Cap<N> for LogSoftcap<N> {
+    fn apply(&self, value: &N) -> N {
+        if BaseNumber::lte(value, &self.start) {
+            return value.clone();
+        }
+
+        let capped = ((value.clone() / &self.start).ln() + N::ONE) * &self.start;
+        at_least(capped, &self.start)
+    }
+
+    fn invert(&self, value: &N) -> N {
+        if BaseNumber::lte(value, &self.start) {
+            return value.clone();
+        }
+
+        let raw = (value.clone() / &self.start - N::ONE).exp() * &self.start;
+        at_least(raw, &self.start)
+    }
+}
+
+/// Takes a root of the exponent of a value past `start`, as
+/// `10^(log10(start) * (log10(value) / log10(start))^power)`.
+///
+/// This is the softcap for values which grow by orders of magnitude: a power of 0.5 turns
+/// `start^100` into `start^10`. The start must be greater than 1, and the power in `(0, 1]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExponentRootSoftcap<N> {
+    /// The value past which the softcap starts.
+    pub start: N,
+    /// The power the exponent past the start's is raised to.
+    pub power: N,
+}
+
+impl<N: BaseNumber> ExponentRootSoftcap<N> {
+    pub fn new(start: N, power: N) -> Self {
+        ExponentRootSoftcap { start, power }
+    }
+
+    fn scale_exponent(&self, value: &N, power: &N) -> N {
+        let exponent = self.start.log10();
+        let scaled = (value.log10() / &exponent).pow(power) * exponent;
+        at_least(scaled.pow10(), &self.start)
+    }
+}
+
+impl<N: BaseNumber> Cap<N> for ExponentRootSoftcap<N> {
+    fn apply(&self, value: &N) -> N {
+        if BaseNumber::lte(value, &self.start) {
+            value.clone()
+        } else {
+            self.scale_exponent(value, &self.power)
+        }
+    }
+
+    fn invert(&self, value: &N) -> N {
+        if BaseNumber::lte(value, &self.start) {
+            value.clone()
+        } else {
+            self.scale_exponent(value, &self.power.recip())
+        }
+    }
+}
+
+/// Applies one cap and then another to its result, as made by [`Cap::then`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Chain<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<N: BaseNumber, A: Cap<N>, B: Cap<N>> Cap<N> for Chain<A, B> {
+    fn apply(&self, value: &N) -> N {
+        self.second.apply(&self.first.apply(value))
+    }
+
+    fn invert(&self, value: &N) -> N {
+        self.first.invert(&self.second.invert(value))
+    }
+}
+
+/// Applies a list of caps in order, such as softcaps which grow harsher past each threshold.
+///
+/// Use `Tiered<Box<dyn Cap<N>>>` to mix kinds of caps.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tiered<C> {
+    /// The caps, in the order they are applied.
+    pub tiers: Vec<C>,
+}
+
+impl<C> Tiered<C> {
+    pub fn new(tiers: Vec<C>) -> Self {
+        Tiered { tiers }
+    }
+}
+
+impl<N: BaseNumber, C: Cap<N>> Cap<N> for Tiered<C> {
+    fn apply(&self, value: &N) -> N {
+        self.tiers
+            .iter()
+            .fold(value.clone(), |value, tier| tier.apply(&value))
+    }
+
+    fn invert(&self, value: &N) -> N {
+        self.tiers
+            .iter()
+            .rev()
+            .fold(value.clone(), |value, tier| tier.invert(&value))
+    }
+}
+
+/// Keeps rounding error from taking a value past a threshold back below it, which would break
+/// monotonicity right at the threshold.
+fn at_least<N: BaseNumber>(value: N, threshold: &N) -> N {
+    if BaseNumber::lt(&value, threshold) {
+        threshold.clone()
+    } else {
+        value
+    }
+}
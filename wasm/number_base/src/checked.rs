@@ -0,0 +1,128 @@
+use crate::{BaseNumber, DecimalError};
+
+/// What to do when arithmetic fails to give a finite number, chosen per call site.
+///
+/// ```ignore
+/// // Catch bad formulas during development, but never let NaN into a release save.
+/// let policy = ArithmeticPolicy::debug_panic(Decimal::from_mantissa_exponent(1.0, 1e15));
+/// let production = policy.mul(&base, &policy.pow(&multiplier, &upgrades));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum ArithmeticPolicy<N> {
+    /// Panics with the operation and its operands.
+    Panic,
+    /// Clamps results to `[-max, max]`, and turns NaN into zero, as the saturating methods of
+    /// [`BaseNumber`] do.
+    Saturate(N),
+}
+
+impl<N: BaseNumber> ArithmeticPolicy<N> {
+    /// Returns a policy which panics in debug builds and saturates at `max` in release builds.
+    pub fn debug_panic(max: N) -> Self {
+        if cfg!(debug_assertions) {
+            ArithmeticPolicy::Panic
+        } else {
+            ArithmeticPolicy::Saturate(max)
+        }
+    }
+
+    pub fn add(&self, a: &N, b: &N) -> N {
+        match self {
+            ArithmeticPolicy::Panic => unwrap(a.checked_add(b), "add", &[a, b]),
+            ArithmeticPolicy::Saturate(max) => a.saturating_add(b, max),
+        }
+    }
+
+    pub fn sub(&self, a: &N, b: &N) -> N {
+        match self {
+            ArithmeticPolicy::Panic => unwrap(a.checked_sub(b), "sub", &[a, b]),
+            ArithmeticPolicy::Saturate(max) => a.saturating_sub(b, max),
+        }
+    }
+
+    pub fn mul(&self, a: &N, b: &N) -> N {
+        match self {
+            ArithmeticPolicy::Panic => unwrap(a.checked_mul(b), "mul", &[a, b]),
+            ArithmeticPolicy::Saturate(max) => a.saturating_mul(b, max),
+        }
+    }
+
+    pub fn div(&self, a: &N, b: &N) -> N {
+        match self {
+            ArithmeticPolicy::Panic => unwrap(a.checked_div(b), "div", &[a, b]),
+            ArithmeticPolicy::Saturate(max) => a.saturating_div(b, max),
+        }
+    }
+
+    pub fn pow(&self, a: &N, exponent: &N) -> N {
+        match self {
+            ArithmeticPolicy::Panic => unwrap(a.checked_pow(exponent), "pow", &[a, exponent]),
+            ArithmeticPolicy::Saturate(max) => a.saturating_pow(exponent, max),
+        }
+    }
+
+    pub fn sqrt(&self, a: &N) -> N {
+        match self {
+            ArithmeticPolicy::Panic => unwrap(a.checked_sqrt(), "sqrt", &[a]),
+            ArithmeticPolicy::Saturate(max) => a.saturating_sqrt(max),
+        }
+    }
+}
+
+#[track_caller]
+fn unwrap<N: BaseNumber>(result: Result<N, DecimalError>, operation: &str, operands: &[&N]) -> N {
+    match result {
+        Ok(value) => value,
+        Err(error) => panic!("{} of {:?}: {}", operation, operands, error),
+    }
+}
+
+pub(crate) fn is_nan<N: BaseNumber>(number: &N) -> bool {
+    number.partial_cmp(number).is_none()
+}
+
+pub(crate) fn is_infinite<N: BaseNumber>(number: &N) -> bool {
+    *number == N::INFINITY || *number == N::NEG_INFINITY
+}
+
+/// Returns [`DecimalError::NaN`] for NaN operands, and [`DecimalError::Domain`] if the
+/// operation is not `defined` for the others.
+pub(crate) fn domain<N: BaseNumber>(defined: bool, operands: &[&N]) -> Result<(), DecimalError> {
+    if operands.iter().any(|operand| is_nan(*operand)) {
+        Err(DecimalError::NaN)
+    } else if !defined {
+        Err(DecimalError::Domain)
+    } else {
+        Ok(())
+    }
+}
+
+/// Classifies the result of an operation, where `nonzero` is whether the exact result is known
+/// not to be zero, so that a zero result has underflowed.
+pub(crate) fn check<N: BaseNumber>(
+    result: N,
+    operands: &[&N],
+    nonzero: bool,
+) -> Result<N, DecimalError> {
+    if operands.iter().any(|operand| is_nan(*operand)) || is_nan(&result) {
+        Err(DecimalError::NaN)
+    } else if is_infinite(&result) {
+        Err(DecimalError::Overflow)
+    } else if nonzero && result == N::ZERO {
+        Err(DecimalError::Underflow)
+    } else {
+        Ok(result)
+    }
+}
+
+pub(crate) fn saturate<N: BaseNumber>(result: N, max: &N) -> N {
+    if is_nan(&result) {
+        N::ZERO
+    } else if BaseNumber::gt(&result, max) {
+        max.clone()
+    } else if BaseNumber::lt(&result, &-max.clone()) {
+        -max.clone()
+    } else {
+        result
+    }
+}
@@ -0,0 +1,1210 @@
+//! A conformance suite which every [`BaseNumber`] implementation can run, so that engine
+//! code behaves the same on any backend.
+//!
+//! [`conformance_tests!`](crate::conformance_tests) instantiates one test per check:
+//!
+//! ```ignore
+//! number_base::conformance_tests!(Decimal);
+//! // Or with a relative tolerance for agreement with f64, 1e-12 by default.
+//! number_base::conformance_tests!(Decimal, 1e-9);
+//! ```
+//!
+//! The checks are also public functions, for backends which only pass some of them. Every
+//! check panics with the offending operation and operands on the first failure.
+
+use crate::{ArithmeticPolicy, BaseNumber, DecimalError, RefNumber, RoundingMode};
+
+/// The relative tolerance used by [`conformance_tests!`](crate::conformance_tests) when none
+/// is given.
+pub const DEFAULT_TOLERANCE: f64 = 1e-12;
+
+/// Generates a `conformance` test module which runs every check against the given type.
+#[macro_export]
+macro_rules! conformance_tests {
+    ($number:ty) => {
+        $crate::conformance_tests!($number, $crate::conformance::DEFAULT_TOLERANCE);
+    };
+    ($number:ty, $tolerance:expr) => {
+        mod conformance {
+            use super::*;
+
+            #[test]
+            fn algebraic_identities() {
+                $crate::conformance::algebraic_identities::<$number>($tolerance);
+            }
+
+            #[test]
+            fn comparison_consistency() {
+                $crate::conformance::comparison_consistency::<$number>();
+            }
+
+            #[test]
+            fn special_values() {
+                $crate::conformance::special_values::<$number>();
+            }
+
+            #[test]
+            fn formatting_contracts() {
+                $crate::conformance::formatting_contracts::<$number>($tolerance);
+            }
+
+            #[test]
+            fn agreement_with_f64() {
+                $crate::conformance::agreement_with_f64::<$number>($tolerance);
+            }
+
+            #[test]
+            fn transcendental_functions() {
+                $crate::conformance::transcendental_functions::<$number>($tolerance);
+            }
+
+            #[test]
+            fn checked_arithmetic() {
+                $crate::conformance::checked_arithmetic::<$number>();
+            }
+
+            #[test]
+            fn sums_products_and_bounds() {
+                $crate::conformance::sums_products_and_bounds::<$number>();
+            }
+
+            #[test]
+            fn rounding_modes() {
+                $crate::conformance::rounding_modes::<$number>();
+            }
+
+            #[test]
+            fn series_purchases() {
+                $crate::conformance::series_purchases::<$number>($tolerance);
+            }
+        }
+    };
+}
+
+/// A xorshift generator, so that every run checks the same numbers.
+struct Xorshift(u64);
+
+impl Xorshift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a number in `[low, high)`.
+    fn next_range(&mut self, low: f64, high: f64) -> f64 {
+        low + (self.next() >> 11) as f64 / (1u64 << 53) as f64 * (high - low)
+    }
+
+    /// Returns a number of either sign with a decimal exponent between -`max_exponent` and
+    /// `max_exponent`.
+    fn next_number(&mut self, max_exponent: i32) -> f64 {
+        let mantissa = self.next_range(1.0, 10.0);
+        let exponent = (self.next() % (2 * max_exponent as u64 + 1)) as i32 - max_exponent;
+        let sign = if self.next().is_multiple_of(2) {
+            1.0
+        } else {
+            -1.0
+        };
+        sign * mantissa * 10.0_f64.powi(exponent)
+    }
+}
+
+/// Finite numbers of both signs and many magnitudes, well inside the range of an f64.
+const SAMPLES: [f64; 16] = [
+    0.0,
+    1.0,
+    -1.0,
+    0.5,
+    -2.5,
+    3.0,
+    10.0,
+    0.1,
+    -0.001,
+    123.456,
+    -98765.4321,
+    1e10,
+    6.02e23,
+    -1.6e-19,
+    1e-100,
+    2.5e100,
+];
+
+/// Returns the sample numbers together with their values.
+fn samples<N: BaseNumber>() -> Vec<(f64, N)> {
+    SAMPLES
+        .iter()
+        .map(|&value| (value, N::from(value)))
+        .collect()
+}
+
+/// Returns if `actual` is within `tolerance` of `expected`, relative to `scale`.
+fn is_close(actual: f64, expected: f64, scale: f64, tolerance: f64) -> bool {
+    actual == expected
+        || (actual.is_nan() && expected.is_nan())
+        || (actual - expected).abs() <= tolerance * scale.abs().max(f64::MIN_POSITIVE)
+}
+
+#[track_caller]
+fn assert_close<N: BaseNumber>(actual: &N, expected: f64, scale: f64, tolerance: f64, what: &str) {
+    let value = actual.to_number();
+    assert!(
+        is_close(value, expected, scale, tolerance),
+        "{}: got {} ({:?}), expected {}",
+        what,
+        value,
+        actual,
+        expected
+    );
+}
+
+#[track_caller]
+fn assert_same<N: BaseNumber>(actual: &N, expected: &N, what: &str) {
+    assert!(
+        actual == expected || (actual.to_number().is_nan() && expected.to_number().is_nan()),
+        "{}: got {:?}, expected {:?}",
+        what,
+        actual,
+        expected
+    );
+}
+
+fn is_nan<N: BaseNumber>(number: &N) -> bool {
+    number.partial_cmp(number).is_none()
+}
+
+/// Checks identities which hold for every number, exactly or up to rounding, and that
+/// owned, borrowed and assigning operators agree.
+pub fn algebraic_identities<N: BaseNumber>(tolerance: f64)
+where
+    for<'a> &'a N: RefNumber<N>,
+{
+    for (x, a) in samples::<N>() {
+        assert_same(&(a.clone() + N::ZERO), &a, &format!("{} + 0", x));
+        assert_same(&(a.clone() * N::ONE), &a, &format!("{} * 1", x));
+        assert_same(&(a.clone() - &a), &N::ZERO, &format!("{} - {}", x, x));
+        assert_same(&(a.clone() + -&a), &N::ZERO, &format!("{} + -{}", x, x));
+        assert_same(&-(-a.clone()), &a, &format!("-(-{})", x));
+        assert_same(&a.abs(), &(-&a).abs(), &format!("|{}| = |-{}|", x, x));
+        assert_close(&a.abs(), x.abs(), x, tolerance, &format!("|{}|", x));
+
+        if x != 0.0 {
+            assert_close(
+                &(&a / &N::from(x)),
+                1.0,
+                1.0,
+                tolerance,
+                &format!("{} / {}", x, x),
+            );
+            assert_close(
+                &a.recip().recip(),
+                x,
+                x,
+                tolerance,
+                &format!("1 / (1 / {})", x),
+            );
+            assert_close(
+                &(&a * &a).sqrt(),
+                x.abs(),
+                x,
+                tolerance,
+                &format!("sqrt({}²)", x),
+            );
+            assert_close(
+                &a.pow(&N::from(2)),
+                x * x,
+                x * x,
+                tolerance,
+                &format!("{}^2", x),
+            );
+        }
+
+        for (y, b) in samples::<N>() {
+            let context = format!("{} and {}", x, y);
+
+            assert_same(
+                &(&a + &b),
+                &(&b + &a),
+                &format!("commutative + of {}", context),
+            );
+            assert_same(
+                &(&a * &b),
+                &(&b * &a),
+                &format!("commutative * of {}", context),
+            );
+            assert_same(
+                &(&a - &b),
+                &(&a + -&b),
+                &format!("a - b = a + -b of {}", context),
+            );
+            assert_same(
+                &(&a - &b),
+                &-(&b - &a),
+                &format!("a - b = -(b - a) of {}", context),
+            );
+
+            // Every form of an operator gives the same result.
+            let sum = &a + &b;
+            assert_same(
+                &(a.clone() + b.clone()),
+                &sum,
+                &format!("owned + of {}", context),
+            );
+            assert_same(
+                &(a.clone() + &b),
+                &sum,
+                &format!("owned + borrowed of {}", context),
+            );
+            assert_same(
+                &(&a + b.clone()),
+                &sum,
+                &format!("borrowed + owned of {}", context),
+            );
+            let mut assigned = a.clone();
+            assigned += &b;
+            assert_same(&assigned, &sum, &format!("+= of {}", context));
+
+            let product = &a * &b;
+            let mut assigned = a.clone();
+            assigned *= b.clone();
+            assert_same(&assigned, &product, &format!("*= of {}", context));
+
+            let difference = &a - &b;
+            let mut assigned = a.clone();
+            assigned -= &b;
+            assert_same(&assigned, &difference, &format!("-= of {}", context));
+
+            if y != 0.0 {
+                let quotient = &a / &b;
+                let mut assigned = a.clone();
+                assigned /= &b;
+                assert_same(&assigned, &quotient, &format!("/= of {}", context));
+                assert_close(
+                    &(&quotient * &b),
+                    x,
+                    x,
+                    tolerance,
+                    &format!("(a / b) * b of {}", context),
+                );
+            }
+
+            if x > 0.0 && y > 0.0 {
+                assert_close(
+                    &(&a * &b).ln(),
+                    x.ln() + y.ln(),
+                    x.ln().abs() + y.ln().abs(),
+                    tolerance,
+                    &format!("ln(a * b) of {}", context),
+                );
+            }
+
+            for (z, c) in samples::<N>() {
+                let context = format!("{}, {} and {}", x, y, z);
+                let scale = x.abs().max(y.abs()).max(z.abs());
+
+                assert_close(
+                    &((&a + &b) + &c),
+                    (&a + (&b + &c)).to_number(),
+                    scale,
+                    tolerance,
+                    &format!("associative + of {}", context),
+                );
+                assert_close(
+                    &((&a * &b) * &c),
+                    (&a * (&b * &c)).to_number(),
+                    (x * y * z).abs(),
+                    tolerance,
+                    &format!("associative * of {}", context),
+                );
+                assert_close(
+                    &(&a * (&b + &c)),
+                    (&a * &b + &a * &c).to_number(),
+                    x.abs() * y.abs().max(z.abs()),
+                    tolerance,
+                    &format!("distributive of {}", context),
+                );
+            }
+        }
+    }
+}
+
+/// Checks that the comparison operators, the shorthand comparisons and [`BaseNumber::sign`]
+/// agree with each other and with the order of f64.
+pub fn comparison_consistency<N: BaseNumber>() {
+    let mut numbers = samples::<N>();
+    numbers.push((f64::INFINITY, N::INFINITY));
+    numbers.push((f64::NEG_INFINITY, N::NEG_INFINITY));
+
+    for (x, a) in &numbers {
+        let sign = a.sign();
+        assert_eq!(
+            sign,
+            a.partial_cmp(&N::ZERO).map_or(0, |o| o as i32),
+            "sign of {}",
+            x
+        );
+
+        for (y, b) in &numbers {
+            let context = format!("{} and {}", x, y);
+            let ordering = a.partial_cmp(b);
+
+            assert_eq!(ordering, x.partial_cmp(y), "order of {}", context);
+            assert_eq!(
+                b.partial_cmp(a),
+                ordering.map(|o| o.reverse()),
+                "antisymmetry of {}",
+                context
+            );
+            assert_eq!(a == b, x == y, "== of {}", context);
+            assert_eq!(BaseNumber::neq(a, b), x != y, "neq of {}", context);
+            assert_eq!(BaseNumber::lt(a, b), x < y, "lt of {}", context);
+            assert_eq!(BaseNumber::lte(a, b), x <= y, "lte of {}", context);
+            assert_eq!(BaseNumber::gt(a, b), x > y, "gt of {}", context);
+            assert_eq!(BaseNumber::gte(a, b), x >= y, "gte of {}", context);
+        }
+    }
+
+    let nan = N::NAN;
+    for (x, a) in &numbers {
+        assert!(a.partial_cmp(&nan).is_none(), "{} compared with NaN", x);
+        assert!(
+            !(BaseNumber::lt(a, &nan)
+                || BaseNumber::lte(a, &nan)
+                || BaseNumber::gt(a, &nan)
+                || BaseNumber::gte(a, &nan)
+                || *a == nan),
+            "{} compared with NaN",
+            x
+        );
+    }
+}
+
+/// Checks the constants, NaN, the infinities and zero.
+pub fn special_values<N: BaseNumber>()
+where
+    for<'a> &'a N: RefNumber<N>,
+{
+    let nan = N::NAN;
+    let infinity = N::INFINITY;
+    let neg_infinity = N::NEG_INFINITY;
+
+    assert_same(&N::ZERO, &N::from(0), "ZERO");
+    assert_same(&N::ONE, &N::from(1), "ONE");
+    assert_same(&-N::ZERO, &N::ZERO, "-0");
+    assert_eq!(N::from(-0.0).sign(), 0, "sign of -0");
+    assert_same(&-infinity.clone(), &neg_infinity, "-Infinity");
+    assert!(is_nan(&nan), "NaN is not equal to itself");
+    assert!(BaseNumber::neq(&nan, &nan), "NaN is not equal to itself");
+    assert!(is_nan(&N::from(f64::NAN)), "NaN from f64");
+    assert_same(&N::from(f64::INFINITY), &infinity, "Infinity from f64");
+    assert_same(
+        &N::from(f64::NEG_INFINITY),
+        &neg_infinity,
+        "-Infinity from f64",
+    );
+
+    assert!(nan.to_number().is_nan(), "NaN to f64");
+    assert_eq!(infinity.to_number(), f64::INFINITY, "Infinity to f64");
+    assert_eq!(
+        neg_infinity.to_number(),
+        f64::NEG_INFINITY,
+        "-Infinity to f64"
+    );
+
+    for (string, expected) in [
+        ("NaN", nan.clone()),
+        ("Infinity", infinity.clone()),
+        ("-Infinity", neg_infinity.clone()),
+    ] {
+        assert_eq!(expected.to_string(), string, "Display");
+        assert_same(&N::try_from_str(string).unwrap(), &expected, string);
+    }
+
+    for (x, a) in samples::<N>() {
+        for result in [
+            &a + &nan,
+            &nan + &a,
+            &a - &nan,
+            &a * &nan,
+            &a / &nan,
+            &nan / &a,
+        ] {
+            assert!(is_nan(&result), "NaN does not propagate through {}", x);
+        }
+
+        if x != 0.0 {
+            let sign = a.sign();
+            let expected = if sign > 0 { &infinity } else { &neg_infinity };
+            assert_same(&(&a * &infinity), expected, &format!("{} * Infinity", x));
+            assert_same(&(&a / &N::ZERO), expected, &format!("{} / 0", x));
+            assert_same(&(&a + &infinity), &infinity, &format!("{} + Infinity", x));
+        }
+
+        assert_same(&(&a / &infinity), &N::ZERO, &format!("{} / Infinity", x));
+    }
+
+    assert!(is_nan(&(&infinity - &N::INFINITY)), "Infinity - Infinity");
+    assert!(is_nan(&(&infinity + &neg_infinity)), "Infinity + -Infinity");
+    assert!(is_nan(&(&infinity * &N::ZERO)), "Infinity * 0");
+    assert!(is_nan(&(&infinity / &N::INFINITY)), "Infinity / Infinity");
+    assert!(is_nan(&(N::ZERO / N::from(0))), "0 / 0");
+    assert!(is_nan(&N::from(-1).sqrt()), "sqrt(-1)");
+    assert!(is_nan(&N::from(-1).ln()), "ln(-1)");
+    assert_same(&N::ZERO.ln(), &neg_infinity, "ln(0)");
+    assert_same(&infinity.sqrt(), &infinity, "sqrt(Infinity)");
+    assert_same(&N::ONE.ln(), &N::ZERO, "ln(1)");
+    assert_same(&N::ZERO.sqrt(), &N::ZERO, "sqrt(0)");
+    assert_eq!(N::ZERO.sign(), 0, "sign of 0");
+    assert_eq!(N::ONE.sign(), 1, "sign of 1");
+    assert_eq!((-N::ONE).sign(), -1, "sign of -1");
+}
+
+/// Returns the digits of a formatted number's mantissa without the sign, the point and
+/// leading zeros, or all of them if the number is zero.
+fn significant_digits(string: &str) -> String {
+    let mantissa = string.split(['e', 'E']).next().unwrap_or("");
+    let digits: String = mantissa.chars().filter(char::is_ascii_digit).collect();
+    let trimmed = digits.trim_start_matches('0');
+
+    if trimmed.is_empty() {
+        digits
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// Checks the shape and rounding of [`BaseNumber::to_fixed`], [`BaseNumber::to_exponential`]
+/// and [`BaseNumber::to_precision`], and that Display and the canonical string read back
+/// into the number.
+pub fn formatting_contracts<N: BaseNumber>(tolerance: f64) {
+    let mut rng = Xorshift(0x9E37_79B9_7F4A_7C15);
+    let values = SAMPLES
+        .iter()
+        .copied()
+        .chain((0..200).map(|_| rng.next_number(20)));
+
+    for x in values {
+        let a = N::from(x);
+
+        for places in 0..=6u32 {
+            let unit = 10.0_f64.powi(-(places as i32));
+
+            let fixed = a.to_fixed(places);
+            let fraction = fixed.split_once('.').map(|(_, fraction)| fraction);
+            assert!(
+                !fixed.contains(['e', 'E']),
+                "to_fixed({}) of {}: {}",
+                places,
+                x,
+                fixed
+            );
+            assert_eq!(
+                fraction.map_or(0, str::len),
+                places as usize,
+                "to_fixed({}) of {}: {}",
+                places,
+                x,
+                fixed
+            );
+            let parsed: f64 = fixed.parse().unwrap();
+            assert!(
+                (parsed - x).abs() <= 0.5 * unit + 2.0 * tolerance * x.abs(),
+                "to_fixed({}) of {}: {}",
+                places,
+                x,
+                fixed
+            );
+
+            let exponential = a.to_exponential(places);
+            let (mantissa, exponent) = exponential
+                .split_once('e')
+                .unwrap_or_else(|| panic!("to_exponential({}) of {}: {}", places, x, exponential));
+            let mantissa = mantissa.trim_start_matches('-');
+            assert!(
+                exponent.starts_with(['+', '-']) && exponent[1..].parse::<u32>().is_ok(),
+                "to_exponential({}) of {}: {}",
+                places,
+                x,
+                exponential
+            );
+            assert_eq!(
+                mantissa.len(),
+                if places == 0 { 1 } else { places as usize + 2 },
+                "to_exponential({}) of {}: {}",
+                places,
+                x,
+                exponential
+            );
+            let parsed: f64 = exponential.parse().unwrap();
+            assert!(
+                (parsed - x).abs() <= (0.5 * unit + 2.0 * tolerance) * x.abs(),
+                "to_exponential({}) of {}: {}",
+                places,
+                x,
+                exponential
+            );
+
+            let precision = places + 1;
+            let string = a.to_precision(precision);
+            assert_eq!(
+                significant_digits(&string).len(),
+                precision as usize,
+                "to_precision({}) of {}: {}",
+                precision,
+                x,
+                string
+            );
+            let parsed: f64 = string.parse().unwrap();
+            assert!(
+                (parsed - x).abs() <= (0.5 * unit + 2.0 * tolerance) * x.abs(),
+                "to_precision({}) of {}: {}",
+                precision,
+                x,
+                string
+            );
+        }
+
+        let displayed = N::try_from_str(&a.to_string()).unwrap();
+        assert_close(&displayed, x, x, tolerance, &format!("Display of {}", x));
+
+        let canonical = a.to_canonical_string();
+        assert_same(
+            &N::try_from_str(&canonical).unwrap(),
+            &a,
+            &format!("canonical string {} of {}", canonical, x),
+        );
+    }
+}
+
+/// Checks that every operation agrees with f64 on random numbers, to within the tolerance
+/// relative to the size of the result or of the operands.
+pub fn agreement_with_f64<N: BaseNumber>(tolerance: f64) {
+    let mut rng = Xorshift(0x2545_F491_4F6C_DD1D);
+
+    for _ in 0..2000 {
+        let x = rng.next_number(100);
+        let y = rng.next_number(100);
+        let a = N::from(x);
+        let b = N::from(y);
+        let context = |operation: &str| format!("{} of {} and {}", operation, x, y);
+
+        assert_close(&a, x, x, tolerance, &context("conversion"));
+        let scale = x.abs().max(y.abs());
+        assert_close(
+            &(a.clone() + b.clone()),
+            x + y,
+            scale,
+            tolerance,
+            &context("+"),
+        );
+        assert_close(
+            &(a.clone() - b.clone()),
+            x - y,
+            scale,
+            tolerance,
+            &context("-"),
+        );
+        assert_close(
+            &(a.clone() * b.clone()),
+            x * y,
+            x * y,
+            tolerance,
+            &context("*"),
+        );
+        assert_close(
+            &(a.clone() / b.clone()),
+            x / y,
+            x / y,
+            tolerance,
+            &context("/"),
+        );
+
+        assert_close(&a.abs(), x.abs(), x, tolerance, &context("abs"));
+        assert_close(
+            &a.recip(),
+            x.recip(),
+            x.recip(),
+            tolerance,
+            &context("recip"),
+        );
+        assert_close(&a.cbrt(), x.cbrt(), x.cbrt(), tolerance, &context("cbrt"));
+        assert_close(&a.round(), x.round(), x, tolerance, &context("round"));
+        assert_close(&a.trunc(), x.trunc(), x, tolerance, &context("trunc"));
+        assert_close(&a.floor(), x.floor(), x, tolerance, &context("floor"));
+        assert_close(&a.ceil(), x.ceil(), x, tolerance, &context("ceil"));
+        assert_eq!(a.sign() as f64, x.signum(), "{}", context("sign"));
+
+        let positive = N::from(x.abs());
+        let magnitude = x.abs();
+        assert_close(
+            &positive.sqrt(),
+            magnitude.sqrt(),
+            magnitude.sqrt(),
+            tolerance,
+            &context("sqrt"),
+        );
+        assert_close(
+            &positive.ln(),
+            magnitude.ln(),
+            magnitude.ln(),
+            tolerance,
+            &context("ln"),
+        );
+        assert_close(
+            &positive.log10(),
+            magnitude.log10(),
+            magnitude.log10(),
+            tolerance,
+            &context("log10"),
+        );
+        assert_close(
+            &positive.log2(),
+            magnitude.log2(),
+            magnitude.log2(),
+            tolerance,
+            &context("log2"),
+        );
+
+        // Keep powers inside the range of an f64.
+        let exponent = rng.next_range(-3.0, 3.0);
+        let expected = magnitude.powf(exponent);
+        assert_close(
+            &positive.pow(&N::from(exponent)),
+            expected,
+            expected,
+            tolerance * (1.0 + (exponent * magnitude.ln()).abs()),
+            &context(&format!("pow {}", exponent)),
+        );
+
+        let integer = (rng.next() % 7) as i32 - 3;
+        let expected = x.powi(integer);
+        assert_close(
+            &a.pow(&N::from(integer)),
+            expected,
+            expected,
+            tolerance * 4.0,
+            &context(&format!("pow {}", integer)),
+        );
+    }
+}
+
+/// Checks the transcendental functions against f64 on arguments whose results fit in one,
+/// with the tolerance scaled by how much each function magnifies the error of its argument.
+pub fn transcendental_functions<N: BaseNumber>(tolerance: f64) {
+    let mut rng = Xorshift(0x9E37_79B9_7F4A_7C15);
+
+    for _ in 0..500 {
+        let x = rng.next_range(-50.0, 50.0);
+        let a = N::from(x);
+        let context = |operation: &str| format!("{} of {}", operation, x);
+        let magnified = tolerance * (1.0 + x.abs());
+
+        assert_close(&a.exp(), x.exp(), x.exp(), magnified, &context("exp"));
+        let expected = 10.0_f64.powf(x);
+        assert_close(
+            &a.pow10(),
+            expected,
+            expected,
+            magnified * std::f64::consts::LN_10,
+            &context("pow10"),
+        );
+        assert_close(&a.sinh(), x.sinh(), x.sinh(), magnified, &context("sinh"));
+        assert_close(&a.cosh(), x.cosh(), x.cosh(), magnified, &context("cosh"));
+        assert_close(&a.tanh(), x.tanh(), 1.0, magnified, &context("tanh"));
+        assert_close(&a.sin(), x.sin(), 1.0, magnified, &context("sin"));
+        assert_close(&a.cos(), x.cos(), 1.0, magnified, &context("cos"));
+        let expected = x.tan();
+        assert_close(
+            &a.tan(),
+            expected,
+            expected,
+            magnified * (1.0 + expected * expected),
+            &context("tan"),
+        );
+        assert_close(
+            &a.root(&N::from(3)),
+            x.cbrt(),
+            x.cbrt(),
+            tolerance,
+            &context("root 3"),
+        );
+        assert_close(
+            &a.root(&N::from(5)),
+            x.signum() * x.abs().powf(0.2),
+            x.cbrt(),
+            tolerance,
+            &context("root 5"),
+        );
+
+        let magnitude = x.abs();
+        let base = rng.next_range(1.5, 20.0);
+        let expected = magnitude.ln() / base.ln();
+        assert_close(
+            &N::from(magnitude).log(&N::from(base)),
+            expected,
+            expected,
+            tolerance,
+            &context(&format!("log {}", base)),
+        );
+        assert_close(
+            &N::from(magnitude).root(&N::from(2)),
+            magnitude.sqrt(),
+            magnitude.sqrt(),
+            tolerance,
+            &context("root 2"),
+        );
+        assert!(is_nan(&N::from(-1).root(&N::from(2))), "root 2 of -1");
+
+        // Γ is only known to f64 precision through its own recurrence.
+        let y = rng.next_range(0.1, 30.0);
+        let b = N::from(y);
+        let gamma = b.gamma().to_number();
+        assert_close(
+            &b.factorial(),
+            gamma * y,
+            gamma * y,
+            tolerance * (1.0 + y * y.ln().abs()),
+            &format!("factorial of {}", y),
+        );
+
+        let z = rng.next_range(-1.0 / std::f64::consts::E, 1e3);
+        let w = N::from(z).lambert_w().to_number();
+        assert!(
+            is_close(w * w.exp(), z, z.abs().max(1.0), tolerance * 4.0),
+            "lambert_w of {}: got {}",
+            z,
+            w
+        );
+    }
+
+    let mut factorial = 1.0;
+    for n in 1..=20 {
+        factorial *= n as f64;
+        assert_close(
+            &N::from(n).factorial(),
+            factorial,
+            factorial,
+            tolerance,
+            &format!("factorial of {}", n),
+        );
+    }
+    assert_close(
+        &N::from(0.5).gamma(),
+        std::f64::consts::PI.sqrt(),
+        1.0,
+        tolerance,
+        "gamma of 0.5",
+    );
+    assert!(is_nan(&N::from(-2).gamma()), "gamma of -2");
+    assert_same(&N::ZERO.exp(), &N::ONE, "exp of 0");
+    assert_same(&N::NEG_INFINITY.exp(), &N::ZERO, "exp of -Infinity");
+    assert_same(&N::INFINITY.exp(), &N::INFINITY, "exp of Infinity");
+    assert!(is_nan(&N::from(-1).lambert_w()), "lambert_w of -1");
+}
+
+/// Checks that the affordable counts of geometric and arithmetic series are the largest whose
+/// cost fits in the budget, and that their costs add up across purchases.
+pub fn series_purchases<N: BaseNumber>(tolerance: f64) {
+    let mut rng = Xorshift(0x2545_F491_4F6C_DD1D);
+
+    for _ in 0..200 {
+        let start = N::from(rng.next_range(1.0, 100.0));
+        let ratio = N::from(rng.next_range(1.01, 2.0));
+        let increase = N::from(rng.next_range(0.0, 50.0));
+        let owned = N::from(rng.next_range(0.0, 20.0).floor());
+        let resources = N::from(10.0_f64.powf(rng.next_range(0.0, 12.0)));
+        let context = format!(
+            "{:?} for {:?} from {:?} with {:?} owned",
+            resources, start, owned, ratio
+        );
+
+        let affordable = N::afford_geometric_series(&resources, &start, &ratio, &owned);
+        let sum = |count: &N| N::sum_geometric_series(count, &start, &ratio, &owned);
+        assert_same(&affordable.floor(), &affordable, &context);
+        assert!(
+            BaseNumber::lte(&sum(&affordable), &resources)
+                && BaseNumber::gt(&sum(&(affordable.clone() + N::ONE)), &resources),
+            "afford_geometric_series of {}: got {:?}",
+            context,
+            affordable
+        );
+
+        let context = format!(
+            "{:?} for {:?} from {:?} with {:?} owned",
+            resources, start, owned, increase
+        );
+        let affordable = N::afford_arithmetic_series(&resources, &start, &increase, &owned);
+        let sum = |count: &N| N::sum_arithmetic_series(count, &start, &increase, &owned);
+        assert_same(&affordable.floor(), &affordable, &context);
+        assert!(
+            BaseNumber::lte(&sum(&affordable), &resources)
+                && BaseNumber::gt(&sum(&(affordable.clone() + N::ONE)), &resources),
+            "afford_arithmetic_series of {}: got {:?}",
+            context,
+            affordable
+        );
+
+        // Buying in two batches costs the same as buying in one.
+        let first = N::from(rng.next_range(0.0, 30.0).floor());
+        let second = N::from(rng.next_range(0.0, 30.0).floor());
+        let total = first.clone() + &second;
+        let later = owned.clone() + &first;
+        let batches = [
+            (
+                N::sum_geometric_series(&first, &start, &ratio, &owned)
+                    + N::sum_geometric_series(&second, &start, &ratio, &later),
+                N::sum_geometric_series(&total, &start, &ratio, &owned),
+            ),
+            (
+                N::sum_arithmetic_series(&first, &start, &increase, &owned)
+                    + N::sum_arithmetic_series(&second, &start, &increase, &later),
+                N::sum_arithmetic_series(&total, &start, &increase, &owned),
+            ),
+        ];
+        for (batched, whole) in batches {
+            let expected = whole.to_number();
+            assert_close(
+                &batched,
+                expected,
+                expected,
+                tolerance * 4.0,
+                &format!("{:?} and then {:?} bought with {}", first, second, context),
+            );
+        }
+    }
+
+    let ten = N::from(10);
+    assert_same(
+        &N::afford_geometric_series(&N::from(1000), &ten, &N::ONE, &N::from(5)),
+        &N::from(100),
+        "afford_geometric_series with a ratio of 1",
+    );
+    assert_same(
+        &N::afford_geometric_series(&N::from(-5), &ten, &N::from(2), &N::ZERO),
+        &N::ZERO,
+        "afford_geometric_series of a debt",
+    );
+    assert_same(
+        &N::afford_arithmetic_series(&N::from(1000), &ten, &N::ZERO, &N::ZERO),
+        &N::from(100),
+        "afford_arithmetic_series with no increase",
+    );
+    assert_same(
+        &N::sum_geometric_series(&N::ZERO, &ten, &N::from(2), &N::ZERO),
+        &N::ZERO,
+        "sum_geometric_series of nothing",
+    );
+    assert_same(
+        &N::efficiency_of_purchase(&N::from(100), &N::from(4), &N::from(5)),
+        &N::from(45),
+        "efficiency_of_purchase",
+    );
+}
+
+/// Checks that checked arithmetic reports every result which is not a finite number, and that
+/// saturating arithmetic clamps it.
+pub fn checked_arithmetic<N: BaseNumber>() {
+    let two = N::from(2);
+    let three = N::from(3);
+    let cases = [
+        ("add", two.checked_add(&three), Ok(N::from(5))),
+        ("sub", two.checked_sub(&three), Ok(N::from(-1))),
+        ("mul", two.checked_mul(&three), Ok(N::from(6))),
+        ("div", N::from(6).checked_div(&three), Ok(two.clone())),
+        ("pow", two.checked_pow(&three), Ok(N::from(8))),
+        ("pow", N::from(-2).checked_pow(&three), Ok(N::from(-8))),
+        ("sqrt", N::from(9).checked_sqrt(), Ok(three.clone())),
+        ("add NaN", two.checked_add(&N::NAN), Err(DecimalError::NaN)),
+        (
+            "div NaN by 0",
+            N::NAN.checked_div(&N::ZERO),
+            Err(DecimalError::NaN),
+        ),
+        (
+            "sub Infinity",
+            N::INFINITY.checked_sub(&N::INFINITY),
+            Err(DecimalError::NaN),
+        ),
+        (
+            "add Infinity",
+            two.checked_add(&N::INFINITY),
+            Err(DecimalError::Overflow),
+        ),
+        (
+            "div by 0",
+            two.checked_div(&N::ZERO),
+            Err(DecimalError::Domain),
+        ),
+        (
+            "div 0 by 0",
+            N::ZERO.checked_div(&N::ZERO),
+            Err(DecimalError::Domain),
+        ),
+        (
+            "div by Infinity",
+            two.checked_div(&N::INFINITY),
+            Ok(N::ZERO),
+        ),
+        (
+            "pow of a negative number",
+            N::from(-8).checked_pow(&N::from(0.5)),
+            Err(DecimalError::Domain),
+        ),
+        (
+            "pow of 0",
+            N::ZERO.checked_pow(&N::from(-1)),
+            Err(DecimalError::Domain),
+        ),
+        (
+            "sqrt of -1",
+            N::from(-1).checked_sqrt(),
+            Err(DecimalError::Domain),
+        ),
+    ];
+    for (what, actual, expected) in cases {
+        assert_eq!(actual, expected, "checked {}", what);
+    }
+
+    // Squaring overflows or underflows eventually, unless the backend's range is too large to
+    // reach this way.
+    for (start, error) in [
+        (10.0, DecimalError::Overflow),
+        (0.1, DecimalError::Underflow),
+    ] {
+        let mut number = N::from(start);
+        for _ in 0..64 {
+            match number.checked_mul(&number) {
+                Ok(square) => number = square,
+                Err(actual) => {
+                    assert_eq!(actual, error, "checked square of {:?}", number);
+                    break;
+                }
+            }
+        }
+    }
+
+    let max = N::from(100);
+    let saturated = [
+        ("add", N::from(99).saturating_add(&two, &max), max.clone()),
+        ("sub", N::from(-99).saturating_sub(&two, &max), -max.clone()),
+        ("mul", N::from(-60).saturating_mul(&two, &max), -max.clone()),
+        (
+            "div",
+            N::from(60).saturating_div(&N::from(0.5), &max),
+            max.clone(),
+        ),
+        ("div by 0", two.saturating_div(&N::ZERO, &max), max.clone()),
+        ("pow", N::from(10).saturating_pow(&three, &max), max.clone()),
+        ("mul", two.saturating_mul(&three, &max), N::from(6)),
+        ("add NaN", two.saturating_add(&N::NAN, &max), N::ZERO),
+        ("sqrt of -1", N::from(-1).saturating_sqrt(&max), N::ZERO),
+    ];
+    for (what, actual, expected) in saturated {
+        assert_same(&actual, &expected, &format!("saturating {}", what));
+    }
+
+    let policy = ArithmeticPolicy::Saturate(max.clone());
+    assert_same(&policy.pow(&N::from(10), &three), &max, "saturating policy");
+    let policy = ArithmeticPolicy::<N>::Panic;
+    assert_same(&policy.add(&two, &three), &N::from(5), "panicking policy");
+}
+
+/// Checks every rounding mode on ties and on either side of them, with numbers whose decimal
+/// digits are exact in every backend.
+pub fn rounding_modes<N: BaseNumber>() {
+    use RoundingMode::*;
+
+    // The number, the places or significant digits, and the results of HalfEven, HalfUp,
+    // TowardZero, Floor and Ceil.
+    let places: &[(f64, i64, [f64; 5])] = &[
+        (2.5, 0, [2.0, 3.0, 2.0, 2.0, 3.0]),
+        (-2.5, 0, [-2.0, -3.0, -2.0, -3.0, -2.0]),
+        (3.5, 0, [4.0, 4.0, 3.0, 3.0, 4.0]),
+        (2.4, 0, [2.0, 2.0, 2.0, 2.0, 3.0]),
+        (-2.6, 0, [-3.0, -3.0, -2.0, -3.0, -2.0]),
+        (-0.375, 2, [-0.38, -0.38, -0.37, -0.38, -0.37]),
+        (0.125, 2, [0.12, 0.13, 0.12, 0.12, 0.13]),
+        (1250.0, -2, [1200.0, 1300.0, 1200.0, 1200.0, 1300.0]),
+        (0.004, 2, [0.0, 0.0, 0.0, 0.0, 0.01]),
+        (-0.004, 2, [0.0, 0.0, 0.0, -0.01, 0.0]),
+        (999.5, 0, [1000.0, 1000.0, 999.0, 999.0, 1000.0]),
+        (7.0, 3, [7.0, 7.0, 7.0, 7.0, 7.0]),
+    ];
+    let significant: &[(f64, u32, [f64; 5])] = &[
+        (12250.0, 3, [12200.0, 12300.0, 12200.0, 12200.0, 12300.0]),
+        (12350.0, 3, [12400.0, 12400.0, 12300.0, 12300.0, 12400.0]),
+        (-0.09995, 3, [-0.1, -0.1, -0.0999, -0.1, -0.0999]),
+        (0.5, 0, [0.5, 0.5, 0.5, 0.5, 0.5]),
+    ];
+
+    let modes = [HalfEven, HalfUp, TowardZero, Floor, Ceil];
+    for &(value, places, expected) in places {
+        for (mode, expected) in modes.iter().zip(expected) {
+            assert_close(
+                &N::from(value).round_to(places, *mode),
+                expected,
+                expected,
+                1e-15,
+                &format!("round_to of {} to {} places with {:?}", value, places, mode),
+            );
+        }
+    }
+    for &(value, digits, expected) in significant {
+        for (mode, expected) in modes.iter().zip(expected) {
+            assert_close(
+                &N::from(value).round_to_significant(digits, *mode),
+                expected,
+                expected,
+                1e-15,
+                &format!(
+                    "round_to_significant of {} to {} digits with {:?}",
+                    value, digits, mode
+                ),
+            );
+        }
+    }
+
+    // Places past the last digit leave the number as it is, even where scaling it by the
+    // power of ten would leave the range of an f64.
+    for (value, places) in [(1.5, 400), (1e300, 10), (-1.25, 2)] {
+        for mode in modes {
+            assert_close(
+                &N::from(value).round_to(places, mode),
+                value,
+                value,
+                1e-15,
+                &format!("round_to of {} to {} places with {:?}", value, places, mode),
+            );
+        }
+    }
+
+    for number in [N::ZERO, N::INFINITY, N::NEG_INFINITY] {
+        assert_same(
+            &number.round_to(2, Floor),
+            &number,
+            &format!("round_to of {:?}", number),
+        );
+    }
+    assert!(
+        is_nan(&N::NAN.round_to_significant(3, Ceil)),
+        "round_to of NaN"
+    );
+
+    assert_eq!(N::from(2.5).to_fixed_with_rounding(0, HalfEven), "2");
+    assert_eq!(
+        N::from(-0.375).to_fixed_with_rounding(2, TowardZero),
+        "-0.37"
+    );
+    assert_eq!(N::from(0.125).to_fixed_with_rounding(3, Floor), "0.125");
+}
+
+/// Checks sums and products of iterators, and that the bounds propagate NaN like arithmetic.
+pub fn sums_products_and_bounds<N: BaseNumber>() {
+    let numbers: Vec<N> = [1.5, -2.0, 4.0, 0.25].into_iter().map(N::from).collect();
+    assert_close(
+        &numbers.iter().sum::<N>(),
+        3.75,
+        3.75,
+        1e-15,
+        "sum of references",
+    );
+    assert_close(
+        &numbers.clone().into_iter().sum::<N>(),
+        3.75,
+        3.75,
+        1e-15,
+        "sum",
+    );
+    assert_close(
+        &numbers.iter().product::<N>(),
+        -3.0,
+        3.0,
+        1e-15,
+        "product of references",
+    );
+    assert_close(
+        &numbers.into_iter().product::<N>(),
+        -3.0,
+        3.0,
+        1e-15,
+        "product",
+    );
+
+    assert_same(
+        &Vec::<N>::new().into_iter().sum::<N>(),
+        &N::ZERO,
+        "empty sum",
+    );
+    assert_same(
+        &Vec::<N>::new().iter().product::<N>(),
+        &N::ONE,
+        "empty product",
+    );
+    assert_same(
+        &[N::ONE, N::NAN, N::ONE].iter().sum::<N>(),
+        &N::NAN,
+        "sum with NaN",
+    );
+    assert_same(
+        &[N::INFINITY, N::NEG_INFINITY].into_iter().sum::<N>(),
+        &N::NAN,
+        "sum of opposite infinities",
+    );
+    assert_same(
+        &[N::INFINITY, N::ZERO].into_iter().product::<N>(),
+        &N::NAN,
+        "product of infinity and zero",
+    );
+
+    let (two, three) = (N::from(2), N::from(3));
+    assert_same(&BaseNumber::max(&two, &three), &three, "max");
+    assert_same(&BaseNumber::max(&three, &two), &three, "max");
+    assert_same(&BaseNumber::min(&two, &three), &two, "min");
+    assert_same(&BaseNumber::min(&three, &two), &two, "min");
+    assert_same(
+        &BaseNumber::max(&N::NEG_INFINITY, &two),
+        &two,
+        "max with -Infinity",
+    );
+    assert_same(
+        &BaseNumber::min(&N::INFINITY, &two),
+        &two,
+        "min with Infinity",
+    );
+    for (a, b) in [(N::NAN, two.clone()), (two.clone(), N::NAN)] {
+        assert!(
+            is_nan(&BaseNumber::max(&a, &b)),
+            "max of {:?} and {:?}",
+            a,
+            b
+        );
+        assert!(
+            is_nan(&BaseNumber::min(&a, &b)),
+            "min of {:?} and {:?}",
+            a,
+            b
+        );
+    }
+
+    let (zero, five) = (N::ZERO, N::from(5));
+    assert_same(&five.clamp(&zero, &three), &three, "clamp above");
+    assert_same(&(-five.clone()).clamp(&zero, &three), &zero, "clamp below");
+    assert_same(&two.clamp(&zero, &three), &two, "clamp within");
+    assert_same(
+        &N::INFINITY.clamp(&zero, &three),
+        &three,
+        "clamp of Infinity",
+    );
+    assert_same(&two.clamp(&two, &two), &two, "clamp to a point");
+    assert_same(&five.clamp_max(&three), &three, "clamp_max");
+    assert_same(&two.clamp_max(&three), &two, "clamp_max");
+    assert_same(&two.clamp_min(&three), &three, "clamp_min");
+    assert_same(&five.clamp_min(&three), &five, "clamp_min");
+    assert!(is_nan(&N::NAN.clamp(&zero, &three)), "clamp of NaN");
+    assert!(
+        is_nan(&two.clamp(&N::NAN, &three)),
+        "clamp to a NaN minimum"
+    );
+    assert!(is_nan(&two.clamp_max(&N::NAN)), "clamp_max to NaN");
+}
@@ -0,0 +1,60 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// An error which can be returned when parsing a number from a string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseDecimalError {
+    /// The string was empty.
+    Empty,
+    /// The mantissa (or the whole number, if there is no exponent) is not a valid number.
+    InvalidMantissa,
+    /// The exponent is not a valid number.
+    InvalidExponent,
+    /// The number is followed by characters which are not part of it.
+    TrailingCharacters,
+}
+
+impl Display for ParseDecimalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseDecimalError::Empty => write!(f, "cannot parse number from empty string"),
+            ParseDecimalError::InvalidMantissa => write!(f, "invalid mantissa"),
+            ParseDecimalError::InvalidExponent => write!(f, "invalid exponent"),
+            ParseDecimalError::TrailingCharacters => {
+                write!(f, "unexpected characters after number")
+            }
+        }
+    }
+}
+
+impl Error for ParseDecimalError {}
+
+/// An error which is returned by the checked arithmetic of
+/// [`BaseNumber`](crate::BaseNumber) instead of a result which is not a finite number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecimalError {
+    /// The result is too large to represent, or an operand was infinite.
+    Overflow,
+    /// The result is too close to zero to represent, and would have been rounded to zero.
+    Underflow,
+    /// An operand was NaN, or the result of an indeterminate form such as `∞ - ∞` was.
+    NaN,
+    /// The operation is undefined for its operands, such as a division by zero or the square
+    /// root of a negative number.
+    Domain,
+}
+
+impl Display for DecimalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DecimalError::Overflow => write!(f, "arithmetic overflow"),
+            DecimalError::Underflow => write!(f, "arithmetic underflow"),
+            DecimalError::NaN => write!(f, "NaN produced"),
+            DecimalError::Domain => write!(f, "operand outside of the domain of the operation"),
+        }
+    }
+}
+
+impl Error for DecimalError {}
@@ -1,20 +1,92 @@
-use std::{fmt::Display, ops::*};
+pub mod caps;
+mod checked;
+#[cfg(feature = "conformance")]
+pub mod conformance;
+mod error;
+mod rounding;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod series;
+pub mod special;
 
+use std::{
+    cmp::Ordering,
+    f64::consts::{E, PI},
+    fmt::{Debug, Display},
+    iter::{Product, Sum},
+    ops::*,
+    str::FromStr,
+};
+
+pub use checked::ArithmeticPolicy;
+pub use error::{DecimalError, ParseDecimalError};
+pub use rounding::RoundingMode;
+
+/// Arithmetic on references to a number, which is implemented for `&N` of every [`BaseNumber`]
+/// `N`.
+///
+/// Bounds on references can not be implied by a trait, so generic code which combines
+/// references with each other, as in `&a + &b`, needs `for<'a> &'a N: RefNumber<N>` in its
+/// where-clause. Combining an owned number with a reference (`a + &b`) needs no extra bounds.
+pub trait RefNumber<Base>:
+    Add<Base, Output = Base>
+    + for<'a> Add<&'a Base, Output = Base>
+    + Sub<Base, Output = Base>
+    + for<'a> Sub<&'a Base, Output = Base>
+    + Mul<Base, Output = Base>
+    + for<'a> Mul<&'a Base, Output = Base>
+    + Div<Base, Output = Base>
+    + for<'a> Div<&'a Base, Output = Base>
+    + Neg<Output = Base>
+{
+}
+
+impl<T, Base> RefNumber<Base> for T where
+    T: Add<Base, Output = Base>
+        + for<'a> Add<&'a Base, Output = Base>
+        + Sub<Base, Output = Base>
+        + for<'a> Sub<&'a Base, Output = Base>
+        + Mul<Base, Output = Base>
+        + for<'a> Mul<&'a Base, Output = Base>
+        + Div<Base, Output = Base>
+        + for<'a> Div<&'a Base, Output = Base>
+        + Neg<Output = Base>
+{
+}
+
+/// A number type which the rest of the engine can be written against once and run on any
+/// backend.
+///
+/// Every operator returns `Self` and accepts either an owned number or a reference on the
+/// right-hand side; see [`RefNumber`] for operators with a reference on the left-hand side.
 pub trait BaseNumber:
-    Add
+    Add<Output = Self>
+    + for<'a> Add<&'a Self, Output = Self>
     + AddAssign
-    + Display
-    + Div
-    + DivAssign
-    + Mul
-    + MulAssign
-    + Neg
-    + Sub
+    + for<'a> AddAssign<&'a Self>
+    + Sub<Output = Self>
+    + for<'a> Sub<&'a Self, Output = Self>
     + SubAssign
+    + for<'a> SubAssign<&'a Self>
+    + Mul<Output = Self>
+    + for<'a> Mul<&'a Self, Output = Self>
+    + MulAssign
+    + for<'a> MulAssign<&'a Self>
+    + Div<Output = Self>
+    + for<'a> Div<&'a Self, Output = Self>
+    + DivAssign
+    + for<'a> DivAssign<&'a Self>
+    + Neg<Output = Self>
+    + Sum
+    + for<'a> Sum<&'a Self>
+    + Product
+    + for<'a> Product<&'a Self>
+    + Clone
+    + Debug
+    + Display
     + Sized
-    + Neg
-    + PartialEq
     + PartialEq
+    + PartialOrd
     + From<i8>
     + From<i16>
     + From<i32>
@@ -30,7 +102,19 @@ pub trait BaseNumber:
     + From<f32>
     + From<f64>
     + From<String>
+    + FromStr<Err = ParseDecimalError>
 {
+    /// Zero.
+    const ZERO: Self;
+    /// One.
+    const ONE: Self;
+    /// Not a Number.
+    const NAN: Self;
+    /// Positive infinity.
+    const INFINITY: Self;
+    /// Negative infinity.
+    const NEG_INFINITY: Self;
+
     fn new(value: f64) -> Self {
         Self::from_number(value)
     }
@@ -39,8 +123,17 @@ pub trait BaseNumber:
         Self::from(value)
     }
 
+    /// Parses a number from the given string, without panicking on invalid input.
+    fn try_from_str(string: &str) -> Result<Self, ParseDecimalError> {
+        string.parse()
+    }
+
     // Conversion
     fn to_number(&self) -> f64;
+    /// Returns a string which [`BaseNumber::try_from_str`] parses back into the same number.
+    fn to_canonical_string(&self) -> String {
+        self.to_string()
+    }
     fn to_exponential(&self, places: u32) -> String;
     fn to_fixed(&self, places: u32) -> String;
     fn to_precision(&self, places: u32) -> String;
@@ -73,17 +166,352 @@ pub trait BaseNumber:
     /// Returns the sign of the number.
     fn sign(&self) -> i32;
 
+    // Rounding
+    //
+    // The defaults scale the number by a power of ten and round it to an integer, which is
+    // exact for backends that store numbers in decimal.
+
+    /// Rounds the number to the given number of decimal places. Negative places round to the
+    /// left of the decimal point, so `-2` rounds to hundreds.
+    fn round_to(&self, places: i64, mode: RoundingMode) -> Self {
+        if !rounding::is_finite_nonzero(self) {
+            return self.clone();
+        }
+
+        let scale = Self::from(10).pow(&Self::from(places as f64));
+        let scaled = self.clone() * &scale;
+        // Places whose scale leaves the range of the backend are past the last digit it holds,
+        // and so are those of a number which scales to an integer.
+        if checked::is_infinite(&scale)
+            || checked::is_infinite(&scaled)
+            || scaled != Self::ZERO && scaled.trunc() == scaled
+        {
+            return self.clone();
+        }
+
+        mode.round(&scaled) / scale
+    }
+    /// Rounds the number to the given number of significant digits, at least one.
+    fn round_to_significant(&self, digits: u32, mode: RoundingMode) -> Self {
+        if !rounding::is_finite_nonzero(self) {
+            return self.clone();
+        }
+
+        let magnitude = self.abs().log10().floor().to_number();
+        self.round_to(digits.max(1) as i64 - 1 - magnitude as i64, mode)
+    }
+    /// Formats the number like [`BaseNumber::to_fixed`], rounding with the given mode.
+    fn to_fixed_with_rounding(&self, places: u32, mode: RoundingMode) -> String {
+        self.round_to(places as i64, mode).to_fixed(places)
+    }
+    /// Formats the number like [`BaseNumber::to_exponential`], rounding with the given mode.
+    fn to_exponential_with_rounding(&self, places: u32, mode: RoundingMode) -> String {
+        self.round_to_significant(places + 1, mode)
+            .to_exponential(places)
+    }
+    /// Formats the number like [`BaseNumber::to_precision`], rounding with the given mode.
+    fn to_precision_with_rounding(&self, digits: u32, mode: RoundingMode) -> String {
+        self.round_to_significant(digits, mode).to_precision(digits)
+    }
+
+    // Transcendental functions
+    //
+    // The defaults are accurate to about the precision of an f64 for every number, as long as
+    // `exp` and `pow` are.
+    /// Returns e raised to the power of the number.
+    fn exp(&self) -> Self {
+        Self::from(E).pow(self)
+    }
+    /// Returns the logarithm in the given base.
+    fn log(&self, base: &Self) -> Self {
+        self.ln() / base.ln()
+    }
+    /// Returns 10 raised to the power of the number.
+    fn pow10(&self) -> Self {
+        Self::from(10).pow(self)
+    }
+    /// Returns the root of the given degree. Negative numbers have roots of odd integer
+    /// degrees, which are negative.
+    fn root(&self, degree: &Self) -> Self {
+        let half = degree.clone() / Self::from(2);
+        let odd = degree.trunc() == *degree && half.trunc() != half;
+
+        if odd && self.sign() < 0 {
+            -(-self.clone()).pow(&degree.recip())
+        } else {
+            self.pow(&degree.recip())
+        }
+    }
+    /// Returns the hyperbolic sine.
+    fn sinh(&self) -> Self {
+        let x = self.to_number();
+        if x.abs() < HYPERBOLIC_LIMIT {
+            near_identity(self, f64::sinh)
+        } else {
+            let half = self.abs().exp() / Self::from(2);
+            if x < 0.0 {
+                -half
+            } else {
+                half
+            }
+        }
+    }
+    /// Returns the hyperbolic cosine.
+    fn cosh(&self) -> Self {
+        let x = self.to_number();
+        if x.abs() < HYPERBOLIC_LIMIT {
+            Self::from(x.cosh())
+        } else {
+            self.abs().exp() / Self::from(2)
+        }
+    }
+    /// Returns the hyperbolic tangent.
+    fn tanh(&self) -> Self {
+        near_identity(self, f64::tanh)
+    }
+    /// Returns the sine of an angle in radians. Numbers too large for an f64 are too coarse to
+    /// have one, and give NaN.
+    fn sin(&self) -> Self {
+        near_identity(self, f64::sin)
+    }
+    /// Returns the cosine of an angle in radians. Numbers too large for an f64 are too coarse
+    /// to have one, and give NaN.
+    fn cos(&self) -> Self {
+        Self::from(self.to_number().cos())
+    }
+    /// Returns the tangent of an angle in radians. Numbers too large for an f64 are too
+    /// coarse to have one, and give NaN.
+    fn tan(&self) -> Self {
+        near_identity(self, f64::tan)
+    }
+    /// Returns the factorial, `Γ(x + 1)`, which is also defined for non-integers.
+    fn factorial(&self) -> Self {
+        (self.clone() + Self::ONE).gamma()
+    }
+    /// Returns the gamma function, which is infinite at zero and NaN at the negative integers.
+    ///
+    /// Above 171 the result comes from `ln Γ` in an f64, so its relative error grows to about
+    /// `x ln(x)` units in the last place.
+    fn gamma(&self) -> Self {
+        let x = self.to_number();
+        if x.abs() < f64::MIN_POSITIVE && self.sign() != 0 {
+            // Γ(x) = 1/x - γ + O(x), where γ is too small to matter.
+            self.recip()
+        } else if x > special::MAX_FACTORIAL + 1.0 {
+            Self::from(special::ln_gamma(x)).exp()
+        } else if x < -special::MAX_FACTORIAL && x.fract() != 0.0 {
+            // The reflection formula, for results too small for an f64.
+            Self::from(PI) / (Self::from(special::sin_pi(x)) * (Self::ONE - self.clone()).gamma())
+        } else {
+            Self::from(special::gamma(x))
+        }
+    }
+    /// Returns the principal branch of the Lambert W function, the inverse of `w e^w`, which
+    /// is NaN below `-1/e`.
+    fn lambert_w(&self) -> Self {
+        if self.to_number() == f64::INFINITY && *self != Self::INFINITY {
+            // Iterate W(x) = ln(x) - ln(W(x)), which gains a factor of W(x) in accuracy each
+            // time, so it converges quickly for numbers this large.
+            let ln = self.ln();
+            let mut w = ln.clone() - ln.ln();
+            for _ in 0..16 {
+                let next = ln.clone() - w.ln();
+                if next == w {
+                    break;
+                }
+                w = next;
+            }
+            w
+        } else {
+            near_identity(self, special::lambert_w)
+        }
+    }
+
+    // Checked arithmetic
+    //
+    // The checked methods return an error instead of a result which is not a finite number, so
+    // that NaN can not spread from one bad formula into the rest of the game. The saturating
+    // methods clamp the result to `[-max, max]` instead, and turn NaN into zero. See
+    // [`ArithmeticPolicy`] for choosing between them at each call site.
+
+    /// Adds a number, returning an error if the sum is not finite.
+    fn checked_add(&self, other: &Self) -> Result<Self, DecimalError> {
+        checked::check(self.clone() + other, &[self, other], false)
+    }
+    /// Subtracts a number, returning an error if the difference is not finite.
+    fn checked_sub(&self, other: &Self) -> Result<Self, DecimalError> {
+        checked::check(self.clone() - other, &[self, other], false)
+    }
+    /// Multiplies by a number, returning an error if the product is not finite or underflows.
+    fn checked_mul(&self, other: &Self) -> Result<Self, DecimalError> {
+        let nonzero = *self != Self::ZERO && *other != Self::ZERO;
+        checked::check(self.clone() * other, &[self, other], nonzero)
+    }
+    /// Divides by a number, returning an error if the divisor is zero or the quotient is not
+    /// finite or underflows.
+    fn checked_div(&self, other: &Self) -> Result<Self, DecimalError> {
+        checked::domain(*other != Self::ZERO, &[self, other])?;
+        let nonzero = *self != Self::ZERO && !checked::is_infinite(other);
+        checked::check(self.clone() / other, &[self, other], nonzero)
+    }
+    /// Raises the number to a power, returning an error if a negative number is raised to a
+    /// non-integer power, zero to a negative power, or the result is not finite or underflows.
+    fn checked_pow(&self, exponent: &Self) -> Result<Self, DecimalError> {
+        let defined = if BaseNumber::lt(self, &Self::ZERO) {
+            exponent.trunc() == *exponent
+        } else {
+            *self != Self::ZERO || !BaseNumber::lt(exponent, &Self::ZERO)
+        };
+        checked::domain(defined, &[self, exponent])?;
+
+        // A power of a number other than zero only vanishes if it underflows, unless the
+        // exponent takes it towards zero on purpose.
+        let nonzero = *self != Self::ZERO && !checked::is_infinite(exponent);
+        checked::check(self.pow(exponent), &[self, exponent], nonzero)
+    }
+    /// Returns the square root, or an error for a negative number.
+    fn checked_sqrt(&self) -> Result<Self, DecimalError> {
+        checked::domain(!BaseNumber::lt(self, &Self::ZERO), &[self])?;
+        checked::check(self.sqrt(), &[self], false)
+    }
+    /// Adds a number, clamping the sum to `[-max, max]`.
+    fn saturating_add(&self, other: &Self, max: &Self) -> Self {
+        checked::saturate(self.clone() + other, max)
+    }
+    /// Subtracts a number, clamping the difference to `[-max, max]`.
+    fn saturating_sub(&self, other: &Self, max: &Self) -> Self {
+        checked::saturate(self.clone() - other, max)
+    }
+    /// Multiplies by a number, clamping the product to `[-max, max]`.
+    fn saturating_mul(&self, other: &Self, max: &Self) -> Self {
+        checked::saturate(self.clone() * other, max)
+    }
+    /// Divides by a number, clamping the quotient to `[-max, max]`.
+    fn saturating_div(&self, other: &Self, max: &Self) -> Self {
+        checked::saturate(self.clone() / other, max)
+    }
+    /// Raises the number to a power, clamping the result to `[-max, max]`.
+    fn saturating_pow(&self, exponent: &Self, max: &Self) -> Self {
+        checked::saturate(self.pow(exponent), max)
+    }
+    /// Returns the square root, clamped to `max`, or zero for a negative number.
+    fn saturating_sqrt(&self, max: &Self) -> Self {
+        checked::saturate(self.sqrt(), max)
+    }
+
+    // Series
+    //
+    // Costs of buying many items at once, when the price rises with each one. See
+    // [`series`] for the generic implementations.
+
+    /// Returns how many items `resources` buys, when the first costs `start * ratio^owned` and
+    /// each one costs `ratio` times the one before.
+    fn afford_geometric_series(resources: &Self, start: &Self, ratio: &Self, owned: &Self) -> Self {
+        series::afford_geometric_series(resources, start, ratio, owned)
+    }
+    /// Returns the cost of buying `count` items, when the first costs `start * ratio^owned` and
+    /// each one costs `ratio` times the one before.
+    fn sum_geometric_series(count: &Self, start: &Self, ratio: &Self, owned: &Self) -> Self {
+        series::sum_geometric_series(count, start, ratio, owned)
+    }
+    /// Returns how many items `resources` buys, when the first costs `start + owned * increase`
+    /// and each one costs `increase` more than the one before.
+    fn afford_arithmetic_series(
+        resources: &Self,
+        start: &Self,
+        increase: &Self,
+        owned: &Self,
+    ) -> Self {
+        series::afford_arithmetic_series(resources, start, increase, owned)
+    }
+    /// Returns the cost of buying `count` items, when the first costs `start + owned * increase`
+    /// and each one costs `increase` more than the one before.
+    fn sum_arithmetic_series(count: &Self, start: &Self, increase: &Self, owned: &Self) -> Self {
+        series::sum_arithmetic_series(count, start, increase, owned)
+    }
+    /// Returns how long a purchase takes to pay for itself, from its cost, the current rate of
+    /// income and the rate it adds. Lower is better.
+    fn efficiency_of_purchase(cost: &Self, current_rate: &Self, added_rate: &Self) -> Self {
+        series::efficiency_of_purchase(cost, current_rate, added_rate)
+    }
+
     // Comparison (shorthand)
     /// Returns if the number is not equal to another number.
     fn neq(&self, other: &Self) -> bool {
         !self.eq(other)
     }
     /// Returns if the number is greater than another number.
-    fn gt(&self, other: &Self) -> bool;
+    fn gt(&self, other: &Self) -> bool {
+        PartialOrd::gt(self, other)
+    }
     /// Returns if the number is greater than or equal to another number.
-    fn gte(&self, other: &Self) -> bool;
+    fn gte(&self, other: &Self) -> bool {
+        PartialOrd::ge(self, other)
+    }
     /// Returns if the number is less than another number.
-    fn lt(&self, other: &Self) -> bool;
+    fn lt(&self, other: &Self) -> bool {
+        PartialOrd::lt(self, other)
+    }
     /// Returns if the number is less than or equal to another number.
-    fn lte(&self, other: &Self) -> bool;
+    fn lte(&self, other: &Self) -> bool {
+        PartialOrd::le(self, other)
+    }
+
+    // Bounds
+    //
+    // Unlike `f64::max` and `f64::min`, these propagate NaN like every other operation, so
+    // that a NaN in a modifier stack shows up in its result.
+    /// Returns the larger of two numbers, or NaN if either is NaN.
+    fn max(&self, other: &Self) -> Self {
+        match self.partial_cmp(other) {
+            Some(Ordering::Less) => other.clone(),
+            Some(_) => self.clone(),
+            None => Self::NAN,
+        }
+    }
+    /// Returns the smaller of two numbers, or NaN if either is NaN.
+    fn min(&self, other: &Self) -> Self {
+        match self.partial_cmp(other) {
+            Some(Ordering::Greater) => other.clone(),
+            Some(_) => self.clone(),
+            None => Self::NAN,
+        }
+    }
+    /// Returns the number raised to at least `min`.
+    fn clamp_min(&self, min: &Self) -> Self {
+        BaseNumber::max(self, min)
+    }
+    /// Returns the number lowered to at most `max`.
+    fn clamp_max(&self, max: &Self) -> Self {
+        BaseNumber::min(self, max)
+    }
+    /// Returns the number restricted to `[min, max]`, or NaN if any of them is NaN.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    fn clamp(&self, min: &Self, max: &Self) -> Self {
+        assert!(
+            !BaseNumber::gt(min, max),
+            "clamp with a minimum of {} above the maximum of {}",
+            min,
+            max
+        );
+        self.clamp_min(min).clamp_max(max)
+    }
+}
+
+/// The argument beyond which `e^-x` is negligible next to `e^x`, and the f64 hyperbolic
+/// functions are close to overflowing.
+const HYPERBOLIC_LIMIT: f64 = 700.0;
+
+/// Applies an f64 function which is the identity near zero, keeping numbers too small for a
+/// normal f64 as they are instead of losing their digits.
+fn near_identity<N: BaseNumber>(number: &N, function: fn(f64) -> f64) -> N {
+    let x = number.to_number();
+    if x.abs() < f64::MIN_POSITIVE {
+        number.clone()
+    } else {
+        N::from(function(x))
+    }
 }
@@ -0,0 +1,52 @@
+use crate::{checked, BaseNumber};
+
+/// How a number is rounded to the digits that are kept.
+///
+/// Ties are decided on the decimal digits of a number, so `2.5` is a tie for every backend,
+/// while `2.675` is one only for backends which store it in decimal.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds to the nearest value, and ties to the one with an even last digit.
+    #[default]
+    HalfEven,
+    /// Rounds to the nearest value, and ties away from zero.
+    HalfUp,
+    /// Drops the digits which are not kept.
+    TowardZero,
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds towards positive infinity.
+    Ceil,
+}
+
+impl RoundingMode {
+    /// Rounds a number to an integer.
+    pub fn round<N: BaseNumber>(self, number: &N) -> N {
+        match self {
+            RoundingMode::HalfEven => {
+                let rounded = number.round();
+                let half = N::from(0.5);
+                if (rounded.clone() - number).abs() == half && !is_even(&rounded) {
+                    // `round` ties away from zero, so the even neighbour is on the other side.
+                    N::from(2) * number - rounded
+                } else {
+                    rounded
+                }
+            }
+            RoundingMode::HalfUp => number.round(),
+            RoundingMode::TowardZero => number.trunc(),
+            RoundingMode::Floor => number.floor(),
+            RoundingMode::Ceil => number.ceil(),
+        }
+    }
+}
+
+fn is_even<N: BaseNumber>(integer: &N) -> bool {
+    let half = integer.clone() / N::from(2);
+    half.trunc() == half
+}
+
+/// Returns whether rounding can change the number.
+pub(crate) fn is_finite_nonzero<N: BaseNumber>(number: &N) -> bool {
+    !checked::is_nan(number) && !checked::is_infinite(number) && *number != N::ZERO
+}
@@ -0,0 +1,30 @@
+//! Serde helpers which work for any [`BaseNumber`].
+
+/// Serializes a number as its canonical string, for use with `#[serde(with = "...")]`.
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Save<N: BaseNumber> {
+///     #[serde(with = "number_base::serde::string")]
+///     coins: N,
+/// }
+/// ```
+pub mod string {
+    use ::serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    use crate::BaseNumber;
+
+    pub fn serialize<N: BaseNumber, S: Serializer>(
+        value: &N,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_canonical_string())
+    }
+
+    pub fn deserialize<'de, N: BaseNumber, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<N, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        N::try_from_str(&string).map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,103 @@
+//! The generic implementations of the series methods of [`BaseNumber`], which backends that
+//! override those methods can fall back on for inputs they do not optimize.
+//!
+//! Prices grow with each purchase, either by a constant ratio (geometric) or by a constant
+//! amount (arithmetic). `owned` is how many items have already been bought, which has raised
+//! the price of the next one. Counts are computed in closed form, so they take the same time
+//! for a billion items as for one.
+
+use crate::BaseNumber;
+
+/// Returns how many items `resources` buys, when the first costs `start * ratio^owned` and
+/// each one costs `ratio` times the one before.
+pub fn afford_geometric_series<N: BaseNumber>(resources: &N, start: &N, ratio: &N, owned: &N) -> N {
+    let first = start.clone() * ratio.pow(owned);
+    let count = if *ratio == N::ONE {
+        resources.clone() / first
+    } else {
+        // first (ratio^n - 1) / (ratio - 1) <= resources, solved for n.
+        (resources.clone() / first * (ratio.clone() - N::ONE) + N::ONE).log10() / ratio.log10()
+    };
+
+    correct_count(count, resources, |count| {
+        sum_geometric_series(count, start, ratio, owned)
+    })
+}
+
+/// Returns the cost of buying `count` items, when the first costs `start * ratio^owned` and
+/// each one costs `ratio` times the one before.
+pub fn sum_geometric_series<N: BaseNumber>(count: &N, start: &N, ratio: &N, owned: &N) -> N {
+    let first = start.clone() * ratio.pow(owned);
+    if *ratio == N::ONE {
+        first * count
+    } else {
+        first * (ratio.pow(count) - N::ONE) / (ratio.clone() - N::ONE)
+    }
+}
+
+/// Returns how many items `resources` buys, when the first costs `start + owned * increase`
+/// and each one costs `increase` more than the one before.
+pub fn afford_arithmetic_series<N: BaseNumber>(
+    resources: &N,
+    start: &N,
+    increase: &N,
+    owned: &N,
+) -> N {
+    let first = start.clone() + owned.clone() * increase;
+
+    // The root of increase/2 n^2 + (first - increase/2) n - resources, written so that it
+    // does not cancel when the increase is small next to the first price, or zero.
+    let b = first - increase.clone() / N::from(2);
+    let discriminant = b.clone() * &b + N::from(2) * increase * resources;
+    let count = N::from(2) * resources / (b + discriminant.sqrt());
+
+    correct_count(count, resources, |count| {
+        sum_arithmetic_series(count, start, increase, owned)
+    })
+}
+
+/// Returns the cost of buying `count` items, when the first costs `start + owned * increase`
+/// and each one costs `increase` more than the one before.
+pub fn sum_arithmetic_series<N: BaseNumber>(count: &N, start: &N, increase: &N, owned: &N) -> N {
+    let first = start.clone() + owned.clone() * increase;
+    count.clone() / N::from(2) * (N::from(2) * first + (count.clone() - N::ONE) * increase)
+}
+
+/// Returns how long a purchase takes to pay for itself: the time to save up its `cost` at the
+/// current rate of income, plus the time for the added rate to earn the cost back. Lower is
+/// better.
+pub fn efficiency_of_purchase<N: BaseNumber>(cost: &N, current_rate: &N, added_rate: &N) -> N {
+    cost.clone() / current_rate + cost.clone() / added_rate
+}
+
+/// The largest count which is checked against the cost, below which every integer is exact
+/// in an f64.
+const MAX_CORRECTED_COUNT: f64 = 9_007_199_254_740_992.0;
+
+/// Floors an estimated count of affordable items, and corrects it by one where rounding error
+/// has pushed the estimate across an integer.
+///
+/// Counts too large to tell apart from their neighbours are only floored.
+pub fn correct_count<N: BaseNumber>(count: N, resources: &N, sum: impl Fn(&N) -> N) -> N {
+    let count = count.floor();
+    if !BaseNumber::gte(&count, &N::ZERO) {
+        // Negative, or NaN from resources which do not buy anything.
+        return N::ZERO;
+    } else if count.to_number() >= MAX_CORRECTED_COUNT {
+        return count;
+    }
+
+    // Every candidate is built from an exact f64, so that it is an integer in every backend.
+    let estimate = count.to_number();
+    let count = N::from(estimate);
+    if estimate > 0.0 && BaseNumber::gt(&sum(&count), resources) {
+        N::from(estimate - 1.0)
+    } else {
+        let next = N::from(estimate + 1.0);
+        if BaseNumber::lte(&sum(&next), resources) {
+            next
+        } else {
+            count
+        }
+    }
+}
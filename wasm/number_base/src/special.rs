@@ -0,0 +1,150 @@
+//! Special functions on f64, which the default methods of [`BaseNumber`](crate::BaseNumber)
+//! build on.
+
+use std::f64::consts::{E, PI};
+
+/// `ln(2π) / 2`.
+const HALF_LN_TAU: f64 = 0.918_938_533_204_672_8;
+
+/// The coefficients `B_2k / (2k (2k - 1))` of the Stirling series for `ln Γ`.
+const STIRLING: [f64; 7] = [
+    1.0 / 12.0,
+    -1.0 / 360.0,
+    1.0 / 1260.0,
+    -1.0 / 1680.0,
+    1.0 / 1188.0,
+    -691.0 / 360360.0,
+    1.0 / 156.0,
+];
+
+/// The smallest argument for which the Stirling series is accurate to an f64.
+const STIRLING_MIN: f64 = 10.0;
+
+/// The largest integer whose factorial fits in an f64.
+pub const MAX_FACTORIAL: f64 = 170.0;
+
+/// Returns `ln Γ(x)` for a positive `x`, and NaN otherwise.
+pub fn ln_gamma(x: f64) -> f64 {
+    if x.is_nan() || x <= 0.0 {
+        return f64::NAN;
+    } else if x == f64::INFINITY {
+        return f64::INFINITY;
+    }
+
+    // Γ(x) = Γ(x + n) / (x (x + 1) ... (x + n - 1)) moves small arguments to where the
+    // series converges.
+    let mut shifted = x;
+    let mut product = 1.0;
+    while shifted < STIRLING_MIN {
+        product *= shifted;
+        shifted += 1.0;
+    }
+
+    let inverse = shifted.recip();
+    let squared = inverse * inverse;
+    let series = STIRLING
+        .iter()
+        .rev()
+        .fold(0.0, |sum, coefficient| sum * squared + coefficient)
+        * inverse;
+
+    (shifted - 0.5) * shifted.ln() - shifted + HALF_LN_TAU + series - product.ln()
+}
+
+/// Returns `Γ(x)`, which is infinite at zero and NaN at the negative integers.
+pub fn gamma(x: f64) -> f64 {
+    if x.is_nan() || x == f64::NEG_INFINITY {
+        f64::NAN
+    } else if x == 0.0 {
+        f64::INFINITY
+    } else if x < 0.0 {
+        if x.fract() == 0.0 {
+            return f64::NAN;
+        }
+
+        // The reflection formula, Γ(x) Γ(1 - x) = π / sin(πx).
+        PI / (sin_pi(x) * gamma(1.0 - x))
+    } else if x.fract() == 0.0 && x <= MAX_FACTORIAL + 1.0 {
+        // Products of integers are exact for as long as they fit in the mantissa.
+        (2..x as u32).fold(1.0, |product, factor| product * factor as f64)
+    } else {
+        ln_gamma(x).exp()
+    }
+}
+
+/// Returns `sin(πx)`, which is exactly zero at the integers.
+pub fn sin_pi(x: f64) -> f64 {
+    // The remainder and the reflections are exact, so no error is added to the argument.
+    let mut reduced = x % 2.0;
+    if reduced > 1.0 {
+        reduced -= 2.0;
+    } else if reduced < -1.0 {
+        reduced += 2.0;
+    }
+
+    if reduced > 0.5 {
+        (PI * (1.0 - reduced)).sin()
+    } else if reduced < -0.5 {
+        (PI * (-1.0 - reduced)).sin()
+    } else {
+        (PI * reduced).sin()
+    }
+}
+
+/// Returns the principal branch of the Lambert W function, the inverse of `w e^w` for
+/// `w >= -1`. It is NaN below `-1/e`.
+pub fn lambert_w(x: f64) -> f64 {
+    if x.is_nan() || x < -1.0 / E {
+        return f64::NAN;
+    } else if x == 0.0 || x == f64::INFINITY {
+        return x;
+    }
+
+    if x >= 3.0 {
+        // Solve w + ln(w) = ln(x) instead, which can not overflow.
+        let ln = x.ln();
+        let mut w = ln - ln.ln() + ln.ln() / ln;
+        for _ in 0..32 {
+            let step = (w + w.ln() - ln) / (1.0 + w.recip());
+            w -= step;
+            if step.abs() <= f64::EPSILON * w {
+                break;
+            }
+        }
+        return w;
+    }
+
+    let mut w = if x < -0.25 {
+        // The series around the branch point, in p = sqrt(2 (e x + 1)).
+        let p = (2.0 * (E * x + 1.0)).max(0.0).sqrt();
+        let series = [
+            -1.0,
+            1.0,
+            -1.0 / 3.0,
+            11.0 / 72.0,
+            -43.0 / 540.0,
+            769.0 / 17280.0,
+            -221.0 / 8505.0,
+        ];
+        let w = series.iter().rev().fold(0.0, |sum, term| sum * p + term);
+        if p < 1e-3 {
+            // Halley's method converges slowly next to the branch point, where the series is
+            // already accurate.
+            return w;
+        }
+        w
+    } else {
+        x.ln_1p()
+    };
+
+    for _ in 0..32 {
+        let exponential = w.exp();
+        let error = w * exponential - x;
+        let step = error / (exponential * (w + 1.0) - (w + 2.0) * error / (2.0 * w + 2.0));
+        w -= step;
+        if step.abs() <= f64::EPSILON * w.abs() {
+            break;
+        }
+    }
+    w
+}
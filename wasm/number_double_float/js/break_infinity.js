@@ -0,0 +1,77 @@
+/**
+ * Names of the break_infinity.js static methods which forward to the instance method of the
+ * same name, e.g. `Decimal.add(a, b)` is `Decimal.fromValue(a).add(b)`.
+ */
+const STATIC_METHODS = [
+	"abs",
+	"neg",
+	"negate",
+	"negated",
+	"sign",
+	"sgn",
+	"round",
+	"floor",
+	"ceil",
+	"trunc",
+	"add",
+	"plus",
+	"sub",
+	"subtract",
+	"minus",
+	"mul",
+	"multiply",
+	"times",
+	"div",
+	"divide",
+	"dividedBy",
+	"recip",
+	"reciprocal",
+	"reciprocate",
+	"cmp",
+	"compare",
+	"eq",
+	"equals",
+	"neq",
+	"notEquals",
+	"lt",
+	"lte",
+	"gt",
+	"gte",
+	"max",
+	"min",
+	"clamp",
+	"clampMin",
+	"clampMax",
+	"eq_tolerance",
+	"neq_tolerance",
+	"lt_tolerance",
+	"lte_tolerance",
+	"gt_tolerance",
+	"gte_tolerance",
+	"log10",
+	"absLog10",
+	"pLog10",
+	"log",
+	"logarithm",
+	"log2",
+	"ln",
+	"pow",
+	"exp",
+	"sqr",
+	"sqrt",
+	"cube",
+	"cbrt",
+];
+
+/**
+ * Installs the static methods on the class of the given Decimal instance.
+ * wasm-bindgen cannot export them itself, as they share their names with instance methods.
+ */
+export function installStatics(instance) {
+	const Decimal = instance.constructor;
+	instance.free();
+
+	for (const name of STATIC_METHODS) {
+		Decimal[name] = (value, ...args) => Decimal.fromValue(value)[name](...args);
+	}
+}
@@ -0,0 +1,177 @@
+//! A compact binary encoding for [`Decimal`].
+//!
+//! Every Decimal takes exactly [`ENCODED_LEN`] bytes: the mantissa followed by the exponent,
+//! each as a little-endian f64. NaN is always written with the bit pattern of [`f64::NAN`]
+//! in both halves. Encoding normalizes Decimals created without normalizing them, and
+//! decoding rejects anything which is not a normalized Decimal, so a corrupted save cannot
+//! smuggle in numbers that arithmetic would not produce.
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    compat::{from_source, DecimalSource},
+    util::from_mantissa_exponent,
+    Decimal, EXP_LIMIT,
+};
+
+/// The number of bytes a single encoded Decimal takes.
+pub const ENCODED_LEN: usize = 16;
+
+/// An error which can be returned when decoding Decimals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryDecimalError {
+    /// The length of the input is not a multiple of [`ENCODED_LEN`].
+    InvalidLength(usize),
+    /// The Decimal at the given index is NaN, but not with the canonical NaN bit pattern.
+    NonCanonicalNan { index: usize },
+    /// The Decimal at the given index does not have a normalized mantissa and exponent.
+    NotNormalized { index: usize },
+}
+
+impl Display for BinaryDecimalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryDecimalError::InvalidLength(length) => write!(
+                f,
+                "length {} is not a multiple of {} bytes",
+                length, ENCODED_LEN
+            ),
+            BinaryDecimalError::NonCanonicalNan { index } => {
+                write!(f, "decimal {} is a non-canonical NaN", index)
+            }
+            BinaryDecimalError::NotNormalized { index } => {
+                write!(f, "decimal {} is not normalized", index)
+            }
+        }
+    }
+}
+
+impl Error for BinaryDecimalError {}
+
+/// Returns whether the mantissa and exponent form a normalized Decimal:
+/// zero, ±Infinity, or a mantissa in [1, 10) with an integer exponent.
+fn is_normalized(mantissa: f64, exponent: f64) -> bool {
+    if mantissa == 0.0 {
+        exponent == 0.0
+    } else if exponent == EXP_LIMIT {
+        mantissa.abs() == 1.0
+    } else {
+        mantissa.abs() >= 1.0
+            && mantissa.abs() < 10.0
+            && exponent.abs() < EXP_LIMIT
+            && exponent.fract() == 0.0
+    }
+}
+
+impl Decimal {
+    /// Encodes the Decimal into its 16-byte binary form, normalizing it first if it was
+    /// created without normalizing it.
+    pub fn to_bytes(&self) -> [u8; ENCODED_LEN] {
+        let (mantissa, exponent) = if self.is_nan() {
+            (f64::NAN, f64::NAN)
+        } else if is_normalized(self.mantissa, self.exponent) {
+            (self.mantissa, self.exponent)
+        } else {
+            let normalized = from_mantissa_exponent(self.mantissa, self.exponent);
+            (normalized.mantissa, normalized.exponent)
+        };
+
+        let mut bytes = [0; ENCODED_LEN];
+        bytes[..8].copy_from_slice(&mantissa.to_le_bytes());
+        bytes[8..].copy_from_slice(&exponent.to_le_bytes());
+        bytes
+    }
+
+    /// Decodes a Decimal from its 16-byte binary form.
+    pub fn from_bytes(bytes: &[u8; ENCODED_LEN]) -> Result<Decimal, BinaryDecimalError> {
+        decode(bytes, 0)
+    }
+}
+
+fn decode(bytes: &[u8; ENCODED_LEN], index: usize) -> Result<Decimal, BinaryDecimalError> {
+    let mut half = [0; 8];
+    half.copy_from_slice(&bytes[..8]);
+    let mantissa = f64::from_le_bytes(half);
+    half.copy_from_slice(&bytes[8..]);
+    let exponent = f64::from_le_bytes(half);
+
+    if f64::is_nan(mantissa) || f64::is_nan(exponent) {
+        return if mantissa.to_bits() == f64::NAN.to_bits()
+            && exponent.to_bits() == f64::NAN.to_bits()
+        {
+            Ok(Decimal { mantissa, exponent })
+        } else {
+            Err(BinaryDecimalError::NonCanonicalNan { index })
+        };
+    } else if !is_normalized(mantissa, exponent) {
+        return Err(BinaryDecimalError::NotNormalized { index });
+    }
+
+    Ok(Decimal {
+        mantissa: if mantissa == 0.0 { 0.0 } else { mantissa },
+        exponent,
+    })
+}
+
+/// Encodes the Decimals into consecutive 16-byte binary forms, as [`Decimal::to_bytes`] does.
+pub fn encode_slice(decimals: &[Decimal]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(decimals.len() * ENCODED_LEN);
+
+    for decimal in decimals {
+        bytes.extend_from_slice(&decimal.to_bytes());
+    }
+
+    bytes
+}
+
+/// Decodes Decimals from consecutive 16-byte binary forms.
+pub fn decode_slice(bytes: &[u8]) -> Result<Vec<Decimal>, BinaryDecimalError> {
+    if !bytes.len().is_multiple_of(ENCODED_LEN) {
+        return Err(BinaryDecimalError::InvalidLength(bytes.len()));
+    }
+
+    bytes
+        .chunks_exact(ENCODED_LEN)
+        .enumerate()
+        .map(|(index, chunk)| decode(chunk.try_into().unwrap(), index))
+        .collect()
+}
+
+#[wasm_bindgen]
+impl Decimal {
+    /// Encodes the Decimal into its 16-byte binary form.
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn js_to_bytes(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+
+    /// Decodes a Decimal from its 16-byte binary form.
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn js_from_bytes(bytes: &[u8]) -> Result<Decimal, JsError> {
+        let bytes: &[u8; ENCODED_LEN] = bytes
+            .try_into()
+            .map_err(|_| BinaryDecimalError::InvalidLength(bytes.len()))?;
+        Ok(Decimal::from_bytes(bytes)?)
+    }
+
+    /// Encodes the numbers into consecutive 16-byte binary forms.
+    #[wasm_bindgen(js_name = encodeSlice)]
+    pub fn js_encode_slice(decimals: Vec<DecimalSource>) -> Result<Vec<u8>, JsError> {
+        let decimals = decimals
+            .iter()
+            .map(from_source)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(encode_slice(&decimals))
+    }
+
+    /// Decodes Decimals from consecutive 16-byte binary forms.
+    #[wasm_bindgen(js_name = decodeSlice)]
+    pub fn js_decode_slice(bytes: &[u8]) -> Result<Vec<Decimal>, JsError> {
+        Ok(decode_slice(bytes)?)
+    }
+}
@@ -0,0 +1,301 @@
+use std::cmp::Ordering;
+
+use number_base::BaseNumber;
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    compat::{from_source, DecimalSource},
+    Decimal,
+};
+
+/// The JavaScript-facing API of Decimal.
+///
+/// Methods are prefixed with `js_` on the Rust side so that they do not shadow the operator
+/// traits and [`BaseNumber`], and are renamed to their camelCase equivalent for JavaScript.
+/// Operands are accepted as any `DecimalSource` (number, string or Decimal).
+#[wasm_bindgen]
+impl Decimal {
+    /// Creates a new instance of Decimal from the given number.
+    #[wasm_bindgen(constructor)]
+    pub fn js_new(value: f64) -> Decimal {
+        Decimal::new(value)
+    }
+
+    /// Creates a new instance of Decimal from the given string.
+    /// Throws if the string is not a valid number.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn js_from_string(value: &str) -> Result<Decimal, JsError> {
+        Ok(value.parse::<Decimal>()?)
+    }
+
+    /// Creates a new instance of Decimal with the given mantissa and exponent.
+    #[wasm_bindgen(js_name = fromMantissaExponent)]
+    pub fn js_from_mantissa_exponent(mantissa: f64, exponent: f64) -> Decimal {
+        Decimal::from_mantissa_exponent(mantissa, exponent)
+    }
+
+    /// Returns the mantissa.
+    #[wasm_bindgen(getter)]
+    pub fn mantissa(&self) -> f64 {
+        self.mantissa
+    }
+
+    /// Returns the exponent.
+    #[wasm_bindgen(getter)]
+    pub fn exponent(&self) -> f64 {
+        self.exponent
+    }
+
+    // Arithmetic
+    #[wasm_bindgen(js_name = add)]
+    pub fn js_add(&self, other: &DecimalSource) -> Result<Decimal, JsError> {
+        Ok(self + from_source(other)?)
+    }
+
+    #[wasm_bindgen(js_name = sub)]
+    pub fn js_sub(&self, other: &DecimalSource) -> Result<Decimal, JsError> {
+        Ok(self - from_source(other)?)
+    }
+
+    #[wasm_bindgen(js_name = mul)]
+    pub fn js_mul(&self, other: &DecimalSource) -> Result<Decimal, JsError> {
+        Ok(self * from_source(other)?)
+    }
+
+    #[wasm_bindgen(js_name = div)]
+    pub fn js_div(&self, other: &DecimalSource) -> Result<Decimal, JsError> {
+        Ok(self / from_source(other)?)
+    }
+
+    #[wasm_bindgen(js_name = neg)]
+    pub fn js_neg(&self) -> Decimal {
+        -self
+    }
+
+    #[wasm_bindgen(js_name = pow)]
+    pub fn js_pow(&self, exponent: &DecimalSource) -> Result<Decimal, JsError> {
+        Ok(BaseNumber::pow(self, &from_source(exponent)?))
+    }
+
+    /// Returns the logarithm in the given base.
+    #[wasm_bindgen(js_name = log)]
+    pub fn js_log(&self, base: &DecimalSource) -> Result<Decimal, JsError> {
+        Ok(BaseNumber::log(self, &from_source(base)?))
+    }
+
+    /// Returns the root of the given degree.
+    #[wasm_bindgen(js_name = root)]
+    pub fn js_root(&self, degree: &DecimalSource) -> Result<Decimal, JsError> {
+        Ok(self.root(&from_source(degree)?))
+    }
+
+    #[wasm_bindgen(js_name = sinh)]
+    pub fn js_sinh(&self) -> Decimal {
+        self.sinh()
+    }
+
+    #[wasm_bindgen(js_name = cosh)]
+    pub fn js_cosh(&self) -> Decimal {
+        self.cosh()
+    }
+
+    #[wasm_bindgen(js_name = tanh)]
+    pub fn js_tanh(&self) -> Decimal {
+        self.tanh()
+    }
+
+    #[wasm_bindgen(js_name = sin)]
+    pub fn js_sin(&self) -> Decimal {
+        self.sin()
+    }
+
+    #[wasm_bindgen(js_name = cos)]
+    pub fn js_cos(&self) -> Decimal {
+        self.cos()
+    }
+
+    #[wasm_bindgen(js_name = tan)]
+    pub fn js_tan(&self) -> Decimal {
+        self.tan()
+    }
+
+    #[wasm_bindgen(js_name = factorial)]
+    pub fn js_factorial(&self) -> Decimal {
+        self.factorial()
+    }
+
+    #[wasm_bindgen(js_name = gamma)]
+    pub fn js_gamma(&self) -> Decimal {
+        self.gamma()
+    }
+
+    /// Returns the principal branch of the Lambert W function.
+    #[wasm_bindgen(js_name = lambertw)]
+    pub fn js_lambert_w(&self) -> Decimal {
+        self.lambert_w()
+    }
+
+    #[wasm_bindgen(js_name = ln)]
+    pub fn js_ln(&self) -> Decimal {
+        self.ln()
+    }
+
+    #[wasm_bindgen(js_name = log10)]
+    pub fn js_log10(&self) -> Decimal {
+        self.log10()
+    }
+
+    #[wasm_bindgen(js_name = log2)]
+    pub fn js_log2(&self) -> Decimal {
+        self.log2()
+    }
+
+    #[wasm_bindgen(js_name = abs)]
+    pub fn js_abs(&self) -> Decimal {
+        BaseNumber::abs(self)
+    }
+
+    #[wasm_bindgen(js_name = round)]
+    pub fn js_round(&self) -> Decimal {
+        BaseNumber::round(self)
+    }
+
+    #[wasm_bindgen(js_name = trunc)]
+    pub fn js_trunc(&self) -> Decimal {
+        BaseNumber::trunc(self)
+    }
+
+    #[wasm_bindgen(js_name = floor)]
+    pub fn js_floor(&self) -> Decimal {
+        BaseNumber::floor(self)
+    }
+
+    #[wasm_bindgen(js_name = ceil)]
+    pub fn js_ceil(&self) -> Decimal {
+        BaseNumber::ceil(self)
+    }
+
+    #[wasm_bindgen(js_name = sqrt)]
+    pub fn js_sqrt(&self) -> Decimal {
+        BaseNumber::sqrt(self)
+    }
+
+    #[wasm_bindgen(js_name = cbrt)]
+    pub fn js_cbrt(&self) -> Decimal {
+        BaseNumber::cbrt(self)
+    }
+
+    #[wasm_bindgen(js_name = recip)]
+    pub fn js_recip(&self) -> Decimal {
+        BaseNumber::recip(self)
+    }
+
+    #[wasm_bindgen(js_name = sign)]
+    pub fn js_sign(&self) -> i32 {
+        self.sign()
+    }
+
+    #[wasm_bindgen(js_name = isNaN)]
+    pub fn js_is_nan(&self) -> bool {
+        self.is_nan()
+    }
+
+    #[wasm_bindgen(js_name = isFinite)]
+    pub fn js_is_finite(&self) -> bool {
+        self.is_finite()
+    }
+
+    #[wasm_bindgen(js_name = isInfinite)]
+    pub fn js_is_infinite(&self) -> bool {
+        self.is_infinite()
+    }
+
+    // Comparison
+    /// Returns -1, 0 or 1 depending on the ordering of the two numbers,
+    /// or `undefined` if either is NaN.
+    #[wasm_bindgen(js_name = cmp)]
+    pub fn js_cmp(&self, other: &DecimalSource) -> Result<Option<i32>, JsError> {
+        Ok(self
+            .partial_cmp(&from_source(other)?)
+            .map(|ordering| match ordering {
+                Ordering::Less => -1,
+                Ordering::Equal => 0,
+                Ordering::Greater => 1,
+            }))
+    }
+
+    #[wasm_bindgen(js_name = eq)]
+    pub fn js_eq(&self, other: &DecimalSource) -> Result<bool, JsError> {
+        Ok(*self == from_source(other)?)
+    }
+
+    #[wasm_bindgen(js_name = neq)]
+    pub fn js_neq(&self, other: &DecimalSource) -> Result<bool, JsError> {
+        Ok(BaseNumber::neq(self, &from_source(other)?))
+    }
+
+    #[wasm_bindgen(js_name = lt)]
+    pub fn js_lt(&self, other: &DecimalSource) -> Result<bool, JsError> {
+        Ok(BaseNumber::lt(self, &from_source(other)?))
+    }
+
+    #[wasm_bindgen(js_name = lte)]
+    pub fn js_lte(&self, other: &DecimalSource) -> Result<bool, JsError> {
+        Ok(BaseNumber::lte(self, &from_source(other)?))
+    }
+
+    #[wasm_bindgen(js_name = gt)]
+    pub fn js_gt(&self, other: &DecimalSource) -> Result<bool, JsError> {
+        Ok(BaseNumber::gt(self, &from_source(other)?))
+    }
+
+    #[wasm_bindgen(js_name = gte)]
+    pub fn js_gte(&self, other: &DecimalSource) -> Result<bool, JsError> {
+        Ok(BaseNumber::gte(self, &from_source(other)?))
+    }
+
+    // Conversion
+    #[wasm_bindgen(js_name = toNumber)]
+    pub fn js_to_number(&self) -> f64 {
+        self.to_number()
+    }
+
+    /// Allows JavaScript to coerce a Decimal into a primitive number.
+    #[wasm_bindgen(js_name = valueOf)]
+    pub fn js_value_of(&self) -> f64 {
+        self.to_number()
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn js_to_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the canonical string form, which `fromString` parses back into the same number.
+    #[wasm_bindgen(js_name = toCanonicalString)]
+    pub fn js_to_canonical_string(&self) -> String {
+        self.to_canonical_string()
+    }
+
+    /// Serializes the Decimal as its canonical string form, so it survives `JSON.stringify`
+    /// and `fromString` without losing precision.
+    #[wasm_bindgen(js_name = toJSON)]
+    pub fn js_to_json(&self) -> String {
+        self.to_canonical_string()
+    }
+
+    #[wasm_bindgen(js_name = toExponential)]
+    pub fn js_to_exponential(&self, places: u32) -> String {
+        self.to_exponential(places)
+    }
+
+    #[wasm_bindgen(js_name = toFixed)]
+    pub fn js_to_fixed(&self, places: u32) -> String {
+        self.to_fixed(places)
+    }
+
+    #[wasm_bindgen(js_name = toPrecision)]
+    pub fn js_to_precision(&self, places: u32) -> String {
+        self.to_precision(places)
+    }
+}
@@ -0,0 +1,460 @@
+//! A break_infinity.js-compatible surface over [`Decimal`].
+//!
+//! Instance methods are exported under every name break_infinity.js uses for them, and accept
+//! any `DecimalSource`. The static two-argument forms such as `Decimal.add(a, b)` cannot be
+//! exported by wasm-bindgen next to instance methods of the same name, so they are attached to
+//! the class by `js/break_infinity.js` when the module starts.
+
+use number_base::BaseNumber;
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    util::{from_mantissa_exponent, from_mantissa_exponent_no_normalize},
+    Decimal,
+};
+
+#[wasm_bindgen(typescript_custom_section)]
+const DECIMAL_SOURCE: &str = r#"
+export type DecimalSource = number | string | Decimal;
+
+export namespace Decimal {
+    function abs(value: DecimalSource): Decimal;
+    function neg(value: DecimalSource): Decimal;
+    function negate(value: DecimalSource): Decimal;
+    function negated(value: DecimalSource): Decimal;
+    function sign(value: DecimalSource): number;
+    function sgn(value: DecimalSource): number;
+    function round(value: DecimalSource): Decimal;
+    function floor(value: DecimalSource): Decimal;
+    function ceil(value: DecimalSource): Decimal;
+    function trunc(value: DecimalSource): Decimal;
+    function add(value: DecimalSource, other: DecimalSource): Decimal;
+    function plus(value: DecimalSource, other: DecimalSource): Decimal;
+    function sub(value: DecimalSource, other: DecimalSource): Decimal;
+    function subtract(value: DecimalSource, other: DecimalSource): Decimal;
+    function minus(value: DecimalSource, other: DecimalSource): Decimal;
+    function mul(value: DecimalSource, other: DecimalSource): Decimal;
+    function multiply(value: DecimalSource, other: DecimalSource): Decimal;
+    function times(value: DecimalSource, other: DecimalSource): Decimal;
+    function div(value: DecimalSource, other: DecimalSource): Decimal;
+    function divide(value: DecimalSource, other: DecimalSource): Decimal;
+    function dividedBy(value: DecimalSource, other: DecimalSource): Decimal;
+    function recip(value: DecimalSource): Decimal;
+    function reciprocal(value: DecimalSource): Decimal;
+    function reciprocate(value: DecimalSource): Decimal;
+    function cmp(value: DecimalSource, other: DecimalSource): number | undefined;
+    function compare(value: DecimalSource, other: DecimalSource): number | undefined;
+    function eq(value: DecimalSource, other: DecimalSource): boolean;
+    function equals(value: DecimalSource, other: DecimalSource): boolean;
+    function neq(value: DecimalSource, other: DecimalSource): boolean;
+    function notEquals(value: DecimalSource, other: DecimalSource): boolean;
+    function lt(value: DecimalSource, other: DecimalSource): boolean;
+    function lte(value: DecimalSource, other: DecimalSource): boolean;
+    function gt(value: DecimalSource, other: DecimalSource): boolean;
+    function gte(value: DecimalSource, other: DecimalSource): boolean;
+    function max(value: DecimalSource, other: DecimalSource): Decimal;
+    function min(value: DecimalSource, other: DecimalSource): Decimal;
+    function clamp(value: DecimalSource, min: DecimalSource, max: DecimalSource): Decimal;
+    function clampMin(value: DecimalSource, min: DecimalSource): Decimal;
+    function clampMax(value: DecimalSource, max: DecimalSource): Decimal;
+    function eq_tolerance(value: DecimalSource, other: DecimalSource, tolerance: number): boolean;
+    function neq_tolerance(value: DecimalSource, other: DecimalSource, tolerance: number): boolean;
+    function lt_tolerance(value: DecimalSource, other: DecimalSource, tolerance: number): boolean;
+    function lte_tolerance(value: DecimalSource, other: DecimalSource, tolerance: number): boolean;
+    function gt_tolerance(value: DecimalSource, other: DecimalSource, tolerance: number): boolean;
+    function gte_tolerance(value: DecimalSource, other: DecimalSource, tolerance: number): boolean;
+    function log10(value: DecimalSource): Decimal;
+    function absLog10(value: DecimalSource): Decimal;
+    function pLog10(value: DecimalSource): Decimal;
+    function log(value: DecimalSource, base: DecimalSource): Decimal;
+    function logarithm(value: DecimalSource, base: DecimalSource): Decimal;
+    function log2(value: DecimalSource): Decimal;
+    function ln(value: DecimalSource): Decimal;
+    function pow(value: DecimalSource, other: DecimalSource): Decimal;
+    function exp(value: DecimalSource): Decimal;
+    function sqr(value: DecimalSource): Decimal;
+    function sqrt(value: DecimalSource): Decimal;
+    function cube(value: DecimalSource): Decimal;
+    function cbrt(value: DecimalSource): Decimal;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    /// A number, string or Decimal-like object which can be converted into a Decimal.
+    #[wasm_bindgen(typescript_type = "DecimalSource")]
+    pub type DecimalSource;
+
+    #[wasm_bindgen(method, getter, structural)]
+    fn mantissa(this: &DecimalSource) -> JsValue;
+
+    #[wasm_bindgen(method, getter, structural)]
+    fn exponent(this: &DecimalSource) -> JsValue;
+}
+
+#[wasm_bindgen(module = "/js/break_infinity.js")]
+extern "C" {
+    #[wasm_bindgen(js_name = installStatics)]
+    fn install_statics(instance: Decimal);
+}
+
+/// Attaches the static break_infinity.js methods to the Decimal class.
+#[wasm_bindgen(start)]
+pub fn start() {
+    install_statics(Decimal::new(0.0));
+}
+
+/// Converts a `DecimalSource` into a Decimal.
+///
+/// Objects are read through their `mantissa` and `exponent` properties, so Decimals from
+/// break_infinity.js itself are accepted as well.
+pub(crate) fn from_source(value: &DecimalSource) -> Result<Decimal, JsError> {
+    if let Some(number) = value.as_f64() {
+        return Ok(Decimal::new(number));
+    } else if let Some(string) = value.as_string() {
+        return Ok(string.parse::<Decimal>()?);
+    } else if value.is_object() {
+        // The properties are read untyped, so that other values are rejected rather than
+        // thrown on.
+        if let (Some(mantissa), Some(exponent)) =
+            (value.mantissa().as_f64(), value.exponent().as_f64())
+        {
+            return Ok(from_mantissa_exponent(mantissa, exponent));
+        }
+    }
+
+    Err(JsError::new("Expected a number, string or Decimal"))
+}
+
+impl Decimal {
+    /// Returns whether the two numbers are equal within the given relative tolerance.
+    fn eq_tolerance(&self, other: &Decimal, tolerance: f64) -> bool {
+        let magnitude = if BaseNumber::gte(&self.abs(), &other.abs()) {
+            self.abs()
+        } else {
+            other.abs()
+        };
+
+        BaseNumber::lte(
+            &(self - other).abs(),
+            &(magnitude * Decimal::new(tolerance)),
+        )
+    }
+}
+
+#[wasm_bindgen]
+impl Decimal {
+    // Construction
+    #[wasm_bindgen(js_name = fromValue)]
+    pub fn js_from_value(value: &DecimalSource) -> Result<Decimal, JsError> {
+        from_source(value)
+    }
+
+    #[wasm_bindgen(js_name = fromNumber)]
+    pub fn js_from_number(value: f64) -> Decimal {
+        Decimal::new(value)
+    }
+
+    #[wasm_bindgen(js_name = fromDecimal)]
+    pub fn js_from_decimal(value: &Decimal) -> Decimal {
+        *value
+    }
+
+    #[wasm_bindgen(js_name = fromMantissaExponent_noNormalize)]
+    pub fn js_from_mantissa_exponent_no_normalize(mantissa: f64, exponent: f64) -> Decimal {
+        from_mantissa_exponent_no_normalize(mantissa, exponent)
+    }
+
+    /// Returns 10 raised to the given power.
+    #[wasm_bindgen(js_name = pow10)]
+    pub fn js_pow10(value: f64) -> Decimal {
+        Decimal::new(value).pow10()
+    }
+
+    #[wasm_bindgen(getter = m)]
+    pub fn js_m(&self) -> f64 {
+        self.mantissa
+    }
+
+    #[wasm_bindgen(getter = e)]
+    pub fn js_e(&self) -> f64 {
+        self.exponent
+    }
+
+    // Arithmetic aliases
+    #[wasm_bindgen(js_name = plus)]
+    pub fn js_plus(&self, other: &DecimalSource) -> Result<Decimal, JsError> {
+        self.js_add(other)
+    }
+
+    #[wasm_bindgen(js_name = subtract)]
+    pub fn js_subtract(&self, other: &DecimalSource) -> Result<Decimal, JsError> {
+        self.js_sub(other)
+    }
+
+    #[wasm_bindgen(js_name = minus)]
+    pub fn js_minus(&self, other: &DecimalSource) -> Result<Decimal, JsError> {
+        self.js_sub(other)
+    }
+
+    #[wasm_bindgen(js_name = multiply)]
+    pub fn js_multiply(&self, other: &DecimalSource) -> Result<Decimal, JsError> {
+        self.js_mul(other)
+    }
+
+    #[wasm_bindgen(js_name = times)]
+    pub fn js_times(&self, other: &DecimalSource) -> Result<Decimal, JsError> {
+        self.js_mul(other)
+    }
+
+    #[wasm_bindgen(js_name = divide)]
+    pub fn js_divide(&self, other: &DecimalSource) -> Result<Decimal, JsError> {
+        self.js_div(other)
+    }
+
+    #[wasm_bindgen(js_name = dividedBy)]
+    pub fn js_divided_by(&self, other: &DecimalSource) -> Result<Decimal, JsError> {
+        self.js_div(other)
+    }
+
+    #[wasm_bindgen(js_name = negate)]
+    pub fn js_negate(&self) -> Decimal {
+        -self
+    }
+
+    #[wasm_bindgen(js_name = negated)]
+    pub fn js_negated(&self) -> Decimal {
+        -self
+    }
+
+    #[wasm_bindgen(js_name = reciprocal)]
+    pub fn js_reciprocal(&self) -> Decimal {
+        self.recip()
+    }
+
+    #[wasm_bindgen(js_name = reciprocate)]
+    pub fn js_reciprocate(&self) -> Decimal {
+        self.recip()
+    }
+
+    #[wasm_bindgen(js_name = sgn)]
+    pub fn js_sgn(&self) -> i32 {
+        self.sign()
+    }
+
+    #[wasm_bindgen(js_name = sqr)]
+    pub fn js_sqr(&self) -> Decimal {
+        self * self
+    }
+
+    #[wasm_bindgen(js_name = cube)]
+    pub fn js_cube(&self) -> Decimal {
+        self * self * self
+    }
+
+    #[wasm_bindgen(js_name = exp)]
+    pub fn js_exp(&self) -> Decimal {
+        self.exp()
+    }
+
+    // Logarithm aliases
+    /// Returns the base 10 logarithm of the absolute value.
+    #[wasm_bindgen(js_name = absLog10)]
+    pub fn js_abs_log10(&self) -> Decimal {
+        self.abs().log10()
+    }
+
+    /// Returns the base 10 logarithm, or 0 for numbers below 1.
+    #[wasm_bindgen(js_name = pLog10)]
+    pub fn js_p_log10(&self) -> Decimal {
+        if BaseNumber::lt(self, &Decimal::new(1.0)) {
+            Decimal::new(0.0)
+        } else {
+            self.log10()
+        }
+    }
+
+    #[wasm_bindgen(js_name = logarithm)]
+    pub fn js_logarithm(&self, base: &DecimalSource) -> Result<Decimal, JsError> {
+        self.js_log(base)
+    }
+
+    // Comparison aliases
+    #[wasm_bindgen(js_name = compare)]
+    pub fn js_compare(&self, other: &DecimalSource) -> Result<Option<i32>, JsError> {
+        self.js_cmp(other)
+    }
+
+    #[wasm_bindgen(js_name = equals)]
+    pub fn js_equals(&self, other: &DecimalSource) -> Result<bool, JsError> {
+        self.js_eq(other)
+    }
+
+    #[wasm_bindgen(js_name = notEquals)]
+    pub fn js_not_equals(&self, other: &DecimalSource) -> Result<bool, JsError> {
+        self.js_neq(other)
+    }
+
+    #[wasm_bindgen(js_name = lessThan)]
+    pub fn js_less_than(&self, other: &DecimalSource) -> Result<bool, JsError> {
+        self.js_lt(other)
+    }
+
+    #[wasm_bindgen(js_name = lessThanOrEqualTo)]
+    pub fn js_less_than_or_equal_to(&self, other: &DecimalSource) -> Result<bool, JsError> {
+        self.js_lte(other)
+    }
+
+    #[wasm_bindgen(js_name = greaterThan)]
+    pub fn js_greater_than(&self, other: &DecimalSource) -> Result<bool, JsError> {
+        self.js_gt(other)
+    }
+
+    #[wasm_bindgen(js_name = greaterThanOrEqualTo)]
+    pub fn js_greater_than_or_equal_to(&self, other: &DecimalSource) -> Result<bool, JsError> {
+        self.js_gte(other)
+    }
+
+    #[wasm_bindgen(js_name = max)]
+    pub fn js_max(&self, other: &DecimalSource) -> Result<Decimal, JsError> {
+        Ok(BaseNumber::max(self, &from_source(other)?))
+    }
+
+    #[wasm_bindgen(js_name = min)]
+    pub fn js_min(&self, other: &DecimalSource) -> Result<Decimal, JsError> {
+        Ok(BaseNumber::min(self, &from_source(other)?))
+    }
+
+    /// Throws if the minimum is greater than the maximum.
+    #[wasm_bindgen(js_name = clamp)]
+    pub fn js_clamp(&self, min: &DecimalSource, max: &DecimalSource) -> Result<Decimal, JsError> {
+        let (min, max) = (from_source(min)?, from_source(max)?);
+        if BaseNumber::gt(&min, &max) {
+            return Err(JsError::new(&format!(
+                "clamp with a minimum of {} above the maximum of {}",
+                min, max
+            )));
+        }
+
+        Ok(BaseNumber::clamp(self, &min, &max))
+    }
+
+    #[wasm_bindgen(js_name = clampMin)]
+    pub fn js_clamp_min(&self, min: &DecimalSource) -> Result<Decimal, JsError> {
+        Ok(BaseNumber::clamp_min(self, &from_source(min)?))
+    }
+
+    #[wasm_bindgen(js_name = clampMax)]
+    pub fn js_clamp_max(&self, max: &DecimalSource) -> Result<Decimal, JsError> {
+        Ok(BaseNumber::clamp_max(self, &from_source(max)?))
+    }
+
+    /// Returns whether the numbers are equal within the given relative tolerance.
+    #[wasm_bindgen(js_name = eq_tolerance)]
+    pub fn js_eq_tolerance(&self, other: &DecimalSource, tolerance: f64) -> Result<bool, JsError> {
+        Ok(self.eq_tolerance(&from_source(other)?, tolerance))
+    }
+
+    #[wasm_bindgen(js_name = neq_tolerance)]
+    pub fn js_neq_tolerance(&self, other: &DecimalSource, tolerance: f64) -> Result<bool, JsError> {
+        Ok(!self.eq_tolerance(&from_source(other)?, tolerance))
+    }
+
+    #[wasm_bindgen(js_name = lt_tolerance)]
+    pub fn js_lt_tolerance(&self, other: &DecimalSource, tolerance: f64) -> Result<bool, JsError> {
+        let other = from_source(other)?;
+        Ok(!self.eq_tolerance(&other, tolerance) && BaseNumber::lt(self, &other))
+    }
+
+    #[wasm_bindgen(js_name = lte_tolerance)]
+    pub fn js_lte_tolerance(&self, other: &DecimalSource, tolerance: f64) -> Result<bool, JsError> {
+        let other = from_source(other)?;
+        Ok(self.eq_tolerance(&other, tolerance) || BaseNumber::lt(self, &other))
+    }
+
+    #[wasm_bindgen(js_name = gt_tolerance)]
+    pub fn js_gt_tolerance(&self, other: &DecimalSource, tolerance: f64) -> Result<bool, JsError> {
+        let other = from_source(other)?;
+        Ok(!self.eq_tolerance(&other, tolerance) && BaseNumber::gt(self, &other))
+    }
+
+    #[wasm_bindgen(js_name = gte_tolerance)]
+    pub fn js_gte_tolerance(&self, other: &DecimalSource, tolerance: f64) -> Result<bool, JsError> {
+        let other = from_source(other)?;
+        Ok(self.eq_tolerance(&other, tolerance) || BaseNumber::gt(self, &other))
+    }
+
+    // Series
+    #[wasm_bindgen(js_name = affordGeometricSeries)]
+    pub fn js_afford_geometric_series(
+        resources_available: &DecimalSource,
+        price_start: &DecimalSource,
+        price_ratio: &DecimalSource,
+        current_owned: &DecimalSource,
+    ) -> Result<Decimal, JsError> {
+        Ok(Decimal::afford_geometric_series(
+            &from_source(resources_available)?,
+            &from_source(price_start)?,
+            &from_source(price_ratio)?,
+            &from_source(current_owned)?,
+        ))
+    }
+
+    #[wasm_bindgen(js_name = sumGeometricSeries)]
+    pub fn js_sum_geometric_series(
+        num_items: &DecimalSource,
+        price_start: &DecimalSource,
+        price_ratio: &DecimalSource,
+        current_owned: &DecimalSource,
+    ) -> Result<Decimal, JsError> {
+        Ok(Decimal::sum_geometric_series(
+            &from_source(num_items)?,
+            &from_source(price_start)?,
+            &from_source(price_ratio)?,
+            &from_source(current_owned)?,
+        ))
+    }
+
+    #[wasm_bindgen(js_name = affordArithmeticSeries)]
+    pub fn js_afford_arithmetic_series(
+        resources_available: &DecimalSource,
+        price_start: &DecimalSource,
+        price_add: &DecimalSource,
+        current_owned: &DecimalSource,
+    ) -> Result<Decimal, JsError> {
+        Ok(Decimal::afford_arithmetic_series(
+            &from_source(resources_available)?,
+            &from_source(price_start)?,
+            &from_source(price_add)?,
+            &from_source(current_owned)?,
+        ))
+    }
+
+    #[wasm_bindgen(js_name = sumArithmeticSeries)]
+    pub fn js_sum_arithmetic_series(
+        num_items: &DecimalSource,
+        price_start: &DecimalSource,
+        price_add: &DecimalSource,
+        current_owned: &DecimalSource,
+    ) -> Result<Decimal, JsError> {
+        Ok(Decimal::sum_arithmetic_series(
+            &from_source(num_items)?,
+            &from_source(price_start)?,
+            &from_source(price_add)?,
+            &from_source(current_owned)?,
+        ))
+    }
+
+    #[wasm_bindgen(js_name = efficiencyOfPurchase)]
+    pub fn js_efficiency_of_purchase(
+        cost: &DecimalSource,
+        current_rp_s: &DecimalSource,
+        delta_rp_s: &DecimalSource,
+    ) -> Result<Decimal, JsError> {
+        Ok(Decimal::efficiency_of_purchase(
+            &from_source(cost)?,
+            &from_source(current_rp_s)?,
+            &from_source(delta_rp_s)?,
+        ))
+    }
+}
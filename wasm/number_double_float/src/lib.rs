@@ -1,16 +1,32 @@
+pub mod binary;
+mod bindings;
+mod compat;
+pub mod locale;
 mod math;
+pub mod notation;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+mod ordered;
+mod parse;
+mod round;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod suffix;
 mod util;
 
 use std::{
     cmp::Ordering,
-    f64::consts::{LN_10, LOG2_10},
-    fmt::{self, Display, Formatter},
-    ops::Neg,
+    f64::consts::{LN_10, LOG10_E, LOG2_10},
+    fmt::{self, Display, Formatter, LowerExp, UpperExp},
 };
 
-use number_base::BaseNumber;
+pub use number_base::{ArithmeticPolicy, DecimalError, ParseDecimalError};
+pub use ordered::OrderedDecimal;
+
+use number_base::{series, BaseNumber, RoundingMode};
 use util::{
-    from_mantissa_exponent, from_mantissa_exponent_no_normalize, pad_end, power_of_10, to_fixed,
+    from_mantissa_exponent, from_mantissa_exponent_no_normalize, from_power_of_10, log10_1p_exp10,
+    pad_end, power_of_10, ratio_logs, to_fixed,
 };
 use wasm_bindgen::prelude::*;
 
@@ -20,7 +36,8 @@ pub const MAX_SIGNIFICANT_DIGITS: u32 = 17;
 
 pub const EXP_LIMIT: f64 = 1.79e308;
 
-/// Tolerance which is used for f64 conversion to compensate for floating-point error.
+/// Tolerance which is used for f64 conversion to compensate for floating-point error, relative
+/// to the size of the number.
 pub const ROUND_TOLERANCE: f64 = f64::EPSILON;
 
 /// The smallest exponent that can appear in an f64, though not all mantissas are valid here.
@@ -32,8 +49,34 @@ pub const NUMBER_EXP_MAX: i32 = 308;
 /// The length of the cache used for powers of 10.
 pub const LENGTH: usize = (NUMBER_EXP_MAX - NUMBER_EXP_MIN + 1) as usize;
 
+/// The difference between log10(e) and [`LOG10_E`], which is rounded to an f64.
+const LOG10_E_LOW: f64 = 1.098_319_650_216_765e-17;
+
+/// The largest argument of `exp_m1` whose result is told apart from that of `exp`.
+const MAX_EXP_M1: f64 = 40.0;
+
+/// The largest degree of a root which is taken of the mantissa directly, so that
+/// `mantissa * 10^(degree - 1)` still fits in an f64.
+const MAX_EXACT_ROOT: f64 = 300.0;
+
+/// A number stored as `mantissa * 10^exponent`, with a mantissa in [1, 10).
+///
+/// # Special values
+///
+/// Decimal follows IEEE 754 for its special values, with the exception that there is only
+/// one zero:
+///
+/// - NaN is produced by invalid operations (`0 * Infinity`, `Infinity - Infinity`,
+///   `0 / 0`, the square root or logarithm of a negative number, a negative number raised to
+///   a non-integer power), propagates through every operation except `x^0` and `1^x`, and
+///   compares unequal and unordered to everything, including itself.
+/// - ±Infinity is produced by overflow (any exponent of at least [`EXP_LIMIT`]) and by
+///   dividing a non-zero number by zero, and compares beyond every finite number.
+/// - Zero is produced by underflow (any exponent of at most `-EXP_LIMIT`) and is unsigned:
+///   negating it gives zero, its [`sign`](BaseNumber::sign) is 0, and dividing by it gives
+///   +Infinity for positive numbers and -Infinity for negative ones.
 #[derive(Clone, Copy, Debug)]
-#[wasm_bindgen(inspectable)]
+#[wasm_bindgen]
 pub struct Decimal {
     mantissa: f64,
     exponent: f64,
@@ -62,11 +105,35 @@ impl_from!(u64);
 impl_from!(u128);
 impl_from!(usize);
 impl_from!(f32);
-impl_from!(f64);
+
+impl From<f64> for Decimal {
+    fn from(value: f64) -> Decimal {
+        if f64::is_nan(value) {
+            return Decimal::NAN;
+        } else if f64::is_infinite(value) {
+            return if value > 0.0 {
+                Decimal::INFINITY
+            } else {
+                Decimal::NEG_INFINITY
+            };
+        } else if value == 0.0 {
+            return Decimal::ZERO;
+        }
+
+        let exponent = value.abs().log10().floor();
+        let mantissa = if (exponent as i32) == NUMBER_EXP_MIN {
+            value * 10.0 / 1e-323
+        } else {
+            value / power_of_10(exponent as i32)
+        };
+
+        Decimal { mantissa, exponent }.normalize()
+    }
+}
 
 impl PartialEq<Decimal> for Decimal {
     fn eq(&self, decimal: &Decimal) -> bool {
-        self.mantissa == decimal.mantissa && self.exponent == decimal.exponent
+        self.partial_cmp(decimal) == Some(Ordering::Equal)
     }
 }
 
@@ -99,20 +166,14 @@ impl PartialOrd for Decimal {
         Infinity
         */
 
+        // Infinities need no special case, as their exponent is larger than that of any
+        // finite number.
         if f64::is_nan(self.mantissa)
             || f64::is_nan(self.exponent)
             || f64::is_nan(decimal.mantissa)
             || f64::is_nan(decimal.exponent)
         {
             None
-        } else if (f64::is_infinite(self.mantissa) && self.mantissa.is_sign_negative())
-            || (f64::is_infinite(decimal.mantissa) && decimal.mantissa.is_sign_positive())
-        {
-            Some(Ordering::Less)
-        } else if (f64::is_infinite(self.mantissa) && self.mantissa.is_sign_negative())
-            || (f64::is_infinite(decimal.mantissa) && decimal.mantissa.is_sign_positive())
-        {
-            Some(Ordering::Greater)
         } else if self.mantissa == 0.0 {
             if decimal.mantissa == 0.0 {
                 Some(Ordering::Equal)
@@ -175,24 +236,10 @@ impl PartialOrd for Decimal {
 
 impl From<String> for Decimal {
     /// Creates a new instance of Decimal from the given string.
-    #[allow(dead_code)]
+    ///
+    /// Invalid strings produce NaN; use [`str::parse`] to handle them instead.
     fn from(string: String) -> Decimal {
-        return if string.find('e').is_some() {
-            let parts: Vec<&str> = string.split('e').collect();
-            let decimal = Decimal {
-                mantissa: String::from(parts[0]).parse().unwrap(),
-                exponent: String::from(parts[1]).parse().unwrap(),
-            };
-
-            decimal.normalize()
-        } else if string == "NaN" {
-            Decimal {
-                mantissa: f64::NAN,
-                exponent: f64::NAN,
-            }
-        } else {
-            Decimal::new(string.parse().unwrap())
-        };
+        string.parse().unwrap_or(Decimal::NAN)
     }
 }
 
@@ -202,37 +249,66 @@ impl From<Decimal> for String {
     }
 }
 
+/// Writes numbers from 1e-7 up to 1e21 in full and others in exponential notation, like
+/// JavaScript, with the precision as the digits after the decimal point.
+///
+/// Width, fill, alignment, `+` and `0` work as they do for the primitive numbers, and the
+/// alternate form `{:#}` groups digits in threes with commas.
 impl Display for Decimal {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        if f64::is_nan(self.mantissa) || f64::is_nan(self.exponent) {
-            return write!(f, "NaN");
-        } else if self.exponent >= EXP_LIMIT {
-            return if self.mantissa > 0.0 {
-                write!(f, "Infinity")
-            } else {
-                write!(f, "-Infinity")
-            };
-        } else if self.exponent <= -EXP_LIMIT || self.mantissa == 0.0 {
-            return write!(f, "0");
-        } else if self.exponent < 21.0 && self.exponent > -7.0 {
-            return if let Some(places) = f.precision() {
-                write!(f, "{:.*}", places, self.to_number().to_string())
-            } else {
-                write!(f, "{}", self.to_number())
-            };
+        if self.is_nan() {
+            return f.pad("NaN");
         }
 
-        let form = if let Some(places) = f.precision() {
-            self.to_exponential(places as u32)
+        let magnitude = self.abs();
+        let body = if self.is_infinite() {
+            String::from("Infinity")
+        } else if self.mantissa == 0.0 || (self.exponent < 21.0 && self.exponent > -7.0) {
+            match f.precision() {
+                Some(places) => magnitude.to_fixed(places as u32),
+                None => magnitude.to_number().to_string(),
+            }
         } else {
-            self.to_exponential(16)
+            magnitude.to_exponential(f.precision().unwrap_or(16) as u32)
         };
 
-        write!(f, "{}", form)
+        if f.alternate() {
+            f.pad_integral(self.mantissa >= 0.0, "", &locale::EN_US.localize(&body))
+        } else {
+            f.pad_integral(self.mantissa >= 0.0, "", &body)
+        }
+    }
+}
+
+/// Writes the number in exponential notation, as `1.5e300`, with the shortest mantissa that
+/// reads back the same or with the precision as the digits after its decimal point.
+///
+/// The flags work as for [`Display`], and the alternate form `{:#e}` always signs the
+/// exponent, as JavaScript's `toExponential` does.
+impl LowerExp for Decimal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_exponential(f, 'e')
+    }
+}
+
+/// Writes the number as [`LowerExp`] does, with an `E` in place of the `e`.
+impl UpperExp for Decimal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_exponential(f, 'E')
     }
 }
 
 impl BaseNumber for Decimal {
+    const ZERO: Decimal = Decimal::ZERO;
+    const ONE: Decimal = Decimal::ONE;
+    const NAN: Decimal = Decimal::NAN;
+    const INFINITY: Decimal = Decimal::INFINITY;
+    const NEG_INFINITY: Decimal = Decimal::NEG_INFINITY;
+
+    fn to_canonical_string(&self) -> String {
+        Decimal::to_canonical_string(self)
+    }
+
     fn to_number(&self) -> f64 {
         //  Problem: new(116.0).to_number() returns 115.99999999999999.
         //  TODO: How to fix in general case? It's clear that if to_number() is
@@ -269,7 +345,9 @@ impl BaseNumber for Decimal {
 
         let result_rounded = result.round();
 
-        if (result_rounded - result).abs() < ROUND_TOLERANCE {
+        // Scaling the mantissa up rounds once, so integers such as 116 = 1.16e2 may come out
+        // one unit in the last place away.
+        if (result_rounded - result).abs() <= ROUND_TOLERANCE * result_rounded.abs() {
             return result_rounded;
         }
 
@@ -299,12 +377,20 @@ impl BaseNumber for Decimal {
 
         let len = places + 1;
         let num_digits = self.mantissa.abs().log10().max(1.0) as u32;
-        let rounded = (self.mantissa * 10.0_f64.powi(len as i32 - num_digits as i32)).round()
+        let mut rounded = (self.mantissa * 10.0_f64.powi(len as i32 - num_digits as i32)).round()
             * 10.0_f64.powi(num_digits as i32 - len as i32);
-        return to_fixed(rounded, 0_u32.max(len - num_digits))
+        let mut exponent = self.exponent;
+
+        // Rounding 9.99... up carries into the next power of ten.
+        if rounded.abs() >= 10.0 {
+            rounded /= 10.0;
+            exponent += 1.0;
+        }
+
+        to_fixed(rounded, len - num_digits)
             + "e"
-            + if self.exponent >= 0.0 { "+" } else { "" }
-            + self.exponent.to_string().as_str();
+            + if exponent >= 0.0 { "+" } else { "" }
+            + exponent.to_string().as_str()
     }
 
     fn to_fixed(&self, places: u32) -> String {
@@ -328,7 +414,8 @@ impl BaseNumber for Decimal {
         } else if self.exponent >= MAX_SIGNIFICANT_DIGITS as f64 {
             let str = pad_end(
                 self.mantissa.to_string().replace('.', ""),
-                (self.exponent + 1.0) as u32,
+                // The minus sign of a negative mantissa is not one of the digits.
+                (self.exponent + 1.0) as u32 + (self.mantissa < 0.0) as u32,
                 String::from("0"),
             ) + if places > 0 { tmp.as_str() } else { "" };
             return str;
@@ -338,12 +425,22 @@ impl BaseNumber for Decimal {
     }
 
     fn to_precision(&self, places: u32) -> String {
-        if self.exponent <= -7.0 {
+        let places = places.max(1);
+
+        // Rounding 9.99... up carries into the next power of ten.
+        let scale = 10.0_f64.powi(places as i32 - 1);
+        let exponent = if (self.mantissa * scale).round().abs() >= 10.0 * scale {
+            self.exponent + 1.0
+        } else {
+            self.exponent
+        };
+
+        if exponent <= -7.0 {
             return self.to_exponential(places - 1);
         }
 
-        if (places as f64) > self.exponent {
-            return self.to_fixed((places as f64 - self.exponent - 1.0) as u32);
+        if (places as f64) > exponent {
+            return self.to_fixed((places as f64 - exponent - 1.0) as u32);
         }
 
         self.to_exponential(places - 1)
@@ -402,7 +499,9 @@ impl BaseNumber for Decimal {
 
     fn sqrt(&self) -> Decimal {
         if self.mantissa < 0.0 {
-            return Decimal::new(f64::NAN);
+            return Decimal::NAN;
+        } else if !self.is_finite() || self.mantissa == 0.0 {
+            return *self;
         } else if self.exponent % 2.0 != 0.0 {
             // Mod of a negative number is negative, so != means '1 or -1'
             return from_mantissa_exponent(
@@ -414,37 +513,31 @@ impl BaseNumber for Decimal {
     }
 
     fn recip(&self) -> Decimal {
-        from_mantissa_exponent(1.0 / self.mantissa, -self.exponent)
+        if self.is_nan() {
+            Decimal::NAN
+        } else if self.is_infinite() {
+            Decimal::ZERO
+        } else if self.mantissa == 0.0 {
+            Decimal::INFINITY
+        } else {
+            from_mantissa_exponent(1.0 / self.mantissa, -self.exponent)
+        }
     }
 
     fn cbrt(&self) -> Decimal {
-        let mut sign = 1;
-        let mut mantissa = self.mantissa;
-
-        if mantissa < 0.0 {
-            sign = -1;
-            mantissa = -mantissa;
+        if !self.is_finite() || self.mantissa == 0.0 {
+            return *self;
         }
 
-        let new_mantissa = sign as f64 * mantissa.powf((1 / 3) as f64);
-        let remainder = (self.exponent % 3.0) as i32;
-
-        if remainder == 1 || remainder == -1 {
-            return from_mantissa_exponent(
-                new_mantissa * 2.154_434_690_031_884,
-                (self.exponent / 3.0).floor(),
-            );
-        }
+        let new_mantissa = self.mantissa.cbrt();
+        let new_exponent = (self.exponent / 3.0).floor();
 
-        if remainder != 0 {
-            // remainder != 0 at this point means 'remainder == 2 || remainder == -2'
-            return from_mantissa_exponent(
-                new_mantissa * 4.641_588_833_612_779,
-                (self.exponent / 3.0).floor(),
-            );
+        // The remainder is taken towards negative infinity, like the exponent.
+        match self.exponent.rem_euclid(3.0) as i32 {
+            1 => from_mantissa_exponent(new_mantissa * 2.154_434_690_031_884, new_exponent),
+            2 => from_mantissa_exponent(new_mantissa * 4.641_588_833_612_779, new_exponent),
+            _ => from_mantissa_exponent(new_mantissa, new_exponent),
         }
-
-        from_mantissa_exponent(new_mantissa, (self.exponent / 3.0).floor())
     }
 
     fn ln(&self) -> Self {
@@ -452,6 +545,11 @@ impl BaseNumber for Decimal {
     }
 
     fn log10(&self) -> Self {
+        if self.is_infinite() && self.mantissa > 0.0 {
+            return Decimal::INFINITY;
+        }
+
+        // log10 of a negative mantissa is NaN, and of zero is -Infinity.
         (self.exponent + self.mantissa.log10()).into()
     }
 
@@ -461,10 +559,42 @@ impl BaseNumber for Decimal {
 
     fn pow(&self, decimal: &Decimal) -> Decimal {
         //  UN-SAFETY: Accuracy not guaranteed beyond ~9-11 decimal places.
-        //  TODO: Decimal.pow(new Decimal(0.5), 0); or Decimal.pow(new Decimal(1), -1);
-        //	makes an exponent of -0! Is a negative zero ever a problem?
 
         let number = decimal.to_number();
+
+        if number == 0.0 || *self == Decimal::ONE {
+            return Decimal::ONE;
+        } else if self.is_nan() || decimal.is_nan() {
+            return Decimal::NAN;
+        } else if self.mantissa < 0.0 {
+            // A negative number can only be raised to an integer power,
+            // and the result is negative for odd powers.
+            if number.fract() != 0.0 {
+                return Decimal::NAN;
+            }
+
+            let result = self.abs().pow(decimal);
+            return if number % 2.0 == 0.0 { result } else { -result };
+        } else if self.mantissa == 0.0 {
+            return if number > 0.0 {
+                Decimal::ZERO
+            } else {
+                Decimal::INFINITY
+            };
+        } else if self.is_infinite() {
+            return if number > 0.0 {
+                Decimal::INFINITY
+            } else {
+                Decimal::ZERO
+            };
+        } else if f64::is_infinite(number) {
+            return if (*self > Decimal::ONE) == (number > 0.0) {
+                Decimal::INFINITY
+            } else {
+                Decimal::ZERO
+            };
+        }
+
         //  TODO: Fast track seems about neutral for performance.
         //	It might become faster if an integer pow is implemented,
         //	or it might not be worth doing (see https://github.com/Patashu/break_infinity.js/issues/4 )
@@ -474,7 +604,7 @@ impl BaseNumber for Decimal {
         let temp = self.exponent * number;
         let mut new_mantissa;
 
-        if temp < MAX_SAFE_INTEGER {
+        if temp.fract() == 0.0 && temp.abs() <= MAX_SAFE_INTEGER {
             // Same speed and usually more accurate.
             new_mantissa = self.mantissa.powf(number);
 
@@ -484,28 +614,131 @@ impl BaseNumber for Decimal {
         }
 
         let new_exponent = temp.trunc();
-        let residue = temp - new_exponent;
+        // The rounding error of the product would otherwise be multiplied into the mantissa
+        // by the size of the exponent.
+        let residue = temp - new_exponent + self.exponent.mul_add(number, -temp);
         new_mantissa = 10.0_f64.powf(number * self.mantissa.log10() + residue);
 
         if f64::is_finite(new_mantissa) && new_mantissa != 0.0 {
             //  return Decimal.exp(value*this.ln());
-            //  UN-SAFETY: This should return NaN when mantissa is negative and value is non-integer.
             return from_mantissa_exponent(new_mantissa, new_exponent);
         }
 
-        let result = Decimal::new(10.0).pow(&Decimal::new(number * self.log10().abs().to_number()));
+        Decimal::new(10.0).pow(&Decimal::new(number * self.log10().to_number()))
+    }
 
-        if self.sign() == -1 && (number % 2.0 - 1.0).abs() < f64::EPSILON {
-            return result.neg();
+    fn round_to(&self, places: i64, mode: RoundingMode) -> Decimal {
+        round::round_to_places(self, places, mode)
+    }
+
+    fn round_to_significant(&self, digits: u32, mode: RoundingMode) -> Decimal {
+        round::round_to_significant(self, digits, mode)
+    }
+
+    fn exp(&self) -> Decimal {
+        let number = self.to_number();
+        if number.abs() < 700.0 {
+            return Decimal::new(number.exp());
         }
 
-        result
+        // e^x = 10^(x log10(e)), where the rounding error of the product would otherwise be
+        // multiplied into the mantissa by the size of the exponent.
+        let power = number * LOG10_E;
+        from_power_of_10(
+            power,
+            number.mul_add(LOG10_E, -power) + number * LOG10_E_LOW,
+        )
+    }
+
+    fn log(&self, base: &Decimal) -> Decimal {
+        self.log10() / base.log10()
+    }
+
+    fn pow10(&self) -> Decimal {
+        from_power_of_10(self.to_number(), 0.0)
+    }
+
+    fn root(&self, degree: &Decimal) -> Decimal {
+        let degree = degree.to_number();
+
+        if self.mantissa < 0.0 {
+            return if degree.fract() == 0.0 && degree % 2.0 != 0.0 {
+                -(-*self).root(&Decimal::new(degree))
+            } else {
+                Decimal::NAN
+            };
+        } else if degree < 0.0 {
+            return self.root(&Decimal::new(-degree)).recip();
+        } else if degree.fract() != 0.0
+            || degree > MAX_EXACT_ROOT
+            || !self.is_finite()
+            || self.mantissa == 0.0
+        {
+            return self.pow(&Decimal::new(degree.recip()));
+        }
+
+        // Move the remainder of the exponent into the mantissa, so the exponent divides
+        // exactly and the root of the mantissa is all that is rounded.
+        let remainder = self.exponent.rem_euclid(degree);
+        let radicand = self.mantissa * power_of_10(remainder as i32);
+        let root = match degree as u32 {
+            2 => radicand.sqrt(),
+            3 => radicand.cbrt(),
+            _ => radicand.powf(degree.recip()),
+        };
+
+        from_mantissa_exponent(root, (self.exponent - remainder) / degree)
+    }
+
+    fn afford_geometric_series(
+        resources: &Decimal,
+        start: &Decimal,
+        ratio: &Decimal,
+        owned: &Decimal,
+    ) -> Decimal {
+        let (log_ratio, log_increase) = match ratio_logs(ratio) {
+            Some(logs) if start.sign() > 0 => logs,
+            _ => return series::afford_geometric_series(resources, start, ratio, owned),
+        };
+
+        // The whole estimate is made on logarithms, so that neither the price of the first
+        // item nor the budget scaled by it has to fit in a Decimal.
+        let log_first = start.log10().to_number() + owned.to_number() * log_ratio;
+        let log_scaled = resources.log10().to_number() - log_first + log_increase;
+        let count = log10_1p_exp10(log_scaled) / log_ratio;
+
+        series::correct_count(Decimal::new(count), resources, |count| {
+            Decimal::sum_geometric_series(count, start, ratio, owned)
+        })
+    }
+
+    fn sum_geometric_series(
+        count: &Decimal,
+        start: &Decimal,
+        ratio: &Decimal,
+        owned: &Decimal,
+    ) -> Decimal {
+        let (log_ratio, log_increase) = match ratio_logs(ratio) {
+            Some(logs) if start.sign() > 0 && count.sign() > 0 => logs,
+            _ => return series::sum_geometric_series(count, start, ratio, owned),
+        };
+
+        // ratio^count - 1 = e^(count ln(ratio)) - 1, which keeps its digits for small counts.
+        let growth = count.to_number() * log_ratio * LN_10;
+        let log_growth = if growth < MAX_EXP_M1 {
+            growth.exp_m1().log10()
+        } else {
+            growth * LOG10_E
+        };
+        let log_first = start.log10().to_number() + owned.to_number() * log_ratio;
+
+        from_power_of_10(log_first + log_growth - log_increase, 0.0)
     }
 
     fn sign(&self) -> i32 {
-        if self.mantissa.is_sign_positive() {
+        if self.mantissa > 0.0 {
             1
-        } else if self.mantissa.is_sign_negative() {
+        } else if self.mantissa < 0.0 {
             -1
         } else {
             0
@@ -548,25 +781,168 @@ impl BaseNumber for Decimal {
 }
 
 impl Decimal {
+    /// Not a Number.
+    pub const NAN: Decimal = Decimal {
+        mantissa: f64::NAN,
+        exponent: f64::NAN,
+    };
+
+    /// Positive infinity.
+    pub const INFINITY: Decimal = Decimal {
+        mantissa: 1.0,
+        exponent: EXP_LIMIT,
+    };
+
+    /// Negative infinity.
+    pub const NEG_INFINITY: Decimal = Decimal {
+        mantissa: -1.0,
+        exponent: EXP_LIMIT,
+    };
+
+    /// Zero.
+    pub const ZERO: Decimal = Decimal {
+        mantissa: 0.0,
+        exponent: 0.0,
+    };
+
+    /// One.
+    pub const ONE: Decimal = Decimal {
+        mantissa: 1.0,
+        exponent: 0.0,
+    };
+
+    /// Creates a new instance of Decimal with the given mantissa and exponent, normalizing them.
+    pub fn from_mantissa_exponent(mantissa: f64, exponent: f64) -> Decimal {
+        from_mantissa_exponent(mantissa, exponent)
+    }
+
+    fn fmt_exponential(&self, f: &mut Formatter<'_>, marker: char) -> fmt::Result {
+        if self.is_nan() {
+            return f.pad("NaN");
+        } else if self.is_infinite() {
+            return f.pad_integral(self.mantissa >= 0.0, "", "Infinity");
+        }
+
+        let (mantissa, exponent) = match f.precision() {
+            Some(places) => {
+                let formatted = self.abs().to_exponential(places as u32);
+                let (mantissa, exponent) = formatted.split_once('e').unwrap();
+                (
+                    mantissa.to_owned(),
+                    exponent.trim_start_matches('+').to_owned(),
+                )
+            }
+            None => {
+                let shortest = format!("{:e}", self.mantissa.abs());
+                let (mantissa, _) = shortest.split_once('e').unwrap();
+                (mantissa.to_owned(), self.exponent.to_string())
+            }
+        };
+
+        let sign = if f.alternate() && !exponent.starts_with('-') {
+            "+"
+        } else {
+            ""
+        };
+        let body = format!("{}{}{}{}", mantissa, marker, sign, exponent);
+        f.pad_integral(self.mantissa >= 0.0, "", &body)
+    }
+
+    /// Returns whether the number is NaN.
+    pub fn is_nan(&self) -> bool {
+        f64::is_nan(self.mantissa) || f64::is_nan(self.exponent)
+    }
+
+    /// Returns whether the number is positive or negative infinity.
+    pub fn is_infinite(&self) -> bool {
+        !self.is_nan() && self.exponent >= EXP_LIMIT
+    }
+
+    /// Returns whether the number is neither NaN nor infinite.
+    pub fn is_finite(&self) -> bool {
+        !self.is_nan() && self.exponent < EXP_LIMIT
+    }
+
+    /// Returns the ordering between the number and another one, treating NaN as a value.
+    ///
+    /// Unlike [`partial_cmp`](PartialOrd::partial_cmp), this is a total order: every NaN is
+    /// equal to every other NaN and greater than every other number, including +Infinity.
+    /// All zeros are equal, like with `partial_cmp`.
+    pub fn total_cmp(&self, other: &Decimal) -> Ordering {
+        match (self.is_nan(), other.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self.partial_cmp(other).unwrap_or(Ordering::Equal),
+        }
+    }
+
+    /// Returns the canonical string form of the number, which parses back into exactly the
+    /// same number.
+    ///
+    /// Finite numbers are written as `<mantissa>e<exponent>`, where the mantissa is the
+    /// shortest string that round-trips to the same f64 and the exponent is written out in
+    /// full, e.g. `1.2345678901234567e-89`. Zero is `0`, and the special values are
+    /// `NaN`, `Infinity` and `-Infinity`.
+    ///
+    /// For every Decimal `d` that is not NaN, `d.to_canonical_string().parse::<Decimal>()`
+    /// returns `Ok(d)`; NaN parses back into NaN.
+    pub fn to_canonical_string(&self) -> String {
+        if self.is_nan() {
+            String::from("NaN")
+        } else if self.exponent >= EXP_LIMIT {
+            String::from(if self.mantissa > 0.0 {
+                "Infinity"
+            } else {
+                "-Infinity"
+            })
+        } else if self.mantissa == 0.0 {
+            String::from("0")
+        } else {
+            format!("{}e{}", self.mantissa, self.exponent)
+        }
+    }
+
     /// Normalizes the mantissa when it is too denormalized.
     fn normalize(&self) -> Decimal {
-        if self.mantissa >= 1.0 && self.mantissa < 10.0 {
-            return *self;
+        if self.mantissa.abs() >= 1.0 && self.mantissa.abs() < 10.0 {
+            return self.clamp_exponent();
         } else if self.mantissa == 0.0 {
-            return Decimal {
-                mantissa: 0.0,
-                exponent: 0.0,
-            };
+            return Decimal::ZERO;
         }
 
         let temp_exponent = self.mantissa.abs().log10().floor();
-        Decimal {
-            mantissa: if (temp_exponent as i32) == NUMBER_EXP_MIN {
-                self.mantissa * 10.0 / 1e-323
+        let mut mantissa = if (temp_exponent as i32) == NUMBER_EXP_MIN {
+            self.mantissa * 10.0 / 1e-323
+        } else {
+            self.mantissa / power_of_10(temp_exponent as i32)
+        };
+        let mut exponent = self.exponent + temp_exponent;
+
+        // log10 can be off by one for mantissas right next to a power of 10.
+        if mantissa.abs() < 1.0 {
+            mantissa *= 10.0;
+            exponent -= 1.0;
+        } else if mantissa.abs() >= 10.0 {
+            mantissa /= 10.0;
+            exponent += 1.0;
+        }
+
+        Decimal { mantissa, exponent }.clamp_exponent()
+    }
+
+    /// Turns overflowed numbers into ±Infinity and underflowed numbers into zero.
+    fn clamp_exponent(self) -> Decimal {
+        if self.exponent >= EXP_LIMIT {
+            if self.mantissa > 0.0 {
+                Decimal::INFINITY
             } else {
-                self.mantissa / power_of_10(temp_exponent as i32)
-            },
-            exponent: self.exponent + temp_exponent,
+                Decimal::NEG_INFINITY
+            }
+        } else if self.exponent <= -EXP_LIMIT {
+            Decimal::ZERO
+        } else {
+            self
         }
     }
 }
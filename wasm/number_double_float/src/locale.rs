@@ -0,0 +1,425 @@
+//! Locales for showing numbers to players in their own language.
+//!
+//! A [`Locale`] describes how a number is written: its decimal mark, how the digits before it
+//! are grouped, the digits themselves and the words for large powers of ten. The bundled
+//! locales are plain data, so formatting needs neither ICU nor the network.
+//!
+//! ```
+//! use number_base::BaseNumber;
+//! use number_double_float::{locale, Decimal};
+//!
+//! let value = Decimal::new(1234567.891);
+//! assert_eq!(locale::DE_DE.localize(&value.to_fixed(2)), "1.234.567,89");
+//! assert_eq!(locale::HI_IN.localize(&value.to_fixed(2)), "12,34,567.89");
+//! ```
+
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    suffix::{per_thousand, short_scale_suffix},
+    Decimal,
+};
+
+/// The digits 0 to 9.
+pub const LATIN_DIGITS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+
+/// The digits used in Arabic.
+pub const ARABIC_INDIC_DIGITS: [char; 10] = ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'];
+
+/// The digits used in Hindi and Marathi.
+pub const DEVANAGARI_DIGITS: [char; 10] = ['०', '१', '२', '३', '४', '५', '६', '७', '८', '९'];
+
+/// How the digits before the decimal mark are grouped, counting from the mark.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Grouping {
+    /// The size of the group next to the decimal mark, or 0 to not group digits.
+    pub primary: u8,
+    /// The size of every other group.
+    pub secondary: u8,
+}
+
+impl Grouping {
+    /// No grouping.
+    pub const NONE: Grouping = Grouping {
+        primary: 0,
+        secondary: 0,
+    };
+
+    /// Groups of three, as in `1,234,567`.
+    pub const THOUSANDS: Grouping = Grouping {
+        primary: 3,
+        secondary: 3,
+    };
+
+    /// A group of three followed by groups of two, as in `12,34,567`.
+    pub const INDIAN: Grouping = Grouping {
+        primary: 3,
+        secondary: 2,
+    };
+}
+
+/// The words for large powers of ten in a locale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Suffixes {
+    /// The short-scale suffixes of [`short_scale_suffix`], at every power of 1000.
+    ShortScale,
+    /// Suffixes for the given powers of ten, in ascending order.
+    ///
+    /// A number takes the suffix of the largest power at or below it, so the number before a
+    /// suffix may be larger than 1000, as in `1234万`. An empty suffix for the power 0 keeps
+    /// numbers below the first suffix in full. Numbers 1000 times the last power or more have
+    /// no suffix.
+    Powers(&'static [(u32, &'static str)]),
+}
+
+impl Suffixes {
+    /// Returns the power of ten and the suffix for a number with the given exponent.
+    pub fn for_exponent(&self, exponent: f64) -> Option<(u32, String)> {
+        match self {
+            Suffixes::ShortScale => per_thousand(exponent, short_scale_suffix),
+            Suffixes::Powers(powers) => {
+                let &(last, _) = powers.last()?;
+                if exponent >= last as f64 + 3.0 {
+                    return None;
+                }
+
+                powers
+                    .iter()
+                    .rev()
+                    .find(|&&(power, _)| power as f64 <= exponent)
+                    .map(|&(power, suffix)| (power, String::from(suffix)))
+            }
+        }
+    }
+}
+
+/// How numbers are written in a language and region.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Locale {
+    /// The BCP 47 language tag, such as `de-DE`.
+    pub tag: &'static str,
+    pub decimal_mark: char,
+    pub group_separator: char,
+    pub grouping: Grouping,
+    pub digits: [char; 10],
+    /// The suffixes which standard notation uses.
+    pub suffixes: Suffixes,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        EN_US
+    }
+}
+
+impl Locale {
+    /// Rewrites a number written by this crate, such as `-1234567.89` or `1.5e+21`, with the
+    /// decimal mark, grouping and digits of the locale.
+    ///
+    /// Every run of digits is treated as a number, and the text around them, such as signs,
+    /// exponent markers and suffixes, is kept as it is.
+    pub fn localize(&self, number: &str) -> String {
+        self.localize_with(number, true)
+    }
+
+    /// Rewrites a number as [`localize`](Locale::localize) does, but only groups its digits if
+    /// `group` is set.
+    pub(crate) fn localize_with(&self, number: &str, group: bool) -> String {
+        let mut localized = String::with_capacity(number.len());
+        let mut rest = number;
+
+        while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+            localized.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit() && c != '.')
+                .unwrap_or(rest.len());
+            let (integer, fraction) = rest[..end].split_at(rest[..end].find('.').unwrap_or(end));
+            self.push_integer(&mut localized, integer, group);
+            if let Some(fraction) = fraction.strip_prefix('.') {
+                localized.push(self.decimal_mark);
+                self.push_digits(&mut localized, fraction);
+            }
+
+            rest = &rest[end..];
+        }
+
+        localized.push_str(rest);
+        localized
+    }
+
+    fn push_integer(&self, localized: &mut String, integer: &str, group: bool) {
+        if !group || self.grouping.primary == 0 {
+            self.push_digits(localized, integer);
+            return;
+        }
+
+        let mut groups = Vec::new();
+        let mut end = integer.len();
+        let mut size = self.grouping.primary as usize;
+        while end > size {
+            groups.push(&integer[end - size..end]);
+            end -= size;
+            size = self.grouping.secondary.max(1) as usize;
+        }
+        groups.push(&integer[..end]);
+
+        for (index, group) in groups.iter().rev().enumerate() {
+            if index > 0 {
+                localized.push(self.group_separator);
+            }
+            self.push_digits(localized, group);
+        }
+    }
+
+    fn push_digits(&self, localized: &mut String, digits: &str) {
+        localized.extend(
+            digits
+                .bytes()
+                .map(|digit| self.digits[(digit - b'0') as usize]),
+        );
+    }
+}
+
+/// English, United States.
+pub const EN_US: Locale = Locale {
+    tag: "en-US",
+    decimal_mark: '.',
+    group_separator: ',',
+    grouping: Grouping::THOUSANDS,
+    digits: LATIN_DIGITS,
+    suffixes: Suffixes::ShortScale,
+};
+
+/// German, Germany.
+pub const DE_DE: Locale = Locale {
+    tag: "de-DE",
+    decimal_mark: ',',
+    group_separator: '.',
+    grouping: Grouping::THOUSANDS,
+    digits: LATIN_DIGITS,
+    suffixes: Suffixes::Powers(&[
+        (3, "\u{a0}Tsd."),
+        (6, "\u{a0}Mio."),
+        (9, "\u{a0}Mrd."),
+        (12, "\u{a0}Bio."),
+        (15, "\u{a0}Brd."),
+        (18, "\u{a0}Trio."),
+        (21, "\u{a0}Trd."),
+        (24, "\u{a0}Quadr."),
+        (27, "\u{a0}Quadrd."),
+        (30, "\u{a0}Quint."),
+        (33, "\u{a0}Quintd."),
+    ]),
+};
+
+/// French, France.
+pub const FR_FR: Locale = Locale {
+    tag: "fr-FR",
+    decimal_mark: ',',
+    group_separator: '\u{202f}',
+    grouping: Grouping::THOUSANDS,
+    digits: LATIN_DIGITS,
+    suffixes: Suffixes::Powers(&[
+        (3, "\u{a0}k"),
+        (6, "\u{a0}M"),
+        (9, "\u{a0}Md"),
+        (12, "\u{a0}Bn"),
+        (15, "\u{a0}Bd"),
+        (18, "\u{a0}Tn"),
+        (21, "\u{a0}Td"),
+    ]),
+};
+
+/// Spanish, Spain.
+pub const ES_ES: Locale = Locale {
+    tag: "es-ES",
+    decimal_mark: ',',
+    group_separator: '.',
+    grouping: Grouping::THOUSANDS,
+    digits: LATIN_DIGITS,
+    suffixes: Suffixes::Powers(&[
+        (3, "\u{a0}mil"),
+        (6, "\u{a0}M"),
+        (9, "\u{a0}mil\u{a0}M"),
+        (12, "\u{a0}B"),
+        (18, "\u{a0}T"),
+    ]),
+};
+
+/// Italian, Italy.
+pub const IT_IT: Locale = Locale {
+    tag: "it-IT",
+    decimal_mark: ',',
+    group_separator: '.',
+    grouping: Grouping::THOUSANDS,
+    digits: LATIN_DIGITS,
+    suffixes: Suffixes::Powers(&[
+        (0, ""),
+        (6, "\u{a0}Mln"),
+        (9, "\u{a0}Mrd"),
+        (12, "\u{a0}Bln"),
+    ]),
+};
+
+/// Portuguese, Brazil.
+pub const PT_BR: Locale = Locale {
+    tag: "pt-BR",
+    decimal_mark: ',',
+    group_separator: '.',
+    grouping: Grouping::THOUSANDS,
+    digits: LATIN_DIGITS,
+    suffixes: Suffixes::Powers(&[
+        (3, "\u{a0}mil"),
+        (6, "\u{a0}mi"),
+        (9, "\u{a0}bi"),
+        (12, "\u{a0}tri"),
+    ]),
+};
+
+/// Russian, Russia.
+pub const RU_RU: Locale = Locale {
+    tag: "ru-RU",
+    decimal_mark: ',',
+    group_separator: '\u{a0}',
+    grouping: Grouping::THOUSANDS,
+    digits: LATIN_DIGITS,
+    suffixes: Suffixes::Powers(&[
+        (3, "\u{a0}тыс."),
+        (6, "\u{a0}млн"),
+        (9, "\u{a0}млрд"),
+        (12, "\u{a0}трлн"),
+    ]),
+};
+
+/// Japanese, Japan, with suffixes for every power of 10,000.
+pub const JA_JP: Locale = Locale {
+    tag: "ja-JP",
+    decimal_mark: '.',
+    group_separator: ',',
+    grouping: Grouping::THOUSANDS,
+    digits: LATIN_DIGITS,
+    suffixes: Suffixes::Powers(&[
+        (0, ""),
+        (4, "万"),
+        (8, "億"),
+        (12, "兆"),
+        (16, "京"),
+        (20, "垓"),
+        (24, "秭"),
+        (28, "穣"),
+        (32, "溝"),
+        (36, "澗"),
+        (40, "正"),
+        (44, "載"),
+        (48, "極"),
+        (52, "恒河沙"),
+        (56, "阿僧祇"),
+        (60, "那由他"),
+        (64, "不可思議"),
+        (68, "無量大数"),
+    ]),
+};
+
+/// Chinese, China.
+pub const ZH_CN: Locale = Locale {
+    tag: "zh-CN",
+    decimal_mark: '.',
+    group_separator: ',',
+    grouping: Grouping::THOUSANDS,
+    digits: LATIN_DIGITS,
+    suffixes: Suffixes::Powers(&[(0, ""), (4, "万"), (8, "亿"), (12, "万亿")]),
+};
+
+/// Korean, South Korea.
+pub const KO_KR: Locale = Locale {
+    tag: "ko-KR",
+    decimal_mark: '.',
+    group_separator: ',',
+    grouping: Grouping::THOUSANDS,
+    digits: LATIN_DIGITS,
+    suffixes: Suffixes::Powers(&[
+        (3, "천"),
+        (4, "만"),
+        (8, "억"),
+        (12, "조"),
+        (16, "경"),
+        (20, "해"),
+    ]),
+};
+
+/// Hindi, India, with lakhs and crores.
+pub const HI_IN: Locale = Locale {
+    tag: "hi-IN",
+    decimal_mark: '.',
+    group_separator: ',',
+    grouping: Grouping::INDIAN,
+    digits: LATIN_DIGITS,
+    suffixes: Suffixes::Powers(&[
+        (3, "\u{a0}हज़ार"),
+        (5, "\u{a0}लाख"),
+        (7, "\u{a0}करोड़"),
+        (9, "\u{a0}अरब"),
+        (11, "\u{a0}खरब"),
+        (13, "\u{a0}नील"),
+        (15, "\u{a0}पद्म"),
+        (17, "\u{a0}शंख"),
+    ]),
+};
+
+/// Arabic, Egypt.
+pub const AR_EG: Locale = Locale {
+    tag: "ar-EG",
+    decimal_mark: '٫',
+    group_separator: '٬',
+    grouping: Grouping::THOUSANDS,
+    digits: ARABIC_INDIC_DIGITS,
+    suffixes: Suffixes::Powers(&[
+        (3, "\u{a0}ألف"),
+        (6, "\u{a0}مليون"),
+        (9, "\u{a0}مليار"),
+        (12, "\u{a0}ترليون"),
+    ]),
+};
+
+/// The bundled locales.
+pub const LOCALES: [&Locale; 12] = [
+    &EN_US, &DE_DE, &FR_FR, &ES_ES, &IT_IT, &PT_BR, &RU_RU, &JA_JP, &ZH_CN, &KO_KR, &HI_IN, &AR_EG,
+];
+
+/// Returns the bundled locale with the given tag, ignoring case and accepting `_` for `-`, or
+/// else the first one for the same language, so that `de-AT` falls back to `de-DE`.
+pub fn by_tag(tag: &str) -> Option<&'static Locale> {
+    let tag = tag.replace('_', "-");
+    let language = tag.split('-').next().unwrap_or_default();
+
+    LOCALES
+        .into_iter()
+        .find(|locale| locale.tag.eq_ignore_ascii_case(&tag))
+        .or_else(|| {
+            LOCALES.into_iter().find(|locale| {
+                let (candidate, _) = locale.tag.split_once('-').unwrap();
+                candidate.eq_ignore_ascii_case(language)
+            })
+        })
+}
+
+impl Decimal {
+    /// Returns the number as [`Display`](std::fmt::Display) writes it, in the given locale.
+    pub fn to_locale_string(&self, locale: &Locale) -> String {
+        locale.localize(&self.to_string())
+    }
+}
+
+#[wasm_bindgen]
+impl Decimal {
+    /// Returns the number in the bundled locale with the given tag, such as `"de-DE"`.
+    /// Throws if there is no locale for the tag or its language.
+    #[wasm_bindgen(js_name = toLocaleString)]
+    pub fn js_to_locale_string(&self, tag: &str) -> Result<String, JsError> {
+        let locale =
+            by_tag(tag).ok_or_else(|| JsError::new(&format!("unknown locale: {}", tag)))?;
+        Ok(self.to_locale_string(locale))
+    }
+}
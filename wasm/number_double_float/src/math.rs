@@ -4,7 +4,10 @@ use crate::{
     util::{from_mantissa_exponent, from_mantissa_exponent_no_normalize, power_of_10},
     Decimal, MAX_SIGNIFICANT_DIGITS,
 };
-use std::ops::*;
+use std::{
+    iter::{Product, Sum},
+    ops::*,
+};
 
 impl Add<Decimal> for Decimal {
     type Output = Decimal;
@@ -13,6 +16,19 @@ impl Add<Decimal> for Decimal {
         // Figure out which is bigger, shrink the mantissa of the smaller
         // by the difference in exponents, add mantissas, normalize and return
         // TODO: Optimizations and simplification may be possible, see https://github.com/Patashu/break_infinity.js/issues/8
+        if self.is_nan() || decimal.is_nan() {
+            return Decimal::NAN;
+        } else if self.is_infinite() || decimal.is_infinite() {
+            // Infinities of opposite signs cancel out into NaN.
+            return if !decimal.is_infinite() {
+                self
+            } else if !self.is_infinite() || self.mantissa == decimal.mantissa {
+                decimal
+            } else {
+                Decimal::NAN
+            };
+        }
+
         if self.mantissa == 0.0 {
             return decimal;
         }
@@ -131,6 +147,16 @@ impl Mul<Decimal> for Decimal {
     type Output = Decimal;
 
     fn mul(self, decimal: Decimal) -> Decimal {
+        if self.is_nan() || decimal.is_nan() {
+            return Decimal::NAN;
+        } else if self.is_infinite() || decimal.is_infinite() {
+            return match self.sign() * decimal.sign() {
+                1 => Decimal::INFINITY,
+                -1 => Decimal::NEG_INFINITY,
+                _ => Decimal::NAN,
+            };
+        }
+
         from_mantissa_exponent(
             self.mantissa * decimal.mantissa,
             self.exponent + decimal.exponent,
@@ -219,11 +245,74 @@ impl DivAssign<Decimal> for Decimal {
     }
 }
 
+/// The remainder of truncated division, which has the sign of the dividend, as `%` gives for
+/// f64. Once the quotient has more digits than a Decimal holds, the remainder is below its
+/// precision and comes out as zero.
+impl Rem<Decimal> for Decimal {
+    type Output = Decimal;
+
+    fn rem(self, decimal: Decimal) -> Decimal {
+        if self.is_nan() || decimal.is_nan() || self.is_infinite() || decimal.mantissa == 0.0 {
+            return Decimal::NAN;
+        } else if decimal.is_infinite() || self.mantissa == 0.0 {
+            return self;
+        }
+
+        let difference = self.exponent - decimal.exponent;
+        if difference < 0.0 {
+            return self;
+        } else if difference > MAX_SIGNIFICANT_DIGITS as f64 {
+            return Decimal::ZERO;
+        }
+
+        from_mantissa_exponent(
+            (self.mantissa * power_of_10(difference as i32)) % decimal.mantissa,
+            decimal.exponent,
+        )
+    }
+}
+
+impl Rem<&Decimal> for Decimal {
+    type Output = Decimal;
+
+    fn rem(self, decimal: &Decimal) -> Decimal {
+        self % *decimal
+    }
+}
+
+impl Rem<Decimal> for &Decimal {
+    type Output = Decimal;
+
+    fn rem(self, decimal: Decimal) -> Decimal {
+        *self % decimal
+    }
+}
+
+impl Rem<&Decimal> for &Decimal {
+    type Output = Decimal;
+
+    fn rem(self, decimal: &Decimal) -> Decimal {
+        *self % *decimal
+    }
+}
+
+impl RemAssign<&Decimal> for Decimal {
+    fn rem_assign(&mut self, rhs: &Decimal) {
+        *self = *self % rhs;
+    }
+}
+
+impl RemAssign<Decimal> for Decimal {
+    fn rem_assign(&mut self, rhs: Decimal) {
+        *self = *self % rhs;
+    }
+}
+
 impl Neg for &Decimal {
     type Output = Decimal;
 
     fn neg(self) -> Decimal {
-        from_mantissa_exponent_no_normalize(-self.mantissa, self.exponent)
+        (*self).neg()
     }
 }
 
@@ -231,7 +320,35 @@ impl Neg for Decimal {
     type Output = Decimal;
 
     fn neg(self) -> Decimal {
-        let decimal = &self.clone();
-        from_mantissa_exponent_no_normalize(-decimal.mantissa, decimal.exponent)
+        // Zero is unsigned.
+        if self.mantissa == 0.0 {
+            return Decimal::ZERO;
+        }
+
+        from_mantissa_exponent_no_normalize(-self.mantissa, self.exponent)
+    }
+}
+
+impl Sum for Decimal {
+    fn sum<I: Iterator<Item = Decimal>>(iter: I) -> Decimal {
+        iter.fold(Decimal::ZERO, |sum, number| sum + number)
+    }
+}
+
+impl<'a> Sum<&'a Decimal> for Decimal {
+    fn sum<I: Iterator<Item = &'a Decimal>>(iter: I) -> Decimal {
+        iter.fold(Decimal::ZERO, |sum, number| sum + number)
+    }
+}
+
+impl Product for Decimal {
+    fn product<I: Iterator<Item = Decimal>>(iter: I) -> Decimal {
+        iter.fold(Decimal::ONE, |product, number| product * number)
+    }
+}
+
+impl<'a> Product<&'a Decimal> for Decimal {
+    fn product<I: Iterator<Item = &'a Decimal>>(iter: I) -> Decimal {
+        iter.fold(Decimal::ONE, |product, number| product * number)
     }
 }
@@ -0,0 +1,524 @@
+//! Notations for showing numbers to players.
+//!
+//! Every notation shows numbers below 1000 in full, with
+//! [`places_under_1000`](NotationOptions::places_under_1000) digits after the point, and
+//! differs only in how it abbreviates the numbers which are larger, or too small to show in
+//! full. Exponents of at least [`EXPONENT_LIMIT`] are themselves shown in scientific notation,
+//! without a mantissa, as in `e1.23e45`.
+//!
+//! ```
+//! use number_base::BaseNumber;
+//! use number_double_float::{
+//!     notation::{Notation, NotationOptions, Standard},
+//!     Decimal,
+//! };
+//!
+//! let options = NotationOptions::default();
+//! assert_eq!(Standard.format(&Decimal::new(1.5e6), &options), "1.50M");
+//! assert_eq!(Standard.format(&Decimal::new(12.345), &options), "12.35");
+//! ```
+//!
+//! Numbers are written in the [`Locale`] of the options, and standard notation uses its
+//! suffixes.
+//!
+//! The same notations are exported to JavaScript as the `Notation` class, so that a UI written
+//! in TypeScript shows numbers exactly as the Rust side does.
+
+use number_base::{BaseNumber, RoundingMode};
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    compat::{from_source, DecimalSource},
+    locale::{self, Locale},
+    suffix::{alphabetic_suffix, long_scale_suffix, per_thousand},
+    util::to_fixed,
+    Decimal,
+};
+
+/// The smallest exponent which is shown in scientific notation itself, in place of the number.
+pub const EXPONENT_LIMIT: f64 = 1e9;
+
+/// The largest power of ten which mixed scientific notation shows with a suffix.
+const MIXED_SCIENTIFIC_MAX_POWER: u32 = 30;
+
+/// The names of the built-in notations, as accepted by [`by_name`].
+pub const NAMES: [&str; 9] = [
+    "scientific",
+    "engineering",
+    "standard",
+    "long scale",
+    "alphabetic",
+    "mixed scientific",
+    "logarithmic",
+    "infinity",
+    "blind",
+];
+
+/// How many digits a notation shows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NotationOptions {
+    /// Digits after the decimal point of abbreviated numbers.
+    pub places: u32,
+    /// Digits after the decimal point of numbers below 1000, which are shown in full.
+    pub places_under_1000: u32,
+    /// Whether digits are grouped as the locale groups them, as in `1e1,234,567`.
+    pub commas: bool,
+    /// How digits, decimal marks and suffixes are written.
+    pub locale: &'static Locale,
+}
+
+impl Default for NotationOptions {
+    fn default() -> Self {
+        NotationOptions {
+            places: 2,
+            places_under_1000: 2,
+            commas: true,
+            locale: &locale::EN_US,
+        }
+    }
+}
+
+/// A way of showing numbers to players.
+pub trait Notation {
+    /// Returns the name of the notation.
+    fn name(&self) -> &str;
+
+    /// Formats a finite number greater than zero which is not shown in full, because it is at
+    /// least 1000 or would round to zero.
+    fn format_abbreviated(&self, value: &Decimal, options: &NotationOptions) -> String;
+
+    /// Formats a number.
+    fn format(&self, value: &Decimal, options: &NotationOptions) -> String {
+        if value.is_nan() {
+            return String::from("NaN");
+        }
+
+        let magnitude = value.abs();
+        let formatted = if value.is_infinite() {
+            String::from("Infinity")
+        } else if let Some(full) = in_full(&magnitude, options) {
+            full
+        } else {
+            self.format_abbreviated(&magnitude, options)
+        };
+
+        options
+            .locale
+            .localize_with(&signed(value, formatted), options.commas)
+    }
+}
+
+impl<N: Notation + ?Sized> Notation for Box<N> {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn format_abbreviated(&self, value: &Decimal, options: &NotationOptions) -> String {
+        (**self).format_abbreviated(value, options)
+    }
+
+    fn format(&self, value: &Decimal, options: &NotationOptions) -> String {
+        (**self).format(value, options)
+    }
+}
+
+/// `1.23e45`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Scientific;
+
+impl Notation for Scientific {
+    fn name(&self) -> &str {
+        "scientific"
+    }
+
+    fn format_abbreviated(&self, value: &Decimal, options: &NotationOptions) -> String {
+        scientific(value, options)
+    }
+}
+
+/// `123.45e42`, with an exponent which is a multiple of three.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Engineering;
+
+impl Notation for Engineering {
+    fn name(&self) -> &str {
+        "engineering"
+    }
+
+    fn format_abbreviated(&self, value: &Decimal, options: &NotationOptions) -> String {
+        if value.exponent.abs() >= EXPONENT_LIMIT {
+            return scientific(value, options);
+        }
+
+        let (mantissa, exponent) = split(value, options.places, 3.0);
+        to_fixed(mantissa, options.places) + "e" + &format_exponent(exponent, options)
+    }
+}
+
+/// `1.23K`, `45.67Qa`, with the suffixes of the locale, and scientific notation after them.
+///
+/// The suffixes of most locales are the short-scale suffixes of
+/// [`short_scale_suffix`](crate::suffix::short_scale_suffix) up to 1e300, and numbers in this
+/// notation can then be parsed back into a Decimal.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Standard;
+
+impl Notation for Standard {
+    fn name(&self) -> &str {
+        "standard"
+    }
+
+    fn format_abbreviated(&self, value: &Decimal, options: &NotationOptions) -> String {
+        suffixed(value, options, |exponent| {
+            options.locale.suffixes.for_exponent(exponent)
+        })
+    }
+}
+
+/// `1.23K`, `45.67Md`, with the long-scale suffixes of [`long_scale_suffix`] up to 1e597, and
+/// scientific notation after them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LongScale;
+
+impl Notation for LongScale {
+    fn name(&self) -> &str {
+        "long scale"
+    }
+
+    fn format_abbreviated(&self, value: &Decimal, options: &NotationOptions) -> String {
+        suffixed(value, options, |exponent| {
+            per_thousand(exponent, long_scale_suffix)
+        })
+    }
+}
+
+/// `1.23T`, `45.67aa`, with the suffixes of [`alphabetic_suffix`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Alphabetic;
+
+impl Notation for Alphabetic {
+    fn name(&self) -> &str {
+        "alphabetic"
+    }
+
+    fn format_abbreviated(&self, value: &Decimal, options: &NotationOptions) -> String {
+        suffixed(value, options, |exponent| {
+            per_thousand(exponent, alphabetic_suffix)
+        })
+    }
+}
+
+/// Standard notation below 1e33, and scientific notation from there on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MixedScientific;
+
+impl Notation for MixedScientific {
+    fn name(&self) -> &str {
+        "mixed scientific"
+    }
+
+    fn format_abbreviated(&self, value: &Decimal, options: &NotationOptions) -> String {
+        suffixed(value, options, |exponent| {
+            options
+                .locale
+                .suffixes
+                .for_exponent(exponent)
+                .filter(|&(power, _)| power <= MIXED_SCIENTIFIC_MAX_POWER)
+        })
+    }
+}
+
+/// `e45.09`, the common logarithm of the number.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Logarithmic;
+
+impl Notation for Logarithmic {
+    fn name(&self) -> &str {
+        "logarithmic"
+    }
+
+    fn format_abbreviated(&self, value: &Decimal, options: &NotationOptions) -> String {
+        let log = value.exponent + value.mantissa.log10();
+        let sign = if log < 0.0 { "-" } else { "" };
+        let formatted = if log.abs() < EXPONENT_LIMIT {
+            to_fixed(log.abs(), options.places)
+        } else {
+            scientific(&Decimal::new(log.abs()), options)
+        };
+
+        "e".to_owned() + sign + &formatted
+    }
+}
+
+/// Shows nothing at all.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Blind;
+
+impl Notation for Blind {
+    fn name(&self) -> &str {
+        "blind"
+    }
+
+    fn format_abbreviated(&self, _value: &Decimal, _options: &NotationOptions) -> String {
+        String::new()
+    }
+
+    fn format(&self, _value: &Decimal, _options: &NotationOptions) -> String {
+        String::new()
+    }
+}
+
+/// Shows every number from a cap on as `Infinity`, and the others with another notation.
+///
+/// The cap defaults to the largest f64, where games written for break_infinity.js usually
+/// end.
+#[derive(Clone, Debug)]
+pub struct InfinityCap<N> {
+    pub notation: N,
+    pub cap: Decimal,
+}
+
+impl<N> InfinityCap<N> {
+    pub fn new(notation: N) -> Self {
+        InfinityCap {
+            notation,
+            cap: Decimal::new(f64::MAX),
+        }
+    }
+
+    /// Returns the notation with the given cap.
+    pub fn at(self, cap: Decimal) -> Self {
+        InfinityCap { cap, ..self }
+    }
+}
+
+impl<N: Notation> Notation for InfinityCap<N> {
+    /// Returns the name of the notation it caps, except that capped scientific notation is the
+    /// built-in `"infinity"`.
+    fn name(&self) -> &str {
+        match self.notation.name() {
+            "scientific" => "infinity",
+            name => name,
+        }
+    }
+
+    fn format_abbreviated(&self, value: &Decimal, options: &NotationOptions) -> String {
+        if value.gte(&self.cap) {
+            String::from("Infinity")
+        } else {
+            self.notation.format_abbreviated(value, options)
+        }
+    }
+
+    fn format(&self, value: &Decimal, options: &NotationOptions) -> String {
+        if value.abs().gte(&self.cap) {
+            options
+                .locale
+                .localize_with(&signed(value, String::from("Infinity")), options.commas)
+        } else {
+            self.notation.format(value, options)
+        }
+    }
+}
+
+/// Returns the built-in notation with the given name, one of [`NAMES`].
+///
+/// `"infinity"` is scientific notation with an [`InfinityCap`] at the largest f64.
+pub fn by_name(name: &str) -> Option<Box<dyn Notation>> {
+    Some(match name {
+        "scientific" => Box::new(Scientific),
+        "engineering" => Box::new(Engineering),
+        "standard" => Box::new(Standard),
+        "long scale" => Box::new(LongScale),
+        "alphabetic" => Box::new(Alphabetic),
+        "mixed scientific" => Box::new(MixedScientific),
+        "logarithmic" => Box::new(Logarithmic),
+        "infinity" => Box::new(InfinityCap::new(Scientific)),
+        "blind" => Box::new(Blind),
+        _ => return None,
+    })
+}
+
+/// Writes out a number below 1000 in full, unless it rounds to zero or to 1000.
+fn in_full(magnitude: &Decimal, options: &NotationOptions) -> Option<String> {
+    let places = options.places_under_1000;
+    let rounded = magnitude.round_to(places as i64, RoundingMode::HalfUp);
+    let rounds_to_zero = rounded.mantissa == 0.0 && magnitude.mantissa != 0.0;
+
+    if rounds_to_zero || rounded.exponent >= 3.0 {
+        None
+    } else {
+        Some(rounded.to_fixed(places))
+    }
+}
+
+fn signed(value: &Decimal, formatted: String) -> String {
+    if value.mantissa < 0.0 {
+        "-".to_owned() + &formatted
+    } else {
+        formatted
+    }
+}
+
+/// Rounds a number to `places` digits after the point of a mantissa whose exponent is a
+/// multiple of `step`, and returns that mantissa and exponent.
+fn split(value: &Decimal, places: u32, step: f64) -> (f64, f64) {
+    let exponent = (value.exponent / step).floor() * step;
+    let rounded = value.round_to(places as i64 - exponent as i64, RoundingMode::HalfUp);
+    let exponent = (rounded.exponent / step).floor() * step;
+
+    let mantissa = rounded.mantissa * 10.0_f64.powi((rounded.exponent - exponent) as i32);
+    (mantissa, exponent)
+}
+
+fn scientific(value: &Decimal, options: &NotationOptions) -> String {
+    if value.exponent.abs() >= EXPONENT_LIMIT {
+        return "e".to_owned() + &format_exponent(value.exponent, options);
+    }
+
+    let (mantissa, exponent) = split(value, options.places, 1.0);
+    to_fixed(mantissa, options.places) + "e" + &format_exponent(exponent, options)
+}
+
+/// Shows a number with the suffix of the largest power of ten at or below it, or in scientific
+/// notation if there is none. `suffix` returns that power and suffix for an exponent.
+fn suffixed(
+    value: &Decimal,
+    options: &NotationOptions,
+    suffix: impl Fn(f64) -> Option<(u32, String)>,
+) -> String {
+    if value.exponent >= 0.0 && value.exponent < EXPONENT_LIMIT {
+        // A number just below the first power with a suffix may round up to it.
+        let power = suffix(value.exponent).or_else(|| {
+            let rounded = value.round_to(
+                options.places as i64 - value.exponent as i64,
+                RoundingMode::HalfUp,
+            );
+            suffix(rounded.exponent)
+        });
+
+        if let Some((power, _)) = power {
+            // Rounding may carry into the next power with a suffix.
+            let rounded =
+                value.round_to(options.places as i64 - power as i64, RoundingMode::HalfUp);
+            if let Some((power, suffix)) = suffix(rounded.exponent) {
+                let mantissa =
+                    rounded.mantissa * 10.0_f64.powi((rounded.exponent - power as f64) as i32);
+                return to_fixed(mantissa, options.places) + &suffix;
+            }
+        }
+    }
+
+    scientific(value, options)
+}
+
+/// Formats an integer exponent, in full below [`EXPONENT_LIMIT`] and in scientific notation
+/// from there on.
+fn format_exponent(exponent: f64, options: &NotationOptions) -> String {
+    let sign = if exponent < 0.0 { "-" } else { "" };
+    let formatted = if exponent.abs() < EXPONENT_LIMIT {
+        to_fixed(exponent.abs(), 0)
+    } else {
+        scientific(&Decimal::new(exponent.abs()), options)
+    };
+
+    sign.to_owned() + &formatted
+}
+
+/// A notation and its options, for formatting numbers in JavaScript as in Rust.
+#[wasm_bindgen(js_name = Notation)]
+pub struct JsNotation {
+    notation: Box<dyn Notation>,
+    options: NotationOptions,
+}
+
+#[wasm_bindgen(js_class = Notation)]
+impl JsNotation {
+    /// Creates the built-in notation with the given name, such as `"scientific"` or
+    /// `"long scale"`, with the default options.
+    /// Throws if there is no such notation.
+    #[wasm_bindgen(constructor)]
+    pub fn js_new(name: &str) -> Result<JsNotation, JsError> {
+        let notation =
+            by_name(name).ok_or_else(|| JsError::new(&format!("unknown notation: {}", name)))?;
+        Ok(JsNotation {
+            notation,
+            options: NotationOptions::default(),
+        })
+    }
+
+    /// Returns the names of the built-in notations.
+    #[wasm_bindgen(js_name = names)]
+    pub fn js_names() -> Vec<String> {
+        NAMES.iter().map(|&name| String::from(name)).collect()
+    }
+
+    /// Returns the name of the notation.
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        String::from(self.notation.name())
+    }
+
+    /// Returns the digits after the decimal point of abbreviated numbers.
+    #[wasm_bindgen(getter)]
+    pub fn places(&self) -> u32 {
+        self.options.places
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_places(&mut self, places: u32) {
+        self.options.places = places;
+    }
+
+    /// Returns the digits after the decimal point of numbers below 1000.
+    #[wasm_bindgen(getter = placesUnder1000)]
+    pub fn places_under_1000(&self) -> u32 {
+        self.options.places_under_1000
+    }
+
+    #[wasm_bindgen(setter = placesUnder1000)]
+    pub fn set_places_under_1000(&mut self, places: u32) {
+        self.options.places_under_1000 = places;
+    }
+
+    /// Returns whether digits are grouped.
+    #[wasm_bindgen(getter)]
+    pub fn commas(&self) -> bool {
+        self.options.commas
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_commas(&mut self, commas: bool) {
+        self.options.commas = commas;
+    }
+
+    /// Returns the tag of the locale.
+    #[wasm_bindgen(getter)]
+    pub fn locale(&self) -> String {
+        String::from(self.options.locale.tag)
+    }
+
+    /// Writes numbers in the bundled locale with the given tag, such as `"de-DE"`.
+    /// Throws if there is no locale for the tag or its language.
+    #[wasm_bindgen(js_name = setLocale)]
+    pub fn js_set_locale(&mut self, tag: &str) -> Result<(), JsError> {
+        self.options.locale =
+            locale::by_tag(tag).ok_or_else(|| JsError::new(&format!("unknown locale: {}", tag)))?;
+        Ok(())
+    }
+
+    /// Shows every number from the given cap on as `Infinity`.
+    #[wasm_bindgen(js_name = capAt)]
+    pub fn js_cap_at(&mut self, cap: &DecimalSource) -> Result<(), JsError> {
+        let cap = from_source(cap)?;
+        let notation = std::mem::replace(&mut self.notation, Box::new(Blind));
+        self.notation = Box::new(InfinityCap::new(notation).at(cap));
+        Ok(())
+    }
+
+    /// Formats a number.
+    #[wasm_bindgen(js_name = format)]
+    pub fn js_format(&self, value: &DecimalSource) -> Result<String, JsError> {
+        Ok(self.notation.format(&from_source(value)?, &self.options))
+    }
+}
@@ -0,0 +1,201 @@
+//! [`num_traits`] support for [`Decimal`], so that it works with crates which are generic over
+//! numbers.
+//!
+//! Decimal behaves like the primitive floats, except that:
+//!
+//! - Its zero is unsigned, so [`Signed::signum`] of zero is zero, and zero is neither positive
+//!   nor negative.
+//! - [`Bounded`] gives the most extreme finite Decimals, which are far beyond `f64::MAX`.
+//! - [`Num::from_str_radix`] parses any Decimal in base 10, but only integers in other bases.
+//! - [`ToPrimitive::to_f64`] of a number beyond the range of an f64 is an infinity or zero.
+
+use ::num_traits::{Bounded, FromPrimitive, Num, One, Pow, Signed, ToPrimitive, Zero};
+use number_base::{BaseNumber, ParseDecimalError};
+
+use crate::{util::from_mantissa_exponent_no_normalize, Decimal, EXP_LIMIT};
+
+/// 2^127, the first f64 beyond an i128.
+const I128_LIMIT: f64 = 170141183460469231731687303715884105728.0;
+
+/// 2^128, the first f64 beyond a u128.
+const U128_LIMIT: f64 = 340282366920938463463374607431768211456.0;
+
+impl Zero for Decimal {
+    fn zero() -> Decimal {
+        Decimal::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.mantissa == 0.0
+    }
+}
+
+impl One for Decimal {
+    fn one() -> Decimal {
+        Decimal::ONE
+    }
+}
+
+impl Num for Decimal {
+    type FromStrRadixErr = ParseDecimalError;
+
+    /// Parses a number as [`FromStr`](std::str::FromStr) does in base 10, or an integer in
+    /// another base.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in `2..=36`.
+    fn from_str_radix(string: &str, radix: u32) -> Result<Decimal, ParseDecimalError> {
+        if radix == 10 {
+            return string.parse();
+        }
+
+        let string = string.trim();
+        if string.is_empty() {
+            return Err(ParseDecimalError::Empty);
+        }
+
+        i128::from_str_radix(string, radix)
+            .map(Decimal::from)
+            .map_err(|_| ParseDecimalError::InvalidMantissa)
+    }
+}
+
+impl Signed for Decimal {
+    fn abs(&self) -> Decimal {
+        BaseNumber::abs(self)
+    }
+
+    fn abs_sub(&self, other: &Decimal) -> Decimal {
+        if BaseNumber::lte(self, other) {
+            Decimal::ZERO
+        } else {
+            self - other
+        }
+    }
+
+    fn signum(&self) -> Decimal {
+        if self.is_nan() {
+            Decimal::NAN
+        } else {
+            Decimal::from(self.sign())
+        }
+    }
+
+    fn is_positive(&self) -> bool {
+        self.mantissa > 0.0
+    }
+
+    fn is_negative(&self) -> bool {
+        self.mantissa < 0.0
+    }
+}
+
+impl Bounded for Decimal {
+    fn min_value() -> Decimal {
+        -Decimal::max_value()
+    }
+
+    fn max_value() -> Decimal {
+        from_mantissa_exponent_no_normalize(
+            f64::from_bits(10.0_f64.to_bits() - 1),
+            f64::from_bits(EXP_LIMIT.to_bits() - 1),
+        )
+    }
+}
+
+impl FromPrimitive for Decimal {
+    fn from_i64(n: i64) -> Option<Decimal> {
+        Some(Decimal::from(n))
+    }
+
+    fn from_u64(n: u64) -> Option<Decimal> {
+        Some(Decimal::from(n))
+    }
+
+    fn from_i128(n: i128) -> Option<Decimal> {
+        Some(Decimal::from(n))
+    }
+
+    fn from_u128(n: u128) -> Option<Decimal> {
+        Some(Decimal::from(n))
+    }
+
+    fn from_f32(n: f32) -> Option<Decimal> {
+        Some(Decimal::from(n))
+    }
+
+    fn from_f64(n: f64) -> Option<Decimal> {
+        Some(Decimal::from(n))
+    }
+}
+
+/// Integers are truncated towards zero, and are `None` for NaN and numbers out of range.
+impl ToPrimitive for Decimal {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_i128().and_then(|n| i64::try_from(n).ok())
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u128().and_then(|n| u64::try_from(n).ok())
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        let number = self.trunc().to_number();
+        (-I128_LIMIT..I128_LIMIT)
+            .contains(&number)
+            .then_some(number as i128)
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        let number = self.trunc().to_number();
+        (0.0..U128_LIMIT)
+            .contains(&number)
+            .then_some(number as u128)
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.to_number())
+    }
+}
+
+/// Implements [`Pow`] for owned and borrowed Decimals with exponents of the given types.
+macro_rules! impl_pow {
+    ($($exponent:ty),*) => {
+        $(
+            impl Pow<$exponent> for Decimal {
+                type Output = Decimal;
+
+                fn pow(self, exponent: $exponent) -> Decimal {
+                    BaseNumber::pow(&self, &Decimal::from(exponent))
+                }
+            }
+
+            impl Pow<$exponent> for &Decimal {
+                type Output = Decimal;
+
+                fn pow(self, exponent: $exponent) -> Decimal {
+                    BaseNumber::pow(self, &Decimal::from(exponent))
+                }
+            }
+        )*
+    };
+}
+
+impl_pow!(i8, i16, i32, u8, u16, u32, f32, f64, Decimal);
+
+impl Pow<&Decimal> for Decimal {
+    type Output = Decimal;
+
+    fn pow(self, exponent: &Decimal) -> Decimal {
+        BaseNumber::pow(&self, exponent)
+    }
+}
+
+impl Pow<&Decimal> for &Decimal {
+    type Output = Decimal;
+
+    fn pow(self, exponent: &Decimal) -> Decimal {
+        BaseNumber::pow(self, exponent)
+    }
+}
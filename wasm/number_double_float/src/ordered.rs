@@ -0,0 +1,88 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter, LowerExp, UpperExp},
+    hash::{Hash, Hasher},
+};
+
+use crate::Decimal;
+
+/// A [`Decimal`] with a total order, so that it can be sorted and used as the key of a
+/// `BTreeMap`, `HashMap` or `BinaryHeap`.
+///
+/// Numbers are ordered by [`Decimal::total_cmp`]: NaN is equal to itself and sorts after
+/// +Infinity, and zeros are equal regardless of the sign of their mantissa.
+#[derive(Clone, Copy, Debug)]
+pub struct OrderedDecimal(pub Decimal);
+
+impl OrderedDecimal {
+    /// Returns the wrapped Decimal.
+    pub fn into_inner(self) -> Decimal {
+        self.0
+    }
+}
+
+impl From<Decimal> for OrderedDecimal {
+    fn from(decimal: Decimal) -> OrderedDecimal {
+        OrderedDecimal(decimal)
+    }
+}
+
+impl From<OrderedDecimal> for Decimal {
+    fn from(ordered: OrderedDecimal) -> Decimal {
+        ordered.0
+    }
+}
+
+impl PartialEq for OrderedDecimal {
+    fn eq(&self, other: &OrderedDecimal) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedDecimal {}
+
+impl PartialOrd for OrderedDecimal {
+    fn partial_cmp(&self, other: &OrderedDecimal) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedDecimal {
+    fn cmp(&self, other: &OrderedDecimal) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for OrderedDecimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Equal numbers must hash the same, so every NaN and every zero hash as one value.
+        let (mantissa, exponent) = if self.0.is_nan() {
+            (f64::NAN, f64::NAN)
+        } else if self.0.mantissa == 0.0 {
+            (0.0, 0.0)
+        } else {
+            (self.0.mantissa, self.0.exponent)
+        };
+
+        mantissa.to_bits().hash(state);
+        exponent.to_bits().hash(state);
+    }
+}
+
+impl Display for OrderedDecimal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl LowerExp for OrderedDecimal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        LowerExp::fmt(&self.0, f)
+    }
+}
+
+impl UpperExp for OrderedDecimal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        UpperExp::fmt(&self.0, f)
+    }
+}
@@ -0,0 +1,143 @@
+use std::str::FromStr;
+
+use number_base::ParseDecimalError;
+
+use crate::{suffix::short_scale_index, util::from_mantissa_exponent, Decimal};
+
+/// Returns the length of the longest prefix of the string which is a number,
+/// or 0 if the string does not start with one.
+///
+/// A number is an optional sign followed by digits. If `mantissa` is true, the digits
+/// may be grouped with `_` or `,` and may be followed by a fractional part. A separator
+/// must be followed by a group of exactly three digits, or it ends the number.
+/// At least one digit is required.
+fn number_prefix_len(string: &str, mantissa: bool) -> usize {
+    let bytes = string.as_bytes();
+    let mut index = 0;
+    let mut digits = 0;
+
+    if matches!(bytes.first(), Some(b'+' | b'-')) {
+        index += 1;
+    }
+
+    while index < bytes.len() {
+        if bytes[index].is_ascii_digit() {
+            digits += 1;
+        } else if !(mantissa
+            && matches!(bytes[index], b'_' | b',')
+            && digits > 0
+            && bytes
+                .get(index + 1..index + 4)
+                .is_some_and(|group| group.iter().all(u8::is_ascii_digit))
+            && !bytes.get(index + 4).is_some_and(u8::is_ascii_digit))
+        {
+            break;
+        }
+
+        index += 1;
+    }
+
+    if mantissa && index < bytes.len() && bytes[index] == b'.' {
+        index += 1;
+
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+            digits += 1;
+        }
+    }
+
+    if digits == 0 {
+        0
+    } else {
+        index
+    }
+}
+
+/// Returns the mantissa and exponent of a number from [`number_prefix_len`].
+///
+/// The digits may stand for a number beyond the range of an f64, like a 1 followed by 400
+/// zeros, so such a number is read with the point moved after its first significant digit.
+fn split_mantissa(number: &str) -> Result<(f64, f64), ParseDecimalError> {
+    let number = number.replace(['_', ','], "");
+    let value: f64 = number
+        .parse()
+        .map_err(|_| ParseDecimalError::InvalidMantissa)?;
+    if value.is_normal() {
+        return Ok((value, 0.0));
+    }
+
+    let (sign, unsigned) =
+        number.split_at(number.len() - number.trim_start_matches(['+', '-']).len());
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let digits = integer.to_owned() + fraction;
+    let Some(first) = digits.find(|digit| digit != '0') else {
+        return Ok((0.0, 0.0));
+    };
+
+    let mantissa = format!(
+        "{}{}.{}",
+        sign,
+        &digits[first..=first],
+        &digits[first + 1..]
+    )
+    .parse()
+    .map_err(|_| ParseDecimalError::InvalidMantissa)?;
+    Ok((mantissa, integer.len() as f64 - 1.0 - first as f64))
+}
+
+impl FromStr for Decimal {
+    type Err = ParseDecimalError;
+
+    /// Parses a Decimal from a string.
+    ///
+    /// Besides `"NaN"` and `"Infinity"`, the string may contain a number with an optional sign
+    /// (`"-123.45"`), digits grouped in threes with `_` or `,` (`"1,000,000"`), an exponent in
+    /// either case (`"1.2345E+2"`) and a short-scale suffix (`"1.5K"`, `"3.2e6M"`).
+    /// Surrounding whitespace is ignored.
+    fn from_str(string: &str) -> Result<Decimal, ParseDecimalError> {
+        let string = string.trim();
+
+        if string.is_empty() {
+            return Err(ParseDecimalError::Empty);
+        } else if string == "NaN" {
+            return Ok(Decimal::NAN);
+        }
+
+        let unsigned = string.trim_start_matches(['+', '-']);
+        if unsigned.len() + 1 >= string.len() && unsigned.eq_ignore_ascii_case("Infinity") {
+            return Ok(if string.starts_with('-') {
+                Decimal::NEG_INFINITY
+            } else {
+                Decimal::INFINITY
+            });
+        }
+
+        let length = number_prefix_len(string, true);
+        if length == 0 {
+            return Err(ParseDecimalError::InvalidMantissa);
+        }
+
+        let (mantissa, mut exponent) = split_mantissa(&string[..length])?;
+        let mut rest = &string[length..];
+
+        if let Some(after) = rest.strip_prefix(['e', 'E']) {
+            let length = number_prefix_len(after, false);
+            if length == 0 {
+                return Err(ParseDecimalError::InvalidExponent);
+            }
+
+            exponent = after[..length]
+                .parse()
+                .map_err(|_| ParseDecimalError::InvalidExponent)?;
+            rest = &after[length..];
+        }
+
+        let suffix = rest.trim_start();
+        if !suffix.is_empty() {
+            let index = short_scale_index(suffix).ok_or(ParseDecimalError::TrailingCharacters)?;
+            exponent += 3.0 * index as f64;
+        }
+
+        Ok(from_mantissa_exponent(mantissa, exponent))
+    }
+}
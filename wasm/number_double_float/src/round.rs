@@ -0,0 +1,117 @@
+//! Rounding on the decimal digits of a Decimal, so that the result does not depend on the
+//! binary value of the mantissa or on the size of the exponent.
+
+use number_base::RoundingMode;
+
+use crate::{util::from_mantissa_exponent, Decimal};
+
+/// The shortest decimal digits which read back as the mantissa, and the power of ten of the
+/// first one.
+struct Digits {
+    negative: bool,
+    digits: Vec<u8>,
+    lead: f64,
+}
+
+impl Digits {
+    /// Returns the digits of a finite number other than zero.
+    fn of(decimal: &Decimal) -> Digits {
+        let formatted = format!("{:e}", decimal.mantissa.abs());
+        let (digits, exponent) = formatted.split_once('e').unwrap();
+        Digits {
+            negative: decimal.mantissa < 0.0,
+            digits: digits
+                .bytes()
+                .filter(u8::is_ascii_digit)
+                .map(|digit| digit - b'0')
+                .collect(),
+            lead: decimal.exponent + exponent.parse::<f64>().unwrap(),
+        }
+    }
+
+    /// Returns whether dropping the digits from `keep` on moves the kept ones away from zero.
+    fn rounds_away(&self, keep: usize, mode: RoundingMode) -> bool {
+        // The shortest digits never end in a zero, so whatever is dropped is more than zero.
+        let first = self.digits[keep];
+        let tie = first == 5 && keep + 1 == self.digits.len();
+        let odd = keep > 0 && self.digits[keep - 1] % 2 == 1;
+
+        match mode {
+            RoundingMode::TowardZero => false,
+            RoundingMode::Floor => self.negative,
+            RoundingMode::Ceil => !self.negative,
+            RoundingMode::HalfUp => first >= 5,
+            RoundingMode::HalfEven => first > 5 || (first == 5 && (!tie || odd)),
+        }
+    }
+}
+
+/// Rounds a number to the given number of decimal places.
+pub(crate) fn round_to_places(decimal: &Decimal, places: i64, mode: RoundingMode) -> Decimal {
+    round(decimal, |lead| lead + 1.0 + places as f64, mode)
+}
+
+/// Rounds a number to the given number of significant digits.
+pub(crate) fn round_to_significant(decimal: &Decimal, digits: u32, mode: RoundingMode) -> Decimal {
+    round(decimal, |_| digits.max(1) as f64, mode)
+}
+
+/// Rounds a number to the number of significant digits `keep` returns for the power of ten of
+/// the first one, which may be fewer than one when rounding to decimal places.
+fn round(decimal: &Decimal, keep: impl Fn(f64) -> f64, mode: RoundingMode) -> Decimal {
+    if !decimal.is_finite() || decimal.mantissa == 0.0 {
+        return *decimal;
+    }
+
+    let digits = Digits::of(decimal);
+    let keep = keep(digits.lead);
+    if keep >= digits.digits.len() as f64 {
+        return *decimal;
+    }
+
+    let sign = if digits.negative { -1.0 } else { 1.0 };
+    let unit = digits.lead + 1.0 - keep;
+
+    if keep < 0.0 {
+        // Every digit is dropped, and there are zeros between them and the kept digit, so they
+        // add up to less than half of it.
+        return match mode {
+            RoundingMode::Floor if digits.negative => from_mantissa_exponent(-1.0, unit),
+            RoundingMode::Ceil if !digits.negative => from_mantissa_exponent(1.0, unit),
+            _ => Decimal::ZERO,
+        };
+    }
+
+    let keep = keep as usize;
+    let mut kept = digits.digits[..keep].to_vec();
+    let mut lead = digits.lead;
+
+    if digits.rounds_away(keep, mode) {
+        match kept.iter().rposition(|&digit| digit != 9) {
+            Some(index) => {
+                kept[index] += 1;
+                kept.truncate(index + 1);
+            }
+            None => {
+                // Every kept digit was a 9, or none was kept, so the result is the next power
+                // of ten.
+                kept = vec![1];
+                lead = unit + keep as f64;
+            }
+        }
+    }
+
+    if kept.is_empty() {
+        return Decimal::ZERO;
+    }
+
+    let mut mantissa = String::with_capacity(kept.len() + 1);
+    for (index, digit) in kept.iter().enumerate() {
+        if index == 1 {
+            mantissa.push('.');
+        }
+        mantissa.push((b'0' + digit) as char);
+    }
+
+    from_mantissa_exponent(sign * mantissa.parse::<f64>().unwrap(), lead)
+}
@@ -0,0 +1,190 @@
+//! Serde support for [`Decimal`].
+//!
+//! By default a Decimal is serialized as its canonical string (see
+//! [`Decimal::to_canonical_string`]) in human-readable formats, which round-trips exactly and
+//! keeps NaN and the infinities intact in formats like JSON. Binary formats, which are not
+//! always self-describing, get the fixed `(mantissa, exponent)` tuple instead.
+//!
+//! Fields can pick a representation with `#[serde(with = "...")]`:
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Save {
+//!     #[serde(with = "number_double_float::serde::string")]
+//!     coins: Decimal, // "1.5e300"
+//!     #[serde(with = "number_double_float::serde::object")]
+//!     gems: Decimal, // { "mantissa": 1.5, "exponent": 300 }
+//!     #[serde(with = "number_double_float::serde::tuple")]
+//!     prestige: Decimal, // [1.5, 300]
+//!     #[serde(with = "number_double_float::serde::any")]
+//!     upgrades: Decimal, // any of the above, or a plain number
+//! }
+//! ```
+//!
+//! The object and tuple forms store the mantissa and exponent as finite floats, so that they
+//! round-trip through formats without NaN and infinite floats, like JSON. An infinity has a
+//! mantissa of ±1 and NaN a mantissa of 0, both with an exponent of [`EXP_LIMIT`], which no
+//! finite Decimal reaches.
+
+use std::fmt::{self, Formatter};
+
+use ::serde::{
+    de::{Error, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeStruct, SerializeTuple},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use number_base::BaseNumber;
+
+use crate::{util::from_mantissa_exponent, Decimal, EXP_LIMIT};
+
+impl Serialize for Decimal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            string::serialize(self, serializer)
+        } else {
+            tuple::serialize(self, serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Decimal {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(DecimalVisitor)
+        } else {
+            tuple::deserialize(deserializer)
+        }
+    }
+}
+
+/// Accepts every representation of a Decimal.
+struct DecimalVisitor;
+
+impl<'de> Visitor<'de> for DecimalVisitor {
+    type Value = Decimal;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "a number, a string, a [mantissa, exponent] pair or a {{ mantissa, exponent }} map"
+        )
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Decimal, E> {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_f64<E: Error>(self, value: f64) -> Result<Decimal, E> {
+        Ok(Decimal::new(value))
+    }
+
+    fn visit_i64<E: Error>(self, value: i64) -> Result<Decimal, E> {
+        Ok(Decimal::from(value))
+    }
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<Decimal, E> {
+        Ok(Decimal::from(value))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Decimal, A::Error> {
+        let mantissa = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let exponent = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+
+        Ok(from_parts(mantissa, exponent))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Decimal, A::Error> {
+        let mut mantissa = None;
+        let mut exponent = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "mantissa" => mantissa = Some(map.next_value()?),
+                "exponent" => exponent = Some(map.next_value()?),
+                _ => return Err(A::Error::unknown_field(&key, FIELDS)),
+            }
+        }
+
+        Ok(from_parts(
+            mantissa.ok_or_else(|| A::Error::missing_field("mantissa"))?,
+            exponent.ok_or_else(|| A::Error::missing_field("exponent"))?,
+        ))
+    }
+}
+
+const FIELDS: &[&str] = &["mantissa", "exponent"];
+
+/// Returns the mantissa and exponent which the object and tuple forms store.
+fn parts(value: &Decimal) -> (f64, f64) {
+    if value.is_nan() {
+        (0.0, EXP_LIMIT)
+    } else if value.is_infinite() {
+        (value.mantissa.signum(), EXP_LIMIT)
+    } else {
+        (value.mantissa, value.exponent)
+    }
+}
+
+/// Returns the Decimal with the mantissa and exponent from [`parts`].
+fn from_parts(mantissa: f64, exponent: f64) -> Decimal {
+    if mantissa == 0.0 && exponent >= EXP_LIMIT {
+        Decimal::NAN
+    } else {
+        from_mantissa_exponent(mantissa, exponent)
+    }
+}
+
+/// Serializes a Decimal as its canonical string, e.g. `"1.5e300"`.
+pub mod string {
+    pub use number_base::serde::string::{deserialize, serialize};
+}
+
+/// Serializes a Decimal as a `{ "mantissa": .., "exponent": .. }` map.
+pub mod object {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &Decimal, serializer: S) -> Result<S::Ok, S::Error> {
+        let (mantissa, exponent) = parts(value);
+        let mut state = serializer.serialize_struct("Decimal", 2)?;
+        state.serialize_field("mantissa", &mantissa)?;
+        state.serialize_field("exponent", &exponent)?;
+        state.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+        deserializer.deserialize_struct("Decimal", FIELDS, DecimalVisitor)
+    }
+}
+
+/// Serializes a Decimal as a `[mantissa, exponent]` pair.
+pub mod tuple {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &Decimal, serializer: S) -> Result<S::Ok, S::Error> {
+        let (mantissa, exponent) = parts(value);
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&mantissa)?;
+        state.serialize_element(&exponent)?;
+        state.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+        deserializer.deserialize_tuple(2, DecimalVisitor)
+    }
+}
+
+/// Serializes a Decimal as its canonical string, and deserializes any of the representations
+/// above or a plain number. This needs a self-describing format, like JSON.
+pub mod any {
+    use super::*;
+
+    pub use super::string::serialize;
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+        deserializer.deserialize_any(DecimalVisitor)
+    }
+}
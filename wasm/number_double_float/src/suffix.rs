@@ -0,0 +1,89 @@
+/// Suffixes for the first ten powers of 1000, starting at 1e3.
+const FIRST: [&str; 10] = ["K", "M", "B", "T", "Qa", "Qi", "Sx", "Sp", "Oc", "No"];
+
+/// Prefixes for the units digit of larger powers of 1000.
+const UNITS: [&str; 10] = ["", "U", "D", "T", "Qa", "Qi", "Sx", "Sp", "Oc", "No"];
+
+/// Suffixes for the tens digit of larger powers of 1000.
+const TENS: [&str; 10] = [
+    "", "Dc", "Vg", "Tg", "Qag", "Qig", "Sxg", "Spg", "Ocg", "Nog",
+];
+
+/// The largest power of 1000 which has a short-scale suffix.
+pub const MAX_SHORT_SCALE_INDEX: u32 = 100;
+
+/// Returns the short-scale suffix for 1000^index, e.g. `K` for 1, `M` for 2 and `UDc` for 12.
+pub fn short_scale_suffix(index: u32) -> Option<String> {
+    match index {
+        1..=10 => Some(String::from(FIRST[index as usize - 1])),
+        11..=MAX_SHORT_SCALE_INDEX => {
+            let illion = (index - 1) as usize;
+            Some(String::from(UNITS[illion % 10]) + TENS[illion / 10])
+        }
+        _ => None,
+    }
+}
+
+/// The largest power of 1000 which has a long-scale suffix.
+pub const MAX_LONG_SCALE_INDEX: u32 = 2 * MAX_SHORT_SCALE_INDEX - 1;
+
+/// Returns the long-scale suffix for 1000^index, where 1000^(2n) is the n-illion and
+/// 1000^(2n+1) the n-illiard, e.g. `K` for 1, `M` for 2, `Md` for 3 and `B` for 4.
+pub fn long_scale_suffix(index: u32) -> Option<String> {
+    match index {
+        1 => Some(String::from("K")),
+        2..=MAX_LONG_SCALE_INDEX => {
+            let illion = short_scale_suffix(index / 2 + 1)?;
+            Some(if index % 2 == 1 { illion + "d" } else { illion })
+        }
+        _ => None,
+    }
+}
+
+/// Returns the alphabetic suffix for 1000^index: `K`, `M`, `B` and `T`, followed by `aa`, `ab`,
+/// …, `zz`, `aaa` and so on.
+pub fn alphabetic_suffix(index: u32) -> Option<String> {
+    match index {
+        0 => None,
+        1..=4 => short_scale_suffix(index),
+        _ => {
+            let mut rest = (index - 5) as u64;
+            let mut length = 2;
+            while rest >= 26u64.pow(length) {
+                rest -= 26u64.pow(length);
+                length += 1;
+            }
+
+            let mut letters = vec![b'a'; length as usize];
+            for letter in letters.iter_mut().rev() {
+                *letter += (rest % 26) as u8;
+                rest /= 26;
+            }
+
+            Some(String::from_utf8(letters).unwrap())
+        }
+    }
+}
+
+/// Returns the power of ten and the suffix of a number with the given exponent, for suffixes
+/// at every power of 1000.
+pub(crate) fn per_thousand(
+    exponent: f64,
+    suffix: impl Fn(u32) -> Option<String>,
+) -> Option<(u32, String)> {
+    let index = (exponent / 3.0).floor();
+    if index < 1.0 || index > u32::MAX as f64 / 3.0 {
+        return None;
+    }
+
+    suffix(index as u32).map(|suffix| (3 * index as u32, suffix))
+}
+
+/// Returns the power of 1000 denoted by the given short-scale suffix, ignoring case.
+pub fn short_scale_index(suffix: &str) -> Option<u32> {
+    (1..=MAX_SHORT_SCALE_INDEX).find(|&index| {
+        short_scale_suffix(index)
+            .map(|candidate| candidate.eq_ignore_ascii_case(suffix))
+            .unwrap_or(false)
+    })
+}
@@ -1,5 +1,8 @@
-use crate::{Decimal, LENGTH, NUMBER_EXP_MIN};
+use std::{cmp::Ordering, f64::consts::LOG10_E};
+
+use crate::{Decimal, LENGTH, MAX_SIGNIFICANT_DIGITS, NUMBER_EXP_MIN};
 use lazy_static::lazy_static;
+use number_base::BaseNumber;
 
 pub fn from_mantissa_exponent_no_normalize(mantissa: f64, exponent: f64) -> Decimal {
     Decimal { mantissa, exponent }
@@ -7,13 +10,26 @@ pub fn from_mantissa_exponent_no_normalize(mantissa: f64, exponent: f64) -> Deci
 
 /// Creates a new instance of Decimal with the given mantissa and exponent with normalizing them.
 pub fn from_mantissa_exponent(mantissa: f64, exponent: f64) -> Decimal {
-    if !f64::is_finite(mantissa) || !f64::is_finite(exponent) {
-        return Decimal {
-            mantissa: f64::NAN,
-            exponent: f64::NAN,
+    if f64::is_nan(mantissa) || f64::is_nan(exponent) {
+        return Decimal::NAN;
+    } else if f64::is_infinite(mantissa) || exponent == f64::INFINITY {
+        return match mantissa.partial_cmp(&0.0) {
+            Some(Ordering::Greater) => Decimal::INFINITY,
+            Some(Ordering::Less) => Decimal::NEG_INFINITY,
+            _ => Decimal::ZERO,
         };
+    } else if exponent == f64::NEG_INFINITY {
+        return Decimal::ZERO;
     }
-    let decimal = from_mantissa_exponent_no_normalize(mantissa, exponent);
+
+    // A Decimal has an integer exponent, so a fractional part scales the mantissa instead.
+    let fraction = exponent.fract();
+    let mantissa = if fraction == 0.0 {
+        mantissa
+    } else {
+        mantissa * 10.0_f64.powf(fraction)
+    };
+    let decimal = from_mantissa_exponent_no_normalize(mantissa, exponent.trunc());
     decimal.normalize()
 }
 
@@ -21,7 +37,9 @@ lazy_static! {
     pub static ref CACHED_POWERS: [f64; LENGTH] = {
         let mut arr = [0.0; LENGTH];
         for (i, item) in &mut arr.iter_mut().enumerate() {
-            *item = 10.0_f64.powi((i as i32) + NUMBER_EXP_MIN);
+            // Parsing gives the correctly rounded power, unlike powi which underflows to 0
+            // for the smallest powers.
+            *item = format!("1e{}", (i as i32) + NUMBER_EXP_MIN).parse().unwrap();
         }
         arr
     };
@@ -31,6 +49,48 @@ pub fn power_of_10(power: i32) -> f64 {
     CACHED_POWERS[(power - NUMBER_EXP_MIN) as usize]
 }
 
+/// Returns 10 raised to the sum of a power and a small correction, which carries the digits
+/// that were rounded off of the power when it was computed.
+pub fn from_power_of_10(power: f64, correction: f64) -> Decimal {
+    if f64::is_nan(power) {
+        return Decimal::NAN;
+    } else if power == f64::INFINITY {
+        return Decimal::INFINITY;
+    } else if power == f64::NEG_INFINITY {
+        return Decimal::ZERO;
+    }
+
+    let exponent = power.floor();
+    from_mantissa_exponent(10.0_f64.powf(power - exponent + correction), exponent)
+}
+
+/// Returns `log10(ratio)` and `log10(ratio - 1)` for a finite ratio greater than 1, without
+/// losing the digits of ratios close to 1.
+pub fn ratio_logs(ratio: &Decimal) -> Option<(f64, f64)> {
+    if !ratio.is_finite() || ratio.le(&Decimal::ONE) {
+        return None;
+    }
+
+    let number = ratio.to_number();
+    Some(if number.is_finite() {
+        // Exact for ratios below 2, where it matters.
+        let increase = number - 1.0;
+        (increase.ln_1p() * LOG10_E, increase.log10())
+    } else {
+        let log = ratio.log10().to_number();
+        (log, log)
+    })
+}
+
+/// Returns `log10(1 + 10^power)`, without overflowing for large powers.
+pub fn log10_1p_exp10(power: f64) -> f64 {
+    if power > MAX_SIGNIFICANT_DIGITS as f64 {
+        power
+    } else {
+        10f64.powf(power).ln_1p() * LOG10_E
+    }
+}
+
 /// Pads the given string with the fill string to the given max length.
 pub fn pad_end(string: String, max_length: u32, fill_string: String) -> String {
     if f32::is_nan(max_length as f32) || f32::is_infinite(max_length as f32) {
@@ -58,10 +118,47 @@ pub fn pad_end(string: String, max_length: u32, fill_string: String) -> String {
         filled
     };
 
-    return string + truncated.as_str();
+    string + truncated.as_str()
 }
 
-/// Formats the given number to the given number of significant digits.
+/// Formats the given number with the given number of digits after the decimal point.
+///
+/// Like JavaScript's `toFixed`, a number exactly halfway between two results is rounded
+/// away from zero, where Rust's formatting would round it to even.
 pub fn to_fixed(num: f64, places: u32) -> String {
-    format!("{:.*}", places as usize, num)
+    let formatted = format!("{:.*}", places as usize, num);
+
+    // Only exact halves can round differently, and those end in a 5 one place further on.
+    // The full expansion of an f64 has at most 1074 digits after the point.
+    let longer = format!("{:.*}", places as usize + 1, num);
+    if !longer.ends_with('5') || format!("{:.1074}", num).trim_end_matches('0') != longer {
+        return formatted;
+    }
+
+    let truncated = longer[..longer.len() - 1].trim_end_matches('.');
+    if formatted != truncated {
+        return formatted;
+    }
+
+    // Round the magnitude up by one in the last place.
+    let mut digits = truncated.as_bytes().to_vec();
+    let mut index = digits.len();
+    loop {
+        if index == 0 || digits[index - 1] == b'-' {
+            digits.insert(index, b'1');
+            break;
+        }
+
+        index -= 1;
+        match digits[index] {
+            b'.' => {}
+            b'9' => digits[index] = b'0',
+            digit => {
+                digits[index] = digit + 1;
+                break;
+            }
+        }
+    }
+
+    String::from_utf8(digits).unwrap()
 }
@@ -0,0 +1,33 @@
+use number_base::BaseNumber;
+use number_double_float::Decimal;
+
+fn d(value: f64) -> Decimal {
+    Decimal::new(value)
+}
+
+#[test]
+fn powers_with_fractional_exponent_products_are_normalized() {
+    let root = d(10.0).pow(&d(-0.5));
+    assert_eq!(root.exponent(), -1.0);
+    assert!((root.mantissa() - 3.1622776601683795).abs() < 1e-15);
+
+    let power = d(1e5).pow(&d(1.5));
+    assert_eq!(power.exponent(), 7.0);
+    assert!((power.mantissa() - 3.1622776601683795).abs() < 1e-14);
+}
+
+#[test]
+fn powers_with_integer_exponent_products_keep_their_digits() {
+    assert_eq!(d(100.0).pow(&d(0.5)), d(10.0));
+    assert_eq!(d(2.0).pow(&d(10.0)), d(1024.0));
+    assert_eq!(d(3.0).pow(&d(-2.0)).exponent(), -1.0);
+}
+
+#[test]
+fn powers_of_huge_numbers_keep_the_digits_of_the_exponent_product() {
+    // (10^(10^15 + 7))^(1/3) = 10^333333333333335.64816..., where the exponent product is
+    // computed from the f64 closest to 1/3 and is not an f64 itself.
+    let power = Decimal::js_from_mantissa_exponent(1.0, 1e15 + 7.0).pow(&d(1.0 / 3.0));
+    assert_eq!(power.exponent(), 333333333333335.0);
+    assert!((power.mantissa() - 4.447981267766664).abs() < 1e-12);
+}
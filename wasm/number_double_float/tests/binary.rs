@@ -0,0 +1,104 @@
+use number_base::BaseNumber;
+use number_double_float::{
+    binary::{decode_slice, encode_slice, BinaryDecimalError, ENCODED_LEN},
+    Decimal,
+};
+
+fn bytes_of(mantissa: f64, exponent: f64) -> [u8; ENCODED_LEN] {
+    let mut bytes = [0; ENCODED_LEN];
+    bytes[..8].copy_from_slice(&mantissa.to_le_bytes());
+    bytes[8..].copy_from_slice(&exponent.to_le_bytes());
+    bytes
+}
+
+#[test]
+fn encodes_mantissa_and_exponent_little_endian() {
+    assert_eq!(Decimal::new(1500.0).to_bytes(), bytes_of(1.5, 3.0));
+}
+
+#[test]
+fn round_trips_single_decimals() {
+    for value in [
+        0.0,
+        1.0,
+        -2.5e-300,
+        5e-324,
+        f64::MAX,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ] {
+        let decimal = Decimal::new(value);
+        assert_eq!(Decimal::from_bytes(&decimal.to_bytes()), Ok(decimal));
+    }
+
+    let huge = Decimal::new(3.0).pow(&Decimal::new(1e300));
+    assert_eq!(Decimal::from_bytes(&huge.to_bytes()), Ok(huge));
+
+    let nan = Decimal::from_bytes(&Decimal::new(f64::NAN).to_bytes()).unwrap();
+    assert!(nan.mantissa().is_nan() && nan.exponent().is_nan());
+}
+
+#[test]
+fn round_trips_slices() {
+    let decimals: Vec<Decimal> = (0..1000)
+        .map(|i| Decimal::new(1.07).pow(&Decimal::new(i as f64 * 37.0)))
+        .collect();
+
+    let bytes = encode_slice(&decimals);
+    assert_eq!(bytes.len(), decimals.len() * ENCODED_LEN);
+    assert_eq!(decode_slice(&bytes), Ok(decimals));
+}
+
+#[test]
+fn normalizes_before_encoding() {
+    for (mantissa, exponent, expected) in [
+        (1.0, 0.5, Decimal::from_mantissa_exponent(1.0, 0.5)),
+        (1500.0, 0.0, Decimal::new(1500.0)),
+        (0.0, 3.0, Decimal::ZERO),
+        (2.0, f64::INFINITY, Decimal::INFINITY),
+    ] {
+        let decimal = Decimal::js_from_mantissa_exponent_no_normalize(mantissa, exponent);
+        assert_eq!(Decimal::from_bytes(&decimal.to_bytes()), Ok(expected));
+        assert_eq!(decode_slice(&encode_slice(&[decimal])), Ok(vec![expected]));
+    }
+}
+
+#[test]
+fn canonicalizes_nan_payloads() {
+    let negative_nan = f64::from_bits(0xFFF8_0000_0000_0000);
+    let nan = Decimal::js_from_mantissa_exponent_no_normalize(negative_nan, 0.0);
+
+    let bytes = encode_slice(&[nan]);
+    assert_eq!(bytes, bytes_of(f64::NAN, f64::NAN));
+}
+
+#[test]
+fn rejects_invalid_input() {
+    assert_eq!(
+        decode_slice(&[0; 17]),
+        Err(BinaryDecimalError::InvalidLength(17))
+    );
+
+    let mut bytes = Decimal::new(1.0).to_bytes().to_vec();
+    bytes.extend_from_slice(&bytes_of(f64::from_bits(0x7FF8_0000_0000_0001), f64::NAN));
+    assert_eq!(
+        decode_slice(&bytes),
+        Err(BinaryDecimalError::NonCanonicalNan { index: 1 })
+    );
+
+    for (mantissa, exponent) in [
+        (15.0, 2.0),
+        (0.5, 1.0),
+        (1.5, 0.5),
+        (0.0, 3.0),
+        (2.0, f64::INFINITY),
+    ] {
+        assert_eq!(
+            Decimal::from_bytes(&bytes_of(mantissa, exponent)),
+            Err(BinaryDecimalError::NotNormalized { index: 0 }),
+            "{} {}",
+            mantissa,
+            exponent
+        );
+    }
+}
@@ -0,0 +1,285 @@
+//! Checks Decimal against break_infinity.js, which it is a port of.
+//!
+//! The expected results in `tests/data/break_infinity.json` come from
+//! `tests/data/generate_break_infinity.mjs`. Every case is checked and every divergence is
+//! reported, so a single run shows everything that differs.
+
+use number_base::BaseNumber;
+use number_double_float::Decimal;
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Deserialize)]
+struct Corpus {
+    cases: Vec<Case>,
+}
+
+#[derive(Deserialize)]
+struct Case {
+    op: String,
+    args: Vec<Value>,
+    expected: Value,
+}
+
+/// How far a result may be from break_infinity.js's.
+#[derive(Clone, Copy, Debug)]
+enum Tolerance {
+    /// The result must be identical.
+    Exact,
+    /// The relative error may be at most this many units in the last place of an f64.
+    Ulps(f64),
+    /// The relative error may be at most this.
+    Relative(f64),
+}
+
+impl Tolerance {
+    fn for_op(op: &str) -> Tolerance {
+        match op {
+            "neg" | "abs" | "round" | "floor" | "ceil" | "trunc" | "cmp" | "toString"
+            | "toFixed" | "toExponential" | "toPrecision" => Tolerance::Exact,
+            "recip" | "toNumber" | "mul" | "div" => Tolerance::Ulps(2.0),
+            "log10" | "ln" | "log2" => Tolerance::Ulps(4.0),
+            // break_infinity.js rounds sums to 14 decimal places of the larger operand's
+            // mantissa, so their error is measured against that operand.
+            "add" | "sub" => Tolerance::Relative(1e-14),
+            "sqrt" | "cbrt" => Tolerance::Ulps(8.0),
+            // break_infinity.js computes powers from the logarithm, so their error grows with
+            // the size of the result's exponent.
+            "pow" => Tolerance::Relative(1e-12),
+            _ => panic!("no tolerance for {op}"),
+        }
+    }
+
+    fn allows(self, error: f64) -> bool {
+        match self {
+            Tolerance::Exact => error == 0.0,
+            Tolerance::Ulps(ulps) => error <= ulps * f64::EPSILON,
+            Tolerance::Relative(relative) => error <= relative,
+        }
+    }
+}
+
+/// A reason for diverging from break_infinity.js, and the cases it covers.
+type Known = (&'static str, fn(&Case) -> bool);
+
+/// Divergences from break_infinity.js which are intentional, with the reason for each.
+///
+/// A case which diverges is only accepted if one of these matches it, and each of these must
+/// still match some case, so fixed divergences do not linger here.
+const KNOWN: &[Known] = &[
+    (
+        // break_infinity.js takes the remainder of a negative exponent as negative, and then
+        // scales the mantissa by the root of 10 rather than of 100 or the other way around.
+        "cbrt of a number with a negative exponent which is not a multiple of 3",
+        |case| {
+            case.op == "cbrt"
+                && exponent(&case.args[0]) < 0.0
+                && exponent(&case.args[0]) % 3.0 != 0.0
+        },
+    ),
+    (
+        // JavaScript's Math.round rounds halves up, Rust's f64::round away from zero.
+        "round of a negative half",
+        |case| case.op == "round" && decimal(&case.args[0]).to_number().fract() == -0.5,
+    ),
+    (
+        // Decimal carries a mantissa which rounds up to 10 into the exponent, as
+        // Number.prototype.toPrecision does, and so switches to exponential notation one
+        // power of ten sooner.
+        "to_precision of a mantissa which rounds up to 10",
+        |case| {
+            case.op == "toPrecision" && {
+                let scale = 10f64.powf(number(&case.args[1]) - 1.0);
+                (mantissa(&case.args[0]) * scale).round().abs() >= 10.0 * scale
+            }
+        },
+    ),
+    (
+        // break_infinity.js pads the digits of a negative mantissa to one digit too few.
+        "to_fixed of a negative number of at least 1e17",
+        |case| {
+            case.op == "toFixed" && mantissa(&case.args[0]) < 0.0 && exponent(&case.args[0]) >= 17.0
+        },
+    ),
+    (
+        // break_infinity.js converts to an f64 first, which loses digits for subnormals.
+        "to_exponential or to_precision of a subnormal number",
+        |case| {
+            matches!(case.op.as_str(), "toExponential" | "toPrecision")
+                && exponent(&case.args[0]) < -307.0
+        },
+    ),
+    (
+        // Display writes all 17 significant digits rather than the shortest which read back.
+        "to_string of a number written with an exponent",
+        |case| {
+            case.op == "toString"
+                && !(-7.0 < exponent(&case.args[0]) && exponent(&case.args[0]) < 21.0)
+        },
+    ),
+];
+
+fn decimal(value: &Value) -> Decimal {
+    let parts = value
+        .as_array()
+        .expect("a decimal is a [mantissa, exponent] pair");
+    Decimal::from_mantissa_exponent(parts[0].as_f64().unwrap(), parts[1].as_f64().unwrap())
+}
+
+fn mantissa(value: &Value) -> f64 {
+    value[0].as_f64().unwrap()
+}
+
+fn exponent(value: &Value) -> f64 {
+    value[1].as_f64().unwrap()
+}
+
+fn number(value: &Value) -> f64 {
+    value.as_f64().expect("expected a number")
+}
+
+/// The error between two decimals relative to `scale`, or infinity if they are too far apart
+/// to compare their mantissas.
+fn decimal_error(actual: &Decimal, expected: &Decimal, scale: &Decimal) -> f64 {
+    if expected.mantissa() == 0.0 || actual.mantissa() == 0.0 {
+        return if actual.mantissa() == expected.mantissa() {
+            0.0
+        } else {
+            f64::INFINITY
+        };
+    }
+
+    let aligned = |decimal: &Decimal| {
+        let shift = decimal.exponent() - scale.exponent();
+        if shift > 1.0 {
+            f64::INFINITY
+        } else {
+            decimal.mantissa() * 10f64.powi(shift.max(-400.0) as i32)
+        }
+    };
+    ((aligned(actual) - aligned(expected)) / scale.mantissa()).abs()
+}
+
+fn number_error(actual: f64, expected: f64) -> f64 {
+    if actual == expected {
+        0.0
+    } else {
+        ((actual - expected) / expected).abs()
+    }
+}
+
+/// Runs a case, returning a description of the result if it diverges from break_infinity.js.
+fn check(case: &Case) -> Option<String> {
+    let x = decimal(&case.args[0]);
+    let tolerance = Tolerance::for_op(&case.op);
+
+    let (actual, error) = match case.op.as_str() {
+        "toString" | "toFixed" | "toExponential" | "toPrecision" => {
+            let places = case.args.get(1).map_or(0, |places| number(places) as u32);
+            let actual = match case.op.as_str() {
+                "toString" => x.to_string(),
+                "toFixed" => x.to_fixed(places),
+                "toExponential" => x.to_exponential(places),
+                _ => x.to_precision(places),
+            };
+            let matches = case.expected.as_str() == Some(actual.as_str());
+            (format!("{actual:?}"), if matches { 0.0 } else { 1.0 })
+        }
+        "log10" | "ln" | "log2" | "toNumber" | "cmp" => {
+            let actual = match case.op.as_str() {
+                "log10" => x.log10().to_number(),
+                "ln" => x.ln().to_number(),
+                "log2" => x.log2().to_number(),
+                "toNumber" => x.to_number(),
+                _ => match x.partial_cmp(&decimal(&case.args[1])) {
+                    Some(ordering) => ordering as i8 as f64,
+                    None => f64::NAN,
+                },
+            };
+            (
+                actual.to_string(),
+                number_error(actual, number(&case.expected)),
+            )
+        }
+        op => {
+            let actual = match op {
+                "neg" => -x,
+                "abs" => x.abs(),
+                "recip" => x.recip(),
+                "sqrt" => x.sqrt(),
+                "cbrt" => x.cbrt(),
+                "round" => x.round(),
+                "floor" => x.floor(),
+                "ceil" => x.ceil(),
+                "trunc" => x.trunc(),
+                "pow" => x.pow(&Decimal::new(number(&case.args[1]))),
+                "add" => x + decimal(&case.args[1]),
+                "sub" => x - decimal(&case.args[1]),
+                "mul" => x * decimal(&case.args[1]),
+                "div" => x / decimal(&case.args[1]),
+                _ => panic!("unknown operation {op}"),
+            };
+
+            let expected = decimal(&case.expected);
+            let scale = match op {
+                "add" | "sub" => {
+                    let y = decimal(&case.args[1]);
+                    if x.exponent() >= y.exponent() {
+                        x
+                    } else {
+                        y
+                    }
+                }
+                _ => expected,
+            };
+            let error = decimal_error(&actual, &expected, &scale);
+            (
+                format!("[{}, {}]", actual.mantissa(), actual.exponent()),
+                error,
+            )
+        }
+    };
+
+    (!tolerance.allows(error)).then(|| {
+        format!(
+            "{}({}): expected {}, got {} (error {:e}, tolerance {:?})",
+            case.op,
+            case.args
+                .iter()
+                .map(Value::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+            case.expected,
+            actual,
+            error,
+            tolerance
+        )
+    })
+}
+
+#[test]
+fn matches_break_infinity() {
+    let corpus: Corpus = serde_json::from_str(include_str!("data/break_infinity.json")).unwrap();
+    let mut divergences = Vec::new();
+    let mut known = vec![0; KNOWN.len()];
+
+    for case in &corpus.cases {
+        if let Some(divergence) = check(case) {
+            match KNOWN.iter().position(|(_, matches)| matches(case)) {
+                Some(index) => known[index] += 1,
+                None => divergences.push(divergence),
+            }
+        }
+    }
+
+    for ((reason, _), count) in KNOWN.iter().zip(&known) {
+        assert!(*count > 0, "no case diverges with {reason}");
+    }
+    assert!(
+        divergences.is_empty(),
+        "{} of {} cases diverge from break_infinity.js:\n{}",
+        divergences.len(),
+        corpus.cases.len(),
+        divergences.join("\n")
+    );
+}
@@ -0,0 +1,104 @@
+use number_base::BaseNumber;
+use number_double_float::Decimal;
+
+/// A xorshift generator, so the corpus is the same on every run.
+struct Xorshift(u64);
+
+impl Xorshift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn next_finite(&mut self) -> f64 {
+        loop {
+            let value = f64::from_bits(self.next());
+            if value.is_finite() {
+                return value;
+            }
+        }
+    }
+}
+
+fn assert_round_trips(decimal: Decimal) {
+    let string = decimal.to_canonical_string();
+    assert_eq!(string.parse::<Decimal>(), Ok(decimal), "{}", string);
+}
+
+#[test]
+fn formats_canonical_strings() {
+    assert_eq!(Decimal::new(0.0).to_canonical_string(), "0");
+    assert_eq!(Decimal::new(1500.0).to_canonical_string(), "1.5e3");
+    assert_eq!(Decimal::new(-0.00125).to_canonical_string(), "-1.25e-3");
+    assert_eq!(Decimal::new(f64::NAN).to_canonical_string(), "NaN");
+    assert_eq!(
+        Decimal::new(f64::INFINITY).to_canonical_string(),
+        "Infinity"
+    );
+    assert_eq!(
+        Decimal::new(f64::NEG_INFINITY).to_canonical_string(),
+        "-Infinity"
+    );
+}
+
+#[test]
+fn special_values_round_trip() {
+    for value in [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        116.0,
+        5e-324,
+        f64::MIN_POSITIVE,
+        f64::MAX,
+        f64::MIN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ] {
+        assert_round_trips(Decimal::new(value));
+    }
+
+    let nan: Decimal = Decimal::new(f64::NAN)
+        .to_canonical_string()
+        .parse()
+        .unwrap();
+    assert!(nan.mantissa().is_nan());
+}
+
+#[test]
+fn fractional_exponents_round_trip() {
+    let decimal = Decimal::from_mantissa_exponent(1.0, 0.5);
+    assert_eq!(decimal.exponent(), 0.0);
+    assert!((decimal.mantissa() - 10.0_f64.sqrt()).abs() < 1e-15);
+    assert_round_trips(decimal);
+
+    for (mantissa, exponent) in [(1.0, -0.5), (-2.5, 7.25), (9.0, 0.999), (1.5, -300.75)] {
+        let decimal = Decimal::from_mantissa_exponent(mantissa, exponent);
+        assert_eq!(decimal.exponent().fract(), 0.0);
+        assert_round_trips(decimal);
+    }
+}
+
+#[test]
+fn random_numbers_round_trip() {
+    let mut rng = Xorshift(0x9E37_79B9_7F4A_7C15);
+
+    for _ in 0..100_000 {
+        assert_round_trips(Decimal::new(rng.next_finite()));
+    }
+}
+
+#[test]
+fn large_exponents_round_trip() {
+    let mut rng = Xorshift(0xD1B5_4A32_D192_ED03);
+
+    for _ in 0..10_000 {
+        let base = Decimal::new((rng.next() % 1_000_000) as f64 / 1000.0 + 1.0);
+        let exponent = Decimal::new((rng.next() % 1_000_000_000) as f64 / 7.0);
+        assert_round_trips(base.pow(&exponent));
+        assert_round_trips(base.pow(&-exponent));
+    }
+}
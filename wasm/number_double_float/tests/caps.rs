@@ -0,0 +1,153 @@
+use number_base::{
+    caps::{Cap, ExponentRootSoftcap, Hardcap, LogSoftcap, PowerSoftcap, Tiered},
+    BaseNumber,
+};
+use number_double_float::Decimal;
+
+fn x(string: &str) -> Decimal {
+    string.parse().unwrap()
+}
+
+fn d(value: f64) -> Decimal {
+    Decimal::new(value)
+}
+
+/// Every kind of cap, with thresholds spread over the range of a Decimal.
+fn caps() -> Vec<(&'static str, Box<dyn Cap<Decimal>>)> {
+    vec![
+        ("hardcap", Box::new(Hardcap::new(x("1e50")))),
+        ("power", Box::new(PowerSoftcap::new(d(1e3), d(0.5)))),
+        ("log", Box::new(LogSoftcap::new(d(1e10)))),
+        (
+            "exponent root",
+            Box::new(ExponentRootSoftcap::new(x("1e100"), d(0.5))),
+        ),
+        (
+            "tiered",
+            Box::new(Tiered::new(vec![
+                PowerSoftcap::new(d(1e3), d(0.5)),
+                PowerSoftcap::new(d(1e6), d(0.25)),
+                PowerSoftcap::new(d(1e9), d(0.1)),
+            ])),
+        ),
+        (
+            "chain",
+            Box::new(
+                ExponentRootSoftcap::new(x("1e1000"), d(0.8))
+                    .then(PowerSoftcap::new(x("1e20"), d(0.3)))
+                    .then(LogSoftcap::new(x("1e300"))),
+            ),
+        ),
+        (
+            "mixed tiers",
+            Box::new(Tiered::<Box<dyn Cap<Decimal>>>::new(vec![
+                Box::new(LogSoftcap::new(d(1e5))),
+                Box::new(Hardcap::new(d(1e6))),
+            ])),
+        ),
+    ]
+}
+
+/// Raw values from tiny to enormous in ascending order, packed densely around the thresholds
+/// of [`caps`].
+fn values() -> Vec<Decimal> {
+    let mut values = Vec::new();
+    let mut exponent = -300.0;
+    while exponent < 1e7 {
+        for mantissa in [1.0, 1.5, 2.0, 3.7, 5.0, 9.99] {
+            values.push(Decimal::from_mantissa_exponent(mantissa, exponent));
+        }
+        exponent = if exponent < 1100.0 {
+            exponent + 1.0
+        } else {
+            (exponent * 1.5).floor()
+        };
+    }
+
+    for threshold in [
+        "1e3", "1e6", "1e9", "1e10", "1e20", "1e50", "1e100", "1e300", "1e1000",
+    ] {
+        let threshold = x(threshold);
+        for ulps in -4..=4 {
+            let scale = 1.0 + ulps as f64 * f64::EPSILON;
+            values.push(threshold * d(scale));
+        }
+    }
+
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    values
+}
+
+#[test]
+fn caps_never_decrease() {
+    let values = values();
+    for (name, cap) in caps() {
+        let mut previous = (Decimal::ZERO, Decimal::ZERO);
+        for value in &values {
+            let capped = cap.apply(value);
+            assert!(
+                capped.gte(&previous.1),
+                "{name} cap of {value:?} is {capped:?}, below {:?} for {:?}",
+                previous.1,
+                previous.0
+            );
+            previous = (*value, capped);
+        }
+    }
+}
+
+#[test]
+fn caps_do_not_raise_values() {
+    for (name, cap) in caps() {
+        for value in values() {
+            let capped = cap.apply(&value);
+            assert!(capped.lte(&value), "{name} cap of {value:?} is {capped:?}");
+        }
+    }
+
+    assert_eq!(PowerSoftcap::new(d(1e3), d(0.5)).apply(&d(999.0)), d(999.0));
+    assert_eq!(LogSoftcap::new(d(1e3)).apply(&d(1e3)), d(1e3));
+}
+
+#[test]
+fn inverses_undo_caps() {
+    for (name, cap) in caps() {
+        for value in values() {
+            let capped = cap.apply(&value);
+            let raw = cap.invert(&capped);
+            let recapped = cap.apply(&raw);
+            // The relative error of a value grows with the size of its exponent.
+            let error = ((recapped - capped) / capped).abs().to_number();
+            let tolerance = 1e-13 * (1.0 + capped.log10().abs().to_number());
+            assert!(
+                error <= tolerance,
+                "{name} cap of {value:?} is {capped:?}, which inverts to {raw:?} and caps to \
+                 {recapped:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn known_values() {
+    let power = PowerSoftcap::new(d(1e3), d(0.5));
+    assert_eq!(power.apply(&d(1e5)), d(1e4));
+    assert_eq!(power.invert(&d(1e4)), d(1e5));
+
+    let log = LogSoftcap::new(d(10.0));
+    assert_eq!(log.apply(&d(10.0 * std::f64::consts::E)), d(20.0));
+
+    let exponent_root = ExponentRootSoftcap::new(d(10.0), d(0.5));
+    assert_eq!(exponent_root.apply(&x("1e100")), x("1e10"));
+    assert_eq!(exponent_root.invert(&x("1e10")), x("1e100"));
+
+    let hardcap = Hardcap::new(d(100.0));
+    assert_eq!(hardcap.apply(&d(1e9)), d(100.0));
+    assert_eq!(hardcap.invert(&d(100.0)), d(100.0));
+    assert_eq!(hardcap.invert(&d(101.0)), Decimal::INFINITY);
+
+    let chain = power.then(hardcap);
+    assert_eq!(chain.apply(&d(1e9)), d(100.0));
+    assert_eq!(chain.invert(&d(50.0)), d(50.0));
+    assert_eq!(chain.invert(&d(200.0)), Decimal::INFINITY);
+}
@@ -0,0 +1,63 @@
+use number_base::BaseNumber;
+use number_double_float::{ArithmeticPolicy, Decimal, DecimalError};
+
+fn x(string: &str) -> Decimal {
+    string.parse().unwrap()
+}
+
+#[test]
+fn overflow_and_underflow_at_the_ends_of_the_range() {
+    let huge = Decimal::from_mantissa_exponent(1.0, 1e308);
+    let tiny = Decimal::from_mantissa_exponent(1.0, -1e308);
+
+    assert_eq!(huge.checked_mul(&huge), Err(DecimalError::Overflow));
+    assert_eq!(huge.checked_div(&tiny), Err(DecimalError::Overflow));
+    assert_eq!(tiny.checked_mul(&tiny), Err(DecimalError::Underflow));
+    assert_eq!(tiny.checked_div(&huge), Err(DecimalError::Underflow));
+    assert_eq!(
+        x("1e10").checked_pow(&x("1e308")),
+        Err(DecimalError::Overflow)
+    );
+    assert_eq!(
+        x("1e-10").checked_pow(&x("1e308")),
+        Err(DecimalError::Underflow)
+    );
+
+    // Numbers far beyond an f64 are fine.
+    assert_eq!(x("1e300").checked_mul(&x("1e300")), Ok(x("1e600")));
+    assert_eq!(x("1e1000").checked_pow(&x("2")), Ok(x("1e2000")));
+}
+
+#[test]
+fn saturating_at_a_configured_max() {
+    let max = x("1e1000");
+    assert_eq!(x("1e999").saturating_mul(&x("100"), &max), max);
+    assert_eq!(x("-1e999").saturating_mul(&x("100"), &max), -max);
+    assert_eq!(x("1e10").saturating_pow(&x("1e308"), &max), max);
+    assert_eq!(x("-8").saturating_pow(&x("0.5"), &max), Decimal::ZERO);
+    assert_eq!(x("1e500").saturating_add(&x("1e500"), &max), x("2e500"));
+}
+
+#[test]
+fn policies() {
+    let max = x("1e1000");
+    let saturate = ArithmeticPolicy::Saturate(max);
+    assert_eq!(saturate.mul(&x("1e999"), &x("100")), max);
+    assert_eq!(saturate.div(&x("1"), &Decimal::ZERO), max);
+    assert_eq!(saturate.sub(&x("3"), &x("1")), x("2"));
+
+    let expected = if cfg!(debug_assertions) {
+        ArithmeticPolicy::Panic
+    } else {
+        saturate
+    };
+    assert_eq!(ArithmeticPolicy::debug_panic(max), expected);
+}
+
+#[test]
+#[should_panic(
+    expected = "pow of [Decimal { mantissa: -8.0, exponent: 0.0 }, Decimal { mantissa: 5.0, exponent: -1.0 }]: operand outside of the domain of the operation"
+)]
+fn panicking_policy_names_the_operation() {
+    ArithmeticPolicy::Panic.pow(&x("-8"), &x("0.5"));
+}
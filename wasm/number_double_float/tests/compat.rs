@@ -0,0 +1,190 @@
+//! Checks the break_infinity.js-compatible JavaScript surface, which needs a JavaScript host.
+//!
+//! Run with `cargo test --target wasm32-unknown-unknown --test compat`, which uses
+//! wasm-bindgen-test-runner on Node.js.
+
+#![cfg(target_arch = "wasm32")]
+
+use js_sys::{Array, Function, Reflect};
+use number_base::BaseNumber;
+use number_double_float::Decimal;
+use wasm_bindgen::{prelude::*, JsCast};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen(module = "/js/break_infinity.js")]
+extern "C" {
+    /// The start function's hook, which is not run under wasm-bindgen-test.
+    #[wasm_bindgen(js_name = installStatics)]
+    fn install_statics_on(instance: JsValue);
+}
+
+fn d(value: f64) -> Decimal {
+    Decimal::new(value)
+}
+
+fn source<T: Into<JsValue>>(value: T) -> JsValue {
+    value.into()
+}
+
+/// Calls the static method of the Decimal class with the given name.
+fn call_static(name: &str, args: &[JsValue]) -> JsValue {
+    let class = Reflect::get(&JsValue::from(d(0.0)), &"constructor".into()).unwrap();
+    let method: Function = Reflect::get(&class, &name.into()).unwrap().unchecked_into();
+    let args: Array = args.iter().collect();
+    method.apply(&class, &args).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn eq_tolerance_is_relative_to_the_larger_operand() {
+    let value = d(100.0);
+    assert!(value
+        .js_eq_tolerance(source(100.5).unchecked_ref(), 0.01)
+        .unwrap());
+    assert!(!value
+        .js_eq_tolerance(source(102.0).unchecked_ref(), 0.01)
+        .unwrap());
+    assert!(value
+        .js_neq_tolerance(source(102.0).unchecked_ref(), 0.01)
+        .unwrap());
+
+    let huge = Decimal::js_from_mantissa_exponent(1.0, 1000.0);
+    let close = Decimal::js_from_mantissa_exponent(1.0000001, 1000.0);
+    assert!(huge
+        .js_eq_tolerance(source(close).unchecked_ref(), 1e-6)
+        .unwrap());
+    assert!(d(0.0)
+        .js_eq_tolerance(source(0.0).unchecked_ref(), 0.0)
+        .unwrap());
+    assert!(!d(0.0)
+        .js_eq_tolerance(source(1e-300).unchecked_ref(), 0.5)
+        .unwrap());
+}
+
+#[wasm_bindgen_test]
+fn tolerance_comparisons_treat_close_numbers_as_equal() {
+    let value = d(100.0);
+    assert!(value
+        .js_lte_tolerance(source(99.9).unchecked_ref(), 0.01)
+        .unwrap());
+    assert!(!value
+        .js_lt_tolerance(source(100.5).unchecked_ref(), 0.01)
+        .unwrap());
+    assert!(value
+        .js_lt_tolerance(source(102.0).unchecked_ref(), 0.01)
+        .unwrap());
+    assert!(!value
+        .js_gt_tolerance(source(99.9).unchecked_ref(), 0.01)
+        .unwrap());
+    assert!(value
+        .js_gte_tolerance(source(100.5).unchecked_ref(), 0.01)
+        .unwrap());
+}
+
+#[wasm_bindgen_test]
+fn max_and_min_accept_any_source() {
+    let value = d(5.0);
+    assert_eq!(value.js_max(source(7.0).unchecked_ref()).unwrap(), d(7.0));
+    assert_eq!(value.js_min(source("1e3").unchecked_ref()).unwrap(), d(5.0));
+    assert_eq!(
+        value.js_max(source(d(-1.0)).unchecked_ref()).unwrap(),
+        d(5.0)
+    );
+}
+
+#[wasm_bindgen_test]
+fn max_min_and_clamp_propagate_nan() {
+    let value = d(5.0);
+    assert!(value
+        .js_max(source("NaN").unchecked_ref())
+        .unwrap()
+        .is_nan());
+    assert!(value
+        .js_min(source(f64::NAN).unchecked_ref())
+        .unwrap()
+        .is_nan());
+    assert!(Decimal::NAN
+        .js_max(source(1.0).unchecked_ref())
+        .unwrap()
+        .is_nan());
+    assert!(value
+        .js_clamp_min(source(f64::NAN).unchecked_ref())
+        .unwrap()
+        .is_nan());
+    assert!(value
+        .js_clamp(source(0.0).unchecked_ref(), source("NaN").unchecked_ref())
+        .unwrap()
+        .is_nan());
+
+    assert_eq!(
+        value
+            .js_clamp(source(0.0).unchecked_ref(), source(3.0).unchecked_ref())
+            .unwrap(),
+        d(3.0)
+    );
+    assert!(value
+        .js_clamp(source(3.0).unchecked_ref(), source(0.0).unchecked_ref())
+        .is_err());
+}
+
+#[wasm_bindgen_test]
+fn sources_are_numbers_strings_and_decimal_like_objects() {
+    let value = d(1.0);
+    assert_eq!(value.js_plus(source(2.0).unchecked_ref()).unwrap(), d(3.0));
+    assert_eq!(
+        value.js_plus(source("2e0").unchecked_ref()).unwrap(),
+        d(3.0)
+    );
+    assert_eq!(
+        value.js_plus(source(d(2.0)).unchecked_ref()).unwrap(),
+        d(3.0)
+    );
+
+    // A break_infinity.js Decimal is read through its mantissa and exponent.
+    let object = js_sys::Object::new();
+    Reflect::set(&object, &"mantissa".into(), &2.0.into()).unwrap();
+    Reflect::set(&object, &"exponent".into(), &1.0.into()).unwrap();
+    assert_eq!(
+        value.js_plus(source(object).unchecked_ref()).unwrap(),
+        d(21.0)
+    );
+
+    // Objects which are not normalized are normalized on the way in.
+    let object = js_sys::Object::new();
+    Reflect::set(&object, &"mantissa".into(), &1500.0.into()).unwrap();
+    Reflect::set(&object, &"exponent".into(), &0.0.into()).unwrap();
+    let read = Decimal::js_from_value(object.unchecked_ref()).unwrap();
+    assert_eq!((read.mantissa(), read.exponent()), (1.5, 3.0));
+    assert_eq!(read, d(1500.0));
+}
+
+#[wasm_bindgen_test]
+fn invalid_sources_are_rejected() {
+    let value = d(1.0);
+    assert!(value.js_plus(source("1.2.3").unchecked_ref()).is_err());
+    assert!(value.js_plus(source(true).unchecked_ref()).is_err());
+    assert!(value.js_plus(JsValue::NULL.unchecked_ref()).is_err());
+    assert!(value.js_plus(JsValue::UNDEFINED.unchecked_ref()).is_err());
+
+    // Objects need both a numeric mantissa and exponent.
+    let object = js_sys::Object::new();
+    Reflect::set(&object, &"mantissa".into(), &2.0.into()).unwrap();
+    assert!(value
+        .js_plus(source(object.clone()).unchecked_ref())
+        .is_err());
+    Reflect::set(&object, &"exponent".into(), &"1".into()).unwrap();
+    assert!(value.js_plus(source(object).unchecked_ref()).is_err());
+}
+
+#[wasm_bindgen_test]
+fn statics_forward_to_the_instance_methods() {
+    install_statics_on(d(0.0).into());
+
+    let sum = call_static("add", &[1.0.into(), "2".into()]);
+    assert_eq!(Decimal::js_from_value(sum.unchecked_ref()).unwrap(), d(3.0));
+
+    let max = call_static("max", &[d(4.0).into(), 9.0.into()]);
+    assert_eq!(Decimal::js_from_value(max.unchecked_ref()).unwrap(), d(9.0));
+
+    let equal = call_static("eq_tolerance", &[100.0.into(), 100.5.into(), 0.01.into()]);
+    assert_eq!(equal.as_bool(), Some(true));
+}
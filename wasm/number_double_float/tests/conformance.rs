@@ -0,0 +1,3 @@
+use number_double_float::Decimal;
+
+number_base::conformance_tests!(Decimal);
@@ -0,0 +1,67 @@
+use number_base::BaseNumber;
+use number_double_float::Decimal;
+
+fn d(value: f64) -> Decimal {
+    Decimal::new(value)
+}
+
+#[test]
+fn to_exponential_carries_rounding_into_the_exponent() {
+    assert_eq!(d(9.99).to_exponential(1), "1.0e+1");
+    assert_eq!(d(-9.96e20).to_exponential(0), "-1e+21");
+    assert_eq!(d(9.999e-5).to_exponential(2), "1.00e-4");
+    assert_eq!(d(9.94).to_exponential(1), "9.9e+0");
+}
+
+#[test]
+fn to_precision_carries_rounding_into_the_exponent() {
+    assert_eq!(d(9.99).to_precision(2), "10");
+    assert_eq!(d(99.96).to_precision(3), "100");
+    assert_eq!(d(999.7).to_precision(3), "1.00e+3");
+    assert_eq!(d(9.9999999e-8).to_precision(2), "1.0e-7");
+}
+
+#[test]
+fn to_fixed_writes_every_digit_of_large_numbers() {
+    assert_eq!(d(1.5e17).to_fixed(0), "150000000000000000");
+    assert_eq!(d(-1.5e17).to_fixed(0), "-150000000000000000");
+    assert_eq!(d(-2e20).to_fixed(2), "-200000000000000000000.00");
+}
+
+#[test]
+fn to_fixed_rounds_exact_halves_away_from_zero() {
+    assert_eq!(d(2.5).to_fixed(0), "3");
+    assert_eq!(d(-2.5).to_fixed(0), "-3");
+    assert_eq!(d(0.125).to_fixed(2), "0.13");
+    assert_eq!(d(9.5).to_fixed(0), "10");
+    assert_eq!(d(-99.75).to_fixed(1), "-99.8");
+}
+
+#[test]
+fn to_fixed_rounds_other_numbers_to_the_nearest() {
+    // 1.005 is really 1.00499999999999989..., which is below the half.
+    assert_eq!(d(1.005).to_fixed(2), "1.00");
+    assert_eq!(d(2.4999).to_fixed(0), "2");
+    assert_eq!(d(0.3).to_fixed(3), "0.300");
+}
+
+#[test]
+fn to_number_snaps_to_nearby_integers() {
+    let cases = [
+        (1.16, 2.0, 116.0),
+        (1.234, 3.0, 1234.0),
+        (9.87654321, 8.0, 987654321.0),
+    ];
+    for (mantissa, exponent, number) in cases {
+        let decimal = Decimal::js_from_mantissa_exponent(mantissa, exponent);
+        assert_eq!(decimal.to_number(), number);
+        assert_eq!(decimal.floor().to_number(), number);
+    }
+}
+
+#[test]
+fn to_number_keeps_numbers_which_are_not_integers() {
+    assert_eq!(d(116.5).to_number(), 116.5);
+    assert_eq!(d(0.1).to_number(), 0.1);
+    assert_eq!(d(1e15 + 0.5).to_number(), 1e15 + 0.5);
+}
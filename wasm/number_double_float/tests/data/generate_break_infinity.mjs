@@ -0,0 +1,160 @@
+// Generates break_infinity.json, the reference corpus for tests/break_infinity.rs.
+//
+// Run from this directory:
+//
+//     npm install
+//     node generate_break_infinity.mjs > break_infinity.json
+//
+// The version of break_infinity.js is pinned in package.json, and the generator refuses to run
+// against any other. The inputs are drawn from a fixed seed, so regenerating only changes the
+// expected outputs if the pinned version changes.
+
+import { readFileSync } from "node:fs";
+import { createRequire } from "node:module";
+
+const VERSION = "2.2.0";
+
+const installed = JSON.parse(
+	readFileSync(new URL("node_modules/break_infinity.js/package.json", import.meta.url)),
+).version;
+if (installed !== VERSION) {
+	throw new Error(`expected break_infinity.js ${VERSION}, but ${installed} is installed`);
+}
+
+const require = createRequire(import.meta.url);
+const imported = require("break_infinity.js");
+const Decimal = imported.default ?? imported;
+
+/** xorshift32, so that the inputs are the same on every run. */
+let state = 0x9e3779b9;
+function next() {
+	state ^= state << 13;
+	state ^= state >>> 17;
+	state ^= state << 5;
+	state >>>= 0;
+	return state / 0x100000000;
+}
+
+function randomMantissa() {
+	const mantissa = 1 + next() * 9;
+	return next() < 0.5 ? -mantissa : mantissa;
+}
+
+function randomExponent(limit) {
+	return Math.floor(next() * (2 * limit + 1)) - limit;
+}
+
+/** Numbers which stress rounding: small integers, halves and values close to 10^n. */
+const SPECIAL = [
+	[1, 0],
+	[-1, 0],
+	[2.99, 2],
+	[1.8, 1],
+	[2.5, 0],
+	[-2.5, 0],
+	[5, -1],
+	[-5, -1],
+	[9.999999999999998, 0],
+	[9.5, 3],
+	[1.2345678901234567, 16],
+	[-9.87654321, 4],
+	[1, 21],
+	[1, -7],
+	[3.3333333333333335, -1],
+	[7, 300],
+	[-4.2, -300],
+	[1.5, 17],
+	[-1.5, 17],
+];
+
+const inputs = [...SPECIAL];
+for (let i = 0; i < 60; i++) {
+	inputs.push([randomMantissa(), randomExponent(20)]);
+}
+for (let i = 0; i < 30; i++) {
+	inputs.push([randomMantissa(), randomExponent(400)]);
+}
+
+const decimal = ([m, e]) => Decimal.fromMantissaExponent(m, e);
+const parts = (value) => [value.mantissa, value.exponent];
+const finite = (value) =>
+	typeof value === "string" ||
+	(Array.isArray(value) ? value.every(Number.isFinite) : Number.isFinite(value));
+
+const cases = [];
+function push(op, args, expected) {
+	if (finite(expected)) {
+		cases.push({ op, args, expected });
+	}
+}
+
+const UNARY = {
+	neg: (x) => parts(x.neg()),
+	abs: (x) => parts(x.abs()),
+	recip: (x) => parts(x.recip()),
+	sqrt: (x) => parts(x.sqrt()),
+	cbrt: (x) => parts(x.cbrt()),
+	round: (x) => parts(x.round()),
+	floor: (x) => parts(x.floor()),
+	ceil: (x) => parts(x.ceil()),
+	trunc: (x) => parts(x.trunc()),
+	log10: (x) => x.log10(),
+	ln: (x) => x.ln(),
+	log2: (x) => x.log2(),
+	toNumber: (x) => x.toNumber(),
+	toString: (x) => x.toString(),
+};
+
+const BINARY = {
+	add: (x, y) => parts(x.add(y)),
+	sub: (x, y) => parts(x.sub(y)),
+	mul: (x, y) => parts(x.mul(y)),
+	div: (x, y) => parts(x.div(y)),
+	cmp: (x, y) => x.cmp(y),
+};
+
+const FORMATS = {
+	toFixed: (x, places) => x.toFixed(places),
+	toExponential: (x, places) => x.toExponential(places),
+	toPrecision: (x, places) => x.toPrecision(places),
+};
+
+const POWERS = [0, 1, 2, 3, -1, -2, 0.5, 1.5, -0.25, 10, 7.3];
+
+for (const input of inputs) {
+	for (const [op, f] of Object.entries(UNARY)) {
+		if (input[0] < 0 && ["sqrt", "log10", "ln", "log2"].includes(op)) {
+			continue;
+		}
+		push(op, [input], f(decimal(input)));
+	}
+
+	for (const [op, f] of Object.entries(FORMATS)) {
+		for (const places of op === "toPrecision" ? [1, 2, 4, 8] : [0, 1, 2, 4, 8]) {
+			push(op, [input, places], f(decimal(input), places));
+		}
+	}
+
+	for (const power of POWERS) {
+		if (input[0] < 0 && !Number.isInteger(power)) {
+			continue;
+		}
+		push("pow", [input, power], parts(decimal(input).pow(power)));
+	}
+}
+
+for (let i = 0; i < inputs.length; i++) {
+	for (let j = 0; j < inputs.length; j += 7) {
+		const left = inputs[i];
+		const right = inputs[(i + j) % inputs.length];
+		for (const [op, f] of Object.entries(BINARY)) {
+			push(op, [left, right], f(decimal(left), decimal(right)));
+		}
+	}
+}
+
+// One case per line keeps diffs between regenerations readable.
+const lines = cases.map((entry) => `\t\t${JSON.stringify(entry)}`);
+process.stdout.write(
+	`{\n\t"version": "${VERSION}",\n\t"cases": [\n${lines.join(",\n")}\n\t]\n}\n`,
+);
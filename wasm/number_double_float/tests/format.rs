@@ -0,0 +1,60 @@
+use number_double_float::{Decimal, OrderedDecimal};
+
+fn x(string: &str) -> Decimal {
+    string.parse().unwrap()
+}
+
+#[test]
+fn display_honors_flags() {
+    assert_eq!(format!("{}", x("42.5")), "42.5");
+    assert_eq!(format!("{}", x("1e21")), "1.0000000000000000e+21");
+    assert_eq!(format!("{:.3}", x("1.23456")), "1.235");
+    assert_eq!(format!("{:.2}", x("1e30")), "1.00e+30");
+    assert_eq!(format!("{:.2}", Decimal::ZERO), "0.00");
+    assert_eq!(format!("{:>10}", x("42")), "        42");
+    assert_eq!(format!("{:<6}|", x("42")), "42    |");
+    assert_eq!(format!("{:*^9}", x("-1.5")), "**-1.5***");
+    assert_eq!(format!("{:+}", x("3")), "+3");
+    assert_eq!(format!("{:08.2}", x("-3.14159")), "-0003.14");
+    assert_eq!(format!("{:#}", x("1234567.5")), "1,234,567.5");
+    assert_eq!(format!("{:#.2}", x("-1234567.5")), "-1,234,567.50");
+    assert_eq!(format!("{:>8}", Decimal::NAN), "     NaN");
+    assert_eq!(format!("{:+}", Decimal::NAN), "NaN");
+    assert_eq!(format!("{:+}", Decimal::INFINITY), "+Infinity");
+    assert_eq!(format!("{:>10}", Decimal::NEG_INFINITY), " -Infinity");
+}
+
+#[test]
+fn exponential_forms() {
+    assert_eq!(format!("{:e}", x("1.5e300")), "1.5e300");
+    assert_eq!(format!("{:e}", x("1e1000")), "1e1000");
+    assert_eq!(format!("{:e}", x("-2.5e-10")), "-2.5e-10");
+    assert_eq!(format!("{:e}", Decimal::ZERO), "0e0");
+    assert_eq!(format!("{:E}", x("1.5e300")), "1.5E300");
+    assert_eq!(format!("{:>12.3e}", x("1234.5678")), "     1.235e3");
+    assert_eq!(format!("{:.2e}", x("9.999e99")), "1.00e100");
+    assert_eq!(format!("{:.1E}", x("-0.000123")), "-1.2E-4");
+    assert_eq!(format!("{:+e}", x("1.5")), "+1.5e0");
+    assert_eq!(format!("{:#e}", x("1.5e300")), "1.5e+300");
+    assert_eq!(format!("{:#.2e}", x("1e-5")), "1.00e-5");
+    assert_eq!(format!("{:e}", Decimal::NAN), "NaN");
+    assert_eq!(format!("{:e}", Decimal::NEG_INFINITY), "-Infinity");
+    assert_eq!(format!("{:<10e}|", x("2e5")), "2e5       |");
+}
+
+#[test]
+fn ordered_decimals_format_alike() {
+    let value = x("-1.25e40");
+    assert_eq!(
+        format!("{:>12.1e}", OrderedDecimal(value)),
+        format!("{:>12.1e}", value)
+    );
+    assert_eq!(
+        format!("{:E}", OrderedDecimal(value)),
+        format!("{:E}", value)
+    );
+    assert_eq!(
+        format!("{:+.3}", OrderedDecimal(value)),
+        format!("{:+.3}", value)
+    );
+}
@@ -0,0 +1,60 @@
+use number_base::{BaseNumber, RefNumber};
+use number_double_float::Decimal;
+
+fn total<N: BaseNumber>(values: &[N]) -> N {
+    values.iter().fold(N::ZERO, |sum, value| sum + value)
+}
+
+fn mean<N: BaseNumber>(values: &[N]) -> N {
+    total(values) / N::from(values.len())
+}
+
+fn distance<N: BaseNumber>(a: &N, b: &N) -> N
+where
+    for<'a> &'a N: RefNumber<N>,
+{
+    (a - b).abs()
+}
+
+fn compound<N: BaseNumber>(principal: N, rate: &N, periods: u32) -> N {
+    let mut value = principal;
+    for _ in 0..periods {
+        value *= N::ONE + rate;
+    }
+    value
+}
+
+fn is_affordable<N: BaseNumber>(cost: &N, money: &N) -> bool {
+    cost <= money && *cost < N::INFINITY
+}
+
+fn d(value: f64) -> Decimal {
+    Decimal::new(value)
+}
+
+#[test]
+fn generic_code_compiles_against_decimal() {
+    let values = [d(1.0), d(2.0), d(3.0), d(4.0)];
+
+    assert_eq!(total(&values), d(10.0));
+    assert_eq!(mean(&values), d(2.5));
+    assert_eq!(distance(&d(3.0), &d(7.5)), d(4.5));
+    assert!((compound(d(100.0), &d(0.1), 2) - d(121.0)).abs() < d(1e-9));
+}
+
+#[test]
+fn associated_constants_match_the_inherent_ones() {
+    assert_eq!(<Decimal as BaseNumber>::ZERO, Decimal::ZERO);
+    assert_eq!(<Decimal as BaseNumber>::ONE, Decimal::ONE);
+    assert!(<Decimal as BaseNumber>::NAN.is_nan());
+    assert_eq!(<Decimal as BaseNumber>::INFINITY, Decimal::INFINITY);
+    assert_eq!(<Decimal as BaseNumber>::NEG_INFINITY, Decimal::NEG_INFINITY);
+}
+
+#[test]
+fn special_values_are_not_affordable() {
+    assert!(is_affordable(&d(5.0), &d(10.0)));
+    assert!(!is_affordable(&d(50.0), &d(10.0)));
+    assert!(!is_affordable(&Decimal::NAN, &Decimal::INFINITY));
+    assert!(!is_affordable(&Decimal::INFINITY, &Decimal::INFINITY));
+}
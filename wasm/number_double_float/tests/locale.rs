@@ -0,0 +1,129 @@
+use number_base::BaseNumber;
+use number_double_float::{
+    locale::{self, by_tag, Grouping, Locale, DEVANAGARI_DIGITS},
+    notation::{InfinityCap, MixedScientific, Notation, NotationOptions, Scientific, Standard},
+    Decimal,
+};
+
+fn x(string: &str) -> Decimal {
+    string.parse().unwrap()
+}
+
+fn standard(value: &str, locale: &'static Locale) -> String {
+    let options = NotationOptions {
+        locale,
+        ..NotationOptions::default()
+    };
+    Standard.format(&x(value), &options)
+}
+
+#[test]
+fn decimal_marks_and_grouping() {
+    assert_eq!(locale::EN_US.localize("-1234567.89"), "-1,234,567.89");
+    assert_eq!(locale::DE_DE.localize("-1234567.89"), "-1.234.567,89");
+    assert_eq!(
+        locale::FR_FR.localize("1234567.89"),
+        "1\u{202f}234\u{202f}567,89"
+    );
+    assert_eq!(locale::RU_RU.localize("1234.5"), "1\u{a0}234,5");
+    assert_eq!(locale::HI_IN.localize("123456789.5"), "12,34,56,789.5");
+    assert_eq!(locale::HI_IN.localize("12345"), "12,345");
+    assert_eq!(locale::HI_IN.localize("123"), "123");
+    assert_eq!(locale::AR_EG.localize("-1234.5"), "-١٬٢٣٤٫٥");
+    assert_eq!(locale::DE_DE.localize("1.5e+21"), "1,5e+21");
+    assert_eq!(locale::DE_DE.localize("NaN"), "NaN");
+}
+
+#[test]
+fn custom_locales() {
+    let marathi = Locale {
+        tag: "mr-IN",
+        digits: DEVANAGARI_DIGITS,
+        ..locale::HI_IN
+    };
+    assert_eq!(marathi.localize("1234567.25"), "१२,३४,५६७.२५");
+
+    let ungrouped = Locale {
+        grouping: Grouping::NONE,
+        ..locale::EN_US
+    };
+    assert_eq!(ungrouped.localize("1234567"), "1234567");
+}
+
+#[test]
+fn decimal_to_locale_string() {
+    assert_eq!(
+        Decimal::new(1234.5).to_locale_string(&locale::DE_DE),
+        "1.234,5"
+    );
+    assert_eq!(
+        x("1.5e20").to_locale_string(&locale::FR_FR),
+        "150\u{202f}000\u{202f}000\u{202f}000\u{202f}000\u{202f}000\u{202f}000"
+    );
+    assert_eq!(
+        Decimal::new(1234567.891).to_fixed(2),
+        "1234567.89",
+        "formatting without a locale is unchanged"
+    );
+}
+
+#[test]
+fn translated_suffixes() {
+    assert_eq!(standard("1500", &locale::EN_US), "1.50K");
+    assert_eq!(standard("1.5e6", &locale::DE_DE), "1,50\u{a0}Mio.");
+    assert_eq!(standard("2.5e9", &locale::FR_FR), "2,50\u{a0}Md");
+    assert_eq!(standard("12345678", &locale::JA_JP), "1,234.57万");
+    assert_eq!(standard("1e12", &locale::ZH_CN), "1.00万亿");
+    assert_eq!(standard("1500", &locale::KO_KR), "1.50천");
+    assert_eq!(standard("1234", &locale::IT_IT), "1.234,00");
+    assert_eq!(standard("1.5e7", &locale::HI_IN), "1.50\u{a0}करोड़");
+    assert_eq!(standard("99999.999", &locale::HI_IN), "1.00\u{a0}लाख");
+    assert_eq!(standard("-1e6", &locale::AR_EG), "-١٫٠٠\u{a0}مليون");
+
+    // Beyond the last suffix, numbers are shown in scientific notation.
+    assert_eq!(standard("1e20", &locale::HI_IN), "1.00e20");
+    assert_eq!(standard("1e36", &locale::DE_DE), "1,00e36");
+
+    let options = NotationOptions {
+        locale: &locale::DE_DE,
+        ..NotationOptions::default()
+    };
+    assert_eq!(
+        MixedScientific.format(&x("1e30"), &options),
+        "1,00\u{a0}Quint."
+    );
+    assert_eq!(MixedScientific.format(&x("1e33"), &options), "1,00e33");
+}
+
+#[test]
+fn notations_group_as_the_locale() {
+    let mut options = NotationOptions {
+        locale: &locale::DE_DE,
+        ..NotationOptions::default()
+    };
+    assert_eq!(
+        Scientific.format(&x("1.5e1234567"), &options),
+        "1,50e1.234.567"
+    );
+    options.commas = false;
+    assert_eq!(
+        Scientific.format(&x("1.5e1234567"), &options),
+        "1,50e1234567"
+    );
+
+    let capped = InfinityCap::new(Scientific).at(x("1e6"));
+    assert_eq!(capped.format(&x("123456"), &options), "1,23e5");
+    assert_eq!(capped.format(&x("-1e6"), &options), "-Infinity");
+}
+
+#[test]
+fn locales_by_tag() {
+    for locale in locale::LOCALES {
+        assert_eq!(by_tag(locale.tag), Some(locale));
+    }
+    assert_eq!(by_tag("pt_br"), Some(&locale::PT_BR));
+    assert_eq!(by_tag("de-AT"), Some(&locale::DE_DE));
+    assert_eq!(by_tag("ja"), Some(&locale::JA_JP));
+    assert_eq!(by_tag("xx-XX"), None);
+    assert_eq!(Locale::default(), locale::EN_US);
+}
@@ -0,0 +1,211 @@
+use number_double_float::{
+    notation::{
+        by_name, Alphabetic, Blind, Engineering, InfinityCap, Logarithmic, LongScale,
+        MixedScientific, Notation, NotationOptions, Scientific, Standard, NAMES,
+    },
+    suffix::{alphabetic_suffix, long_scale_suffix},
+    Decimal,
+};
+
+fn x(string: &str) -> Decimal {
+    string.parse().unwrap()
+}
+
+fn check(notation: &dyn Notation, cases: &[(&str, &str)]) {
+    let options = NotationOptions::default();
+    for (value, expected) in cases {
+        assert_eq!(
+            notation.format(&x(value), &options),
+            *expected,
+            "{} notation of {}",
+            notation.name(),
+            value
+        );
+    }
+}
+
+#[test]
+fn scientific() {
+    check(
+        &Scientific,
+        &[
+            ("0", "0.00"),
+            ("12.345", "12.35"),
+            ("-999.994", "-999.99"),
+            ("999.995", "1.00e3"),
+            ("123456", "1.23e5"),
+            ("-9.999e99", "-1.00e100"),
+            ("0.001", "1.00e-3"),
+            ("0.004", "4.00e-3"),
+            ("0.005", "0.01"),
+            ("1.5e1234567", "1.50e1,234,567"),
+            ("1e1234567890", "e1.23e9"),
+            ("1e-1234567890", "e-1.23e9"),
+            ("NaN", "NaN"),
+            ("Infinity", "Infinity"),
+            ("-Infinity", "-Infinity"),
+        ],
+    );
+}
+
+#[test]
+fn engineering() {
+    check(
+        &Engineering,
+        &[
+            ("123456", "123.46e3"),
+            ("1e10", "10.00e9"),
+            ("999995", "1.00e6"),
+            ("1.5e-5", "15.00e-6"),
+            ("-4.2e3000", "-4.20e3,000"),
+        ],
+    );
+}
+
+#[test]
+fn standard() {
+    check(
+        &Standard,
+        &[
+            ("1500", "1.50K"),
+            ("1.2345e6", "1.23M"),
+            ("999999", "1.00M"),
+            ("999.995", "1.00K"),
+            ("-999.995", "-1.00K"),
+            ("-1e33", "-1.00Dc"),
+            ("1.5e302", "150.00NoNog"),
+            ("1e303", "1.00e303"),
+            ("0.0001", "1.00e-4"),
+        ],
+    );
+
+    // Standard notation reads back as the number it shows.
+    let options = NotationOptions::default();
+    assert_eq!(x(&Standard.format(&x("1.5e42"), &options)), x("1.5e42"));
+}
+
+#[test]
+fn long_scale() {
+    check(
+        &LongScale,
+        &[
+            ("1e3", "1.00K"),
+            ("999.995", "1.00K"),
+            ("1e6", "1.00M"),
+            ("1e9", "1.00Md"),
+            ("1e12", "1.00B"),
+            ("2.5e15", "2.50Bd"),
+            ("1e600", "1.00e600"),
+        ],
+    );
+    assert_eq!(long_scale_suffix(199).as_deref(), Some("NoNogd"));
+    assert_eq!(long_scale_suffix(200), None);
+}
+
+#[test]
+fn alphabetic() {
+    check(
+        &Alphabetic,
+        &[
+            ("999.995", "1.00K"),
+            ("1e12", "1.00T"),
+            ("1e15", "1.00aa"),
+            ("1e18", "1.00ab"),
+            ("1e21", "1.00ac"),
+            ("1e90", "1.00az"),
+            ("1e93", "1.00ba"),
+        ],
+    );
+    assert_eq!(alphabetic_suffix(4 + 676).as_deref(), Some("zz"));
+    assert_eq!(alphabetic_suffix(5 + 676).as_deref(), Some("aaa"));
+}
+
+#[test]
+fn mixed_scientific() {
+    check(
+        &MixedScientific,
+        &[
+            ("999.995", "1.00K"),
+            ("1e30", "1.00No"),
+            ("9.99999e32", "1.00e33"),
+            ("1e33", "1.00e33"),
+        ],
+    );
+}
+
+#[test]
+fn logarithmic() {
+    check(
+        &Logarithmic,
+        &[
+            ("12.5", "12.50"),
+            ("12345", "e4.09"),
+            ("0.001", "e-3.00"),
+            ("1e1234567", "e1,234,567.00"),
+        ],
+    );
+
+    let huge = Decimal::from_mantissa_exponent(1.0, 1.5e45);
+    assert_eq!(
+        Logarithmic.format(&huge, &NotationOptions::default()),
+        "e1.50e45"
+    );
+}
+
+#[test]
+fn infinity_cap() {
+    check(
+        &InfinityCap::new(Scientific),
+        &[
+            ("1e308", "1.00e308"),
+            ("1.8e308", "Infinity"),
+            ("-1e400", "-Infinity"),
+        ],
+    );
+    check(
+        &InfinityCap::new(Standard).at(x("1e6")),
+        &[("999999", "1.00M"), ("1e6", "Infinity"), ("12", "12.00")],
+    );
+
+    assert_eq!(InfinityCap::new(Scientific).name(), "infinity");
+    assert_eq!(InfinityCap::new(Standard).name(), "standard");
+    assert_eq!(
+        InfinityCap::new(InfinityCap::new(Engineering)).name(),
+        "engineering"
+    );
+}
+
+#[test]
+fn blind() {
+    check(
+        &Blind,
+        &[("0", ""), ("12.5", ""), ("1e100", ""), ("NaN", "")],
+    );
+}
+
+#[test]
+fn options() {
+    let options = NotationOptions {
+        places: 0,
+        places_under_1000: 0,
+        commas: false,
+        ..NotationOptions::default()
+    };
+    assert_eq!(Standard.format(&x("1500"), &options), "2K");
+    assert_eq!(Standard.format(&x("12.5"), &options), "13");
+    assert_eq!(Scientific.format(&x("1e1234567"), &options), "1e1234567");
+
+    let options = NotationOptions {
+        places: 4,
+        ..NotationOptions::default()
+    };
+    assert_eq!(Scientific.format(&x("123456"), &options), "1.2346e5");
+}
+
+#[test]
+fn notations_by_name() {
+    for name in NAMES {
+        assert_eq!(by_name(name).unwrap().name(), name);
+    }
+    assert!(by_name("hexadecimal").is_none());
+}
@@ -0,0 +1,150 @@
+use num_traits::{Bounded, FromPrimitive, Num, One, Pow, Signed, ToPrimitive, Zero};
+use number_base::{BaseNumber, ParseDecimalError};
+use number_double_float::Decimal;
+
+fn d(value: f64) -> Decimal {
+    Decimal::new(value)
+}
+
+fn p(value: &str) -> Decimal {
+    value.parse().unwrap()
+}
+
+/// The mean of some values, written the way a statistics crate would.
+fn mean<T: Num + FromPrimitive + Copy>(values: &[T]) -> T {
+    let total = values.iter().fold(T::zero(), |total, &value| total + value);
+    total / T::from_usize(values.len()).unwrap()
+}
+
+/// Linear interpolation, written the way an interpolation crate would.
+fn lerp<T: Num + Copy>(from: T, to: T, t: T) -> T {
+    from + (to - from) * t
+}
+
+#[test]
+fn generic_code_accepts_decimals_like_floats() {
+    assert_eq!(mean(&[1.0, 2.0, 3.0, 6.0]), 3.0);
+    assert_eq!(mean(&[d(1.0), d(2.0), d(3.0), d(6.0)]), d(3.0));
+    assert_eq!(mean(&[d(1e300), d(3e300)]), d(2e300));
+
+    assert_eq!(lerp(d(10.0), d(20.0), d(0.25)), d(12.5));
+    assert_eq!(lerp(p("1e500"), p("3e500"), d(0.5)), p("2e500"));
+}
+
+#[test]
+fn identities() {
+    assert_eq!(Decimal::zero(), Decimal::ZERO);
+    assert_eq!(Decimal::one(), Decimal::ONE);
+    assert!(Decimal::zero().is_zero());
+    assert!(!d(1e-300).is_zero());
+    assert!(Decimal::one().is_one());
+}
+
+#[test]
+fn zero_is_unsigned() {
+    // Unlike -0.0, which is negative for f64, a Decimal zero has no sign.
+    assert!(Signed::is_negative(&-0.0_f64));
+    assert!(!d(-0.0).is_negative());
+    assert!(!Decimal::ZERO.is_positive());
+    assert_eq!(Signed::signum(&0.0_f64), 1.0);
+    assert_eq!(Decimal::ZERO.signum(), Decimal::ZERO);
+
+    assert_eq!(p("-2e400").signum(), d(-1.0));
+    assert_eq!(Decimal::INFINITY.signum(), d(1.0));
+    assert!(Decimal::NAN.signum().is_nan());
+    assert!(p("1e-400").is_positive());
+}
+
+#[test]
+fn absolute_values() {
+    assert_eq!(Signed::abs(&p("-1e400")), p("1e400"));
+    assert_eq!(d(5.0).abs_sub(&d(3.0)), d(2.0));
+    assert_eq!(d(3.0).abs_sub(&d(5.0)), Decimal::ZERO);
+}
+
+#[test]
+fn bounds_are_far_beyond_f64() {
+    let max = Decimal::max_value();
+    let min = Decimal::min_value();
+
+    assert!(max.is_finite() && min.is_finite());
+    assert!(max > d(f64::MAX));
+    assert!(max > d(1e300).pow(&d(1e300)));
+    assert_eq!(min, -max);
+    assert!(max < Decimal::INFINITY);
+}
+
+#[test]
+fn from_str_radix_only_takes_fractions_in_base_10() {
+    assert_eq!(Decimal::from_str_radix("1.5e400", 10), Ok(p("1.5e400")));
+    assert_eq!(Decimal::from_str_radix("ff", 16), Ok(d(255.0)));
+    assert_eq!(Decimal::from_str_radix("-101", 2), Ok(d(-5.0)));
+
+    assert_eq!(
+        Decimal::from_str_radix("", 16),
+        Err(ParseDecimalError::Empty)
+    );
+    assert_eq!(
+        Decimal::from_str_radix("1.1", 2),
+        Err(ParseDecimalError::InvalidMantissa)
+    );
+}
+
+#[test]
+fn conversions_from_primitives_always_succeed() {
+    assert_eq!(Decimal::from_i64(-42), Some(d(-42.0)));
+    assert_eq!(Decimal::from_u128(u128::MAX), Some(d(u128::MAX as f64)));
+    assert_eq!(Decimal::from_f32(0.5), Some(d(0.5)));
+    assert!(Decimal::from_f64(f64::NAN).unwrap().is_nan());
+}
+
+#[test]
+fn conversions_to_integers_truncate_and_check_the_range() {
+    assert_eq!(d(2.9).to_i64(), Some(2));
+    assert_eq!(d(-2.9).to_i64(), Some(-2));
+    assert_eq!(d(-2.9).to_u64(), None);
+    assert_eq!(d(-0.5).to_u64(), Some(0));
+    assert_eq!(d(1e30).to_i64(), None);
+    assert_eq!(d(1e30).to_i128(), Some(1e30 as i128));
+    assert_eq!(p("1e400").to_u128(), None);
+    assert_eq!(Decimal::NAN.to_i32(), None);
+    assert_eq!(Decimal::INFINITY.to_u8(), None);
+}
+
+#[test]
+fn conversions_to_floats_saturate() {
+    // f64 itself would give None for a value out of range, but a Decimal rounds to infinity or
+    // zero like an f64 calculation that overflows or underflows.
+    assert_eq!(d(1.5).to_f64(), Some(1.5));
+    assert_eq!(p("1e400").to_f64(), Some(f64::INFINITY));
+    assert_eq!(p("-1e400").to_f64(), Some(f64::NEG_INFINITY));
+    assert_eq!(p("1e-400").to_f64(), Some(0.0));
+    assert_eq!(d(1e300).to_f32(), Some(f32::INFINITY));
+}
+
+#[test]
+fn remainder_takes_the_sign_of_the_dividend() {
+    assert_eq!(d(7.0) % d(3.0), d(1.0));
+    assert_eq!(d(-7.0) % d(3.0), d(-1.0));
+    assert_eq!(d(7.0) % d(-3.0), d(1.0));
+    assert_eq!(d(5.5) % d(2.0), d(1.5));
+    assert_eq!(d(2.0) % d(5.0), d(2.0));
+    assert_eq!(p("3e400") % p("1e400"), Decimal::ZERO);
+    assert_eq!(d(5.0) % Decimal::INFINITY, d(5.0));
+
+    assert!((d(5.0) % Decimal::ZERO).is_nan());
+    assert!((Decimal::INFINITY % d(5.0)).is_nan());
+
+    let mut value = d(10.0);
+    value %= d(4.0);
+    assert_eq!(value, d(2.0));
+}
+
+#[test]
+fn powers() {
+    assert_eq!(Pow::pow(d(2.0), 10_u8), d(1024.0));
+    assert_eq!(Pow::pow(&d(10.0), 400_i32), p("1e400"));
+    assert_eq!(Pow::pow(d(4.0), 0.5_f64), d(2.0));
+    assert_eq!(Pow::pow(d(10.0), d(-2.0)), d(0.01));
+    assert_eq!(Pow::pow(&d(3.0), &d(2.0)), d(9.0));
+}
@@ -0,0 +1,86 @@
+use std::collections::{BTreeMap, BinaryHeap, HashSet};
+
+use number_base::BaseNumber;
+use number_double_float::{Decimal, OrderedDecimal};
+
+fn d(value: f64) -> Decimal {
+    Decimal::new(value)
+}
+
+#[test]
+fn total_cmp_orders_nan_after_infinity() {
+    let mut decimals = [
+        Decimal::NAN,
+        d(3.0),
+        Decimal::INFINITY,
+        d(-1e300),
+        Decimal::ZERO,
+        Decimal::NEG_INFINITY,
+        Decimal::NAN,
+        d(2.5e-7),
+    ];
+    decimals.sort_by(Decimal::total_cmp);
+
+    let expected = [
+        Decimal::NEG_INFINITY,
+        d(-1e300),
+        Decimal::ZERO,
+        d(2.5e-7),
+        d(3.0),
+        Decimal::INFINITY,
+    ];
+    assert_eq!(decimals[..6], expected);
+    assert!(decimals[6].is_nan() && decimals[7].is_nan());
+}
+
+#[test]
+fn nan_equals_itself_and_zeros_are_unified() {
+    assert_eq!(OrderedDecimal(Decimal::NAN), OrderedDecimal(Decimal::NAN));
+    assert_eq!(
+        OrderedDecimal(Decimal::ZERO),
+        OrderedDecimal(-Decimal::ZERO)
+    );
+    assert_eq!(
+        OrderedDecimal(Decimal::ZERO),
+        OrderedDecimal(Decimal::js_from_mantissa_exponent_no_normalize(-0.0, 5.0))
+    );
+
+    let set: HashSet<_> = [
+        Decimal::NAN,
+        -Decimal::NAN,
+        Decimal::ZERO,
+        Decimal::js_from_mantissa_exponent_no_normalize(-0.0, 5.0),
+        d(1.0),
+        d(1.0),
+    ]
+    .into_iter()
+    .map(OrderedDecimal)
+    .collect();
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn works_as_a_map_key() {
+    let mut leaderboard = BTreeMap::new();
+    leaderboard.insert(OrderedDecimal(d(1e50)), "second");
+    leaderboard.insert(OrderedDecimal(d(1e100)), "first");
+    leaderboard.insert(OrderedDecimal(d(1e10)), "third");
+
+    let names: Vec<_> = leaderboard.values().rev().copied().collect();
+    assert_eq!(names, ["first", "second", "third"]);
+}
+
+#[test]
+fn works_in_a_priority_queue() {
+    let costs = [d(250.0), d(1e6), d(15.0), d(3e3)];
+    let mut queue: BinaryHeap<_> = costs
+        .iter()
+        .map(|&cost| std::cmp::Reverse(OrderedDecimal(cost)))
+        .collect();
+
+    let mut cheapest = Vec::new();
+    while let Some(std::cmp::Reverse(cost)) = queue.pop() {
+        cheapest.push(cost.into_inner().to_number());
+    }
+    assert_eq!(cheapest, [15.0, 250.0, 3e3, 1e6]);
+}
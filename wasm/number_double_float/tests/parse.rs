@@ -0,0 +1,168 @@
+use number_base::BaseNumber;
+use number_double_float::{Decimal, ParseDecimalError};
+
+#[test]
+fn parses_plain_numbers() {
+    assert_eq!("123.45".parse::<Decimal>(), Ok(Decimal::new(123.45)));
+    assert_eq!("-0.5".parse::<Decimal>(), Ok(Decimal::new(-0.5)));
+    assert_eq!("0".parse::<Decimal>(), Ok(Decimal::new(0.0)));
+}
+
+#[test]
+fn parses_scientific_notation() {
+    let decimal: Decimal = "1.5e400".parse().unwrap();
+    assert_eq!(decimal.mantissa(), 1.5);
+    assert_eq!(decimal.exponent(), 400.0);
+
+    let decimal: Decimal = "25e-3".parse().unwrap();
+    assert_eq!(decimal.mantissa(), 2.5);
+    assert_eq!(decimal.exponent(), -2.0);
+}
+
+#[test]
+fn parses_nan() {
+    let decimal: Decimal = "NaN".parse().unwrap();
+    assert!(decimal.mantissa().is_nan());
+}
+
+#[test]
+fn rejects_invalid_strings() {
+    assert_eq!("".parse::<Decimal>(), Err(ParseDecimalError::Empty));
+    assert_eq!(
+        "abc".parse::<Decimal>(),
+        Err(ParseDecimalError::InvalidMantissa)
+    );
+    assert_eq!(
+        "e5".parse::<Decimal>(),
+        Err(ParseDecimalError::InvalidMantissa)
+    );
+    assert_eq!(
+        "1.5eabc".parse::<Decimal>(),
+        Err(ParseDecimalError::InvalidExponent)
+    );
+    assert_eq!(
+        "1.5e".parse::<Decimal>(),
+        Err(ParseDecimalError::InvalidExponent)
+    );
+    assert_eq!(
+        "1.5x".parse::<Decimal>(),
+        Err(ParseDecimalError::TrailingCharacters)
+    );
+    assert_eq!(
+        "1e5e3".parse::<Decimal>(),
+        Err(ParseDecimalError::TrailingCharacters)
+    );
+    assert_eq!(
+        "1e5.5".parse::<Decimal>(),
+        Err(ParseDecimalError::TrailingCharacters)
+    );
+}
+
+#[test]
+fn try_from_str_matches_from_str() {
+    assert_eq!(Decimal::try_from_str("42"), "42".parse::<Decimal>());
+    assert_eq!(Decimal::try_from_str(""), Err(ParseDecimalError::Empty));
+}
+
+#[test]
+fn from_string_produces_nan_for_invalid_strings() {
+    assert!(Decimal::from(String::from("1.5eabc")).mantissa().is_nan());
+    assert_eq!(Decimal::from(String::from("1e3")), Decimal::new(1000.0));
+}
+
+#[test]
+fn parses_infinity() {
+    for (string, sign) in [("Infinity", 1), ("+Infinity", 1), ("-Infinity", -1)] {
+        let decimal: Decimal = string.parse().unwrap();
+        assert_eq!(decimal.to_number(), sign as f64 * f64::INFINITY);
+    }
+
+    assert_eq!(
+        Decimal::new(f64::INFINITY).to_string().parse::<Decimal>(),
+        Ok(Decimal::new(f64::INFINITY))
+    );
+    assert_eq!(
+        Decimal::new(f64::NEG_INFINITY)
+            .to_string()
+            .parse::<Decimal>(),
+        Ok(Decimal::new(f64::NEG_INFINITY))
+    );
+}
+
+#[test]
+fn parses_exponent_variants() {
+    let expected = Decimal::from(String::from("1.5e20"));
+    assert_eq!("1.5E20".parse::<Decimal>(), Ok(expected));
+    assert_eq!("1.5e+20".parse::<Decimal>(), Ok(expected));
+    assert_eq!("+1.5e20".parse::<Decimal>(), Ok(expected));
+    assert_eq!("  1.5e20\n".parse::<Decimal>(), Ok(expected));
+}
+
+#[test]
+fn parses_digit_groups() {
+    assert_eq!("1_000_000".parse::<Decimal>(), Ok(Decimal::new(1e6)));
+    assert_eq!("1,000,000".parse::<Decimal>(), Ok(Decimal::new(1e6)));
+    assert_eq!("-1,234.5".parse::<Decimal>(), Ok(Decimal::new(-1234.5)));
+    assert_eq!(
+        "1__000".parse::<Decimal>(),
+        Err(ParseDecimalError::TrailingCharacters)
+    );
+    assert_eq!(
+        "1,000,".parse::<Decimal>(),
+        Err(ParseDecimalError::TrailingCharacters)
+    );
+    assert_eq!(
+        "1,5".parse::<Decimal>(),
+        Err(ParseDecimalError::TrailingCharacters)
+    );
+    assert_eq!(
+        "1_50".parse::<Decimal>(),
+        Err(ParseDecimalError::TrailingCharacters)
+    );
+    assert_eq!(
+        "1,5000".parse::<Decimal>(),
+        Err(ParseDecimalError::TrailingCharacters)
+    );
+    assert_eq!(
+        "1,000,5K".parse::<Decimal>(),
+        Err(ParseDecimalError::TrailingCharacters)
+    );
+    assert_eq!(
+        ",100".parse::<Decimal>(),
+        Err(ParseDecimalError::InvalidMantissa)
+    );
+}
+
+#[test]
+fn parses_digits_beyond_the_range_of_an_f64() {
+    let parse = |string: String| string.parse::<Decimal>().unwrap();
+    let zeros = "0".repeat(400);
+
+    assert_eq!(parse(format!("1{}", zeros)), parse("1e400".into()));
+    assert_eq!(parse(format!("-25{}.75", zeros)), parse("-2.5e401".into()));
+    assert_eq!(parse(format!("1{}K", zeros)), parse("1e403".into()));
+    assert_eq!(
+        parse(format!("1{}", ",000".repeat(134))),
+        parse("1e402".into())
+    );
+    assert_eq!(parse(format!("0.{}15", zeros)), parse("1.5e-401".into()));
+    assert_eq!(parse(format!("{}.{}", zeros, zeros)), Decimal::ZERO);
+}
+
+#[test]
+fn parses_short_scale_suffixes() {
+    assert_eq!("1.5K".parse::<Decimal>(), Ok(Decimal::new(1500.0)));
+    assert_eq!("2 m".parse::<Decimal>(), Ok(Decimal::new(2e6)));
+    assert_eq!("7Qa".parse::<Decimal>(), Ok(Decimal::new(7e15)));
+
+    let decimal: Decimal = "3.2e6M".parse().unwrap();
+    assert_eq!(decimal.exponent(), 12.0);
+
+    let decimal: Decimal = "1UDc".parse().unwrap();
+    assert_eq!(decimal.exponent(), 36.0);
+
+    assert_eq!(
+        "1.5Kx".parse::<Decimal>(),
+        Err(ParseDecimalError::TrailingCharacters)
+    );
+}
@@ -0,0 +1,40 @@
+use number_base::BaseNumber;
+use number_double_float::Decimal;
+
+#[test]
+fn normalized_mantissas_are_kept_as_they_are() {
+    for mantissa in [1.0, 1.0000000000000002, 2.5, 9.999999999999998] {
+        for mantissa in [mantissa, -mantissa] {
+            let decimal = Decimal::js_from_mantissa_exponent(mantissa, 7.0);
+            assert_eq!(decimal.mantissa(), mantissa);
+            assert_eq!(decimal.exponent(), 7.0);
+        }
+    }
+}
+
+#[test]
+fn denormalized_mantissas_are_normalized_whatever_their_sign() {
+    let decimal = Decimal::js_from_mantissa_exponent(-250.0, 1.0);
+    assert_eq!(decimal.mantissa(), -2.5);
+    assert_eq!(decimal.exponent(), 3.0);
+
+    let decimal = Decimal::js_from_mantissa_exponent(-0.025, 1.0);
+    assert_eq!(decimal.mantissa(), -2.5);
+    assert_eq!(decimal.exponent(), -1.0);
+}
+
+#[test]
+fn subnormal_mantissas_are_normalized() {
+    let decimal = Decimal::js_from_mantissa_exponent(1.5e-315, 0.0);
+    assert!((decimal.mantissa() - 1.5).abs() < 1e-6);
+    assert_eq!(decimal.exponent(), -315.0);
+}
+
+#[test]
+fn subnormal_numbers_convert_back_to_f64() {
+    let decimal: Decimal = "1.5e-315".parse().unwrap();
+    assert_eq!(decimal.to_number(), 1.5e-315);
+
+    let decimal: Decimal = "5e-324".parse().unwrap();
+    assert_eq!(decimal.to_number(), 5e-324);
+}
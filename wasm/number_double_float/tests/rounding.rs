@@ -0,0 +1,77 @@
+use number_base::{BaseNumber, RoundingMode};
+use number_double_float::Decimal;
+
+fn x(string: &str) -> Decimal {
+    string.parse().unwrap()
+}
+
+#[test]
+fn ties_are_decided_on_decimal_digits() {
+    // 1.005 is a little below the tie as an f64, but its shortest digits are a tie.
+    assert_eq!(x("1.005").round_to(2, RoundingMode::HalfUp), x("1.01"));
+    assert_eq!(x("1.005").round_to(2, RoundingMode::HalfEven), x("1"));
+    assert_eq!(x("2.675").round_to(2, RoundingMode::HalfEven), x("2.68"));
+    assert_eq!(x("-0.125").round_to(2, RoundingMode::HalfEven), x("-0.12"));
+}
+
+#[test]
+fn rounding_beyond_an_f64() {
+    assert_eq!(
+        x("1.234567e20").round_to(-15, RoundingMode::HalfEven),
+        x("1.23457e20")
+    );
+    assert_eq!(
+        x("1.2345e20").round_to(-17, RoundingMode::TowardZero),
+        x("1.234e20")
+    );
+    assert_eq!(
+        x("9.995e25").round_to_significant(3, RoundingMode::HalfUp),
+        x("1e26")
+    );
+    assert_eq!(
+        x("1.5e1000").round_to_significant(1, RoundingMode::HalfEven),
+        x("2e1000")
+    );
+    assert_eq!(
+        x("-1.25e-1000").round_to_significant(2, RoundingMode::Floor),
+        x("-1.3e-1000")
+    );
+}
+
+#[test]
+fn dropping_every_digit() {
+    assert_eq!(x("1e-400").round_to(2, RoundingMode::Ceil), x("0.01"));
+    assert_eq!(x("1e-400").round_to(2, RoundingMode::HalfUp), Decimal::ZERO);
+    assert_eq!(x("-1.5e-10").round_to(5, RoundingMode::Floor), x("-1e-5"));
+    assert_eq!(x("-1.5e-10").round_to(5, RoundingMode::Ceil), Decimal::ZERO);
+    assert_eq!(x("0.6").round_to(0, RoundingMode::HalfEven), x("1"));
+    assert_eq!(x("1e300").round_to(-400, RoundingMode::Ceil), x("1e400"));
+}
+
+#[test]
+fn formatting_with_a_rounding_mode() {
+    assert_eq!(
+        x("123.456").to_fixed_with_rounding(1, RoundingMode::TowardZero),
+        "123.4"
+    );
+    assert_eq!(
+        x("2.5").to_fixed_with_rounding(0, RoundingMode::HalfEven),
+        "2"
+    );
+    assert_eq!(
+        x("2.5").to_fixed_with_rounding(0, RoundingMode::HalfUp),
+        "3"
+    );
+    assert_eq!(
+        x("1.2345e50").to_exponential_with_rounding(2, RoundingMode::Floor),
+        x("1.23e50").to_exponential(2)
+    );
+    assert_eq!(
+        x("-1.2345e50").to_exponential_with_rounding(2, RoundingMode::Floor),
+        x("-1.24e50").to_exponential(2)
+    );
+    assert_eq!(
+        x("0.0012345").to_precision_with_rounding(3, RoundingMode::Ceil),
+        x("0.00124").to_precision(3)
+    );
+}
@@ -0,0 +1,448 @@
+use number_base::BaseNumber;
+use number_double_float::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+/// A minimal binary format which, like bincode, writes numbers one after the other and relies on
+/// the type to say what comes next.
+mod binary {
+    use std::{
+        fmt::{self, Display, Formatter},
+        slice::Iter,
+    };
+
+    use serde::{
+        de::{self, DeserializeOwned, DeserializeSeed, SeqAccess, Visitor},
+        forward_to_deserialize_any,
+        ser::{self, Impossible, Serialize, SerializeStruct, SerializeTuple},
+    };
+
+    #[derive(Debug)]
+    pub struct Error(String);
+
+    impl Display for Error {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str(&self.0)
+        }
+    }
+
+    impl std::error::Error for Error {}
+
+    impl ser::Error for Error {
+        fn custom<T: Display>(message: T) -> Error {
+            Error(message.to_string())
+        }
+    }
+
+    impl de::Error for Error {
+        fn custom<T: Display>(message: T) -> Error {
+            Error(message.to_string())
+        }
+    }
+
+    pub fn to_vec<T: Serialize>(value: &T) -> Result<Vec<f64>, Error> {
+        let mut serializer = Serializer(Vec::new());
+        value.serialize(&mut serializer)?;
+        Ok(serializer.0)
+    }
+
+    pub fn from_slice<T: DeserializeOwned>(input: &[f64]) -> Result<T, Error> {
+        T::deserialize(&mut Deserializer(input.iter()))
+    }
+
+    struct Serializer(Vec<f64>);
+
+    /// Implements the serializer methods for the values the format has no room for.
+    macro_rules! unsupported {
+        ($($method:ident($($argument:ty),*)),*) => {
+            $(
+                fn $method(self, $(_: $argument),*) -> Result<(), Error> {
+                    Err(Error(format!("{} is not supported", stringify!($method))))
+                }
+            )*
+        };
+    }
+
+    impl ser::Serializer for &mut Serializer {
+        type Ok = ();
+        type Error = Error;
+        type SerializeSeq = Impossible<(), Error>;
+        type SerializeTuple = Self;
+        type SerializeTupleStruct = Impossible<(), Error>;
+        type SerializeTupleVariant = Impossible<(), Error>;
+        type SerializeMap = Impossible<(), Error>;
+        type SerializeStruct = Self;
+        type SerializeStructVariant = Impossible<(), Error>;
+
+        fn is_human_readable(&self) -> bool {
+            false
+        }
+
+        fn serialize_f64(self, value: f64) -> Result<(), Error> {
+            self.0.push(value);
+            Ok(())
+        }
+
+        fn serialize_tuple(self, _: usize) -> Result<Self, Error> {
+            Ok(self)
+        }
+
+        fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self, Error> {
+            Ok(self)
+        }
+
+        unsupported!(
+            serialize_bool(bool),
+            serialize_i8(i8),
+            serialize_i16(i16),
+            serialize_i32(i32),
+            serialize_i64(i64),
+            serialize_u8(u8),
+            serialize_u16(u16),
+            serialize_u32(u32),
+            serialize_u64(u64),
+            serialize_f32(f32),
+            serialize_char(char),
+            serialize_str(&str),
+            serialize_bytes(&[u8]),
+            serialize_none(),
+            serialize_unit(),
+            serialize_unit_struct(&'static str),
+            serialize_unit_variant(&'static str, u32, &'static str)
+        );
+
+        fn serialize_some<T: Serialize + ?Sized>(self, _: &T) -> Result<(), Error> {
+            Err(Error("options are not supported".into()))
+        }
+
+        fn serialize_newtype_struct<T: Serialize + ?Sized>(
+            self,
+            _: &'static str,
+            _: &T,
+        ) -> Result<(), Error> {
+            Err(Error("newtypes are not supported".into()))
+        }
+
+        fn serialize_newtype_variant<T: Serialize + ?Sized>(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: &T,
+        ) -> Result<(), Error> {
+            Err(Error("enums are not supported".into()))
+        }
+
+        fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+            Err(Error("sequences are not supported".into()))
+        }
+
+        fn serialize_tuple_struct(
+            self,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleStruct, Error> {
+            Err(Error("tuple structs are not supported".into()))
+        }
+
+        fn serialize_tuple_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleVariant, Error> {
+            Err(Error("enums are not supported".into()))
+        }
+
+        fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Error> {
+            Err(Error("maps are not supported".into()))
+        }
+
+        fn serialize_struct_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeStructVariant, Error> {
+            Err(Error("enums are not supported".into()))
+        }
+    }
+
+    impl SerializeTuple for &mut Serializer {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+            value.serialize(&mut **self)
+        }
+
+        fn end(self) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    impl SerializeStruct for &mut Serializer {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_field<T: Serialize + ?Sized>(
+            &mut self,
+            _: &'static str,
+            value: &T,
+        ) -> Result<(), Error> {
+            value.serialize(&mut **self)
+        }
+
+        fn end(self) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    struct Deserializer<'a>(Iter<'a, f64>);
+
+    impl<'de> de::Deserializer<'de> for &mut Deserializer<'_> {
+        type Error = Error;
+
+        fn is_human_readable(&self) -> bool {
+            false
+        }
+
+        fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Error> {
+            Err(Error("the format is not self-describing".into()))
+        }
+
+        fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            let value = self
+                .0
+                .next()
+                .ok_or(Error("unexpected end of input".into()))?;
+            visitor.visit_f64(*value)
+        }
+
+        fn deserialize_tuple<V: Visitor<'de>>(
+            self,
+            length: usize,
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            visitor.visit_seq(Elements(self, length))
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(
+            self,
+            _: &'static str,
+            fields: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            visitor.visit_seq(Elements(self, fields.len()))
+        }
+
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 char str string bytes byte_buf
+            option unit unit_struct newtype_struct seq tuple_struct map enum identifier
+            ignored_any
+        }
+    }
+
+    /// The remaining elements of a tuple or struct.
+    struct Elements<'a, 'b>(&'a mut Deserializer<'b>, usize);
+
+    impl<'de> SeqAccess<'de> for Elements<'_, '_> {
+        type Error = Error;
+
+        fn next_element_seed<T: DeserializeSeed<'de>>(
+            &mut self,
+            seed: T,
+        ) -> Result<Option<T::Value>, Error> {
+            if self.1 == 0 {
+                return Ok(None);
+            }
+
+            self.1 -= 1;
+            seed.deserialize(&mut *self.0).map(Some)
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Save {
+    coins: Decimal,
+    #[serde(with = "number_double_float::serde::string")]
+    gems: Decimal,
+    #[serde(with = "number_double_float::serde::object")]
+    keys: Decimal,
+    #[serde(with = "number_double_float::serde::tuple")]
+    prestige: Decimal,
+}
+
+#[test]
+fn serializes_each_representation() {
+    let save = Save {
+        coins: "1.5e300".parse().unwrap(),
+        gems: Decimal::new(-0.25),
+        keys: Decimal::new(1500.0),
+        prestige: Decimal::new(-2e-5),
+    };
+
+    let value = serde_json::to_value(&save).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "coins": "1.5e300",
+            "gems": "-2.5e-1",
+            "keys": { "mantissa": 1.5, "exponent": 3.0 },
+            "prestige": [-2.0, -5.0],
+        })
+    );
+
+    assert_eq!(serde_json::from_value::<Save>(value).unwrap(), save);
+}
+
+#[test]
+fn deserializes_any_representation_on_request() {
+    #[derive(Deserialize)]
+    struct Lenient {
+        #[serde(with = "number_double_float::serde::any")]
+        value: Decimal,
+    }
+
+    let expected = Decimal::new(1500.0);
+
+    for value in [
+        json!("1.5e3"),
+        json!("1,500"),
+        json!(1500),
+        json!(1500.0),
+        json!([1.5, 3]),
+        json!({ "mantissa": 15, "exponent": 2 }),
+    ] {
+        let lenient: Lenient = serde_json::from_value(json!({ "value": value })).unwrap();
+        assert_eq!(lenient.value, expected);
+    }
+
+    // Without it, human-readable formats only take the string.
+    assert_eq!(
+        serde_json::from_value::<Decimal>(json!("1.5e3")).unwrap(),
+        expected
+    );
+    assert!(serde_json::from_value::<Decimal>(json!(1500)).is_err());
+    assert!(serde_json::from_value::<Decimal>(json!([1.5, 3])).is_err());
+}
+
+#[test]
+fn uses_the_tuple_in_binary_formats() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Save {
+        coins: Decimal,
+        #[serde(with = "number_double_float::serde::object")]
+        keys: Decimal,
+        #[serde(with = "number_double_float::serde::tuple")]
+        prestige: Decimal,
+    }
+
+    let save = Save {
+        coins: "1.5e300".parse().unwrap(),
+        keys: Decimal::new(-1500.0),
+        prestige: "2e-1000".parse().unwrap(),
+    };
+
+    let values = binary::to_vec(&save).unwrap();
+    assert_eq!(values, [1.5, 300.0, -1.5, 3.0, 2.0, -1000.0]);
+    assert_eq!(binary::from_slice::<Save>(&values).unwrap(), save);
+
+    let nan: Decimal = binary::from_slice(&binary::to_vec(&Decimal::NAN).unwrap()).unwrap();
+    assert!(nan.is_nan());
+    assert!(binary::from_slice::<Decimal>(&[1.5]).is_err());
+}
+
+#[test]
+fn writes_special_values_with_finite_numbers() {
+    #[derive(Serialize, Deserialize)]
+    struct Forms {
+        #[serde(with = "number_double_float::serde::object")]
+        object: Decimal,
+        #[serde(with = "number_double_float::serde::tuple")]
+        tuple: Decimal,
+    }
+
+    let cases = [
+        (
+            Decimal::INFINITY,
+            json!({ "mantissa": 1.0, "exponent": 1.79e308 }),
+        ),
+        (
+            Decimal::NEG_INFINITY,
+            json!({ "mantissa": -1.0, "exponent": 1.79e308 }),
+        ),
+        (
+            Decimal::NAN,
+            json!({ "mantissa": 0.0, "exponent": 1.79e308 }),
+        ),
+    ];
+
+    for (value, object) in cases {
+        let json = serde_json::to_string(&Forms {
+            object: value,
+            tuple: value,
+        })
+        .unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["object"], object);
+        assert_eq!(
+            parsed["tuple"],
+            json!([object["mantissa"], object["exponent"]])
+        );
+
+        let forms: Forms = serde_json::from_str(&json).unwrap();
+        let values = binary::to_vec(&value).unwrap();
+        let binary: Decimal = binary::from_slice(&values).unwrap();
+        for read in [forms.object, forms.tuple, binary] {
+            if value.is_nan() {
+                assert!(read.is_nan());
+            } else {
+                assert_eq!(read, value);
+            }
+        }
+    }
+}
+
+#[test]
+fn keeps_special_values_as_strings() {
+    let nan: Decimal =
+        serde_json::from_str(&serde_json::to_string(&Decimal::new(f64::NAN)).unwrap()).unwrap();
+    assert!(nan.mantissa().is_nan());
+
+    let infinity = Decimal::new(f64::NEG_INFINITY);
+    assert_eq!(serde_json::to_string(&infinity).unwrap(), "\"-Infinity\"");
+    for value in [Decimal::INFINITY, Decimal::NEG_INFINITY] {
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<Decimal>(&json).unwrap(), value);
+    }
+}
+
+#[test]
+fn rejects_invalid_input() {
+    assert!(serde_json::from_value::<Decimal>(json!("1.5eabc")).is_err());
+    assert!(serde_json::from_value::<Decimal>(json!({ "mantissa": 1.5 })).is_err());
+    assert!(serde_json::from_value::<Decimal>(json!([1.5])).is_err());
+    assert!(serde_json::from_value::<Decimal>(json!(true)).is_err());
+}
+
+#[test]
+fn round_trips_generic_numbers_as_strings() {
+    #[derive(Serialize, Deserialize)]
+    struct Generic<N: BaseNumber> {
+        #[serde(with = "number_base::serde::string")]
+        value: N,
+    }
+
+    let decimal = Decimal::new(2.0).pow(&Decimal::new(12345.678));
+    let json = serde_json::to_string(&Generic { value: decimal }).unwrap();
+    assert_eq!(
+        serde_json::from_str::<Generic<Decimal>>(&json)
+            .unwrap()
+            .value,
+        decimal
+    );
+}
@@ -0,0 +1,79 @@
+use number_base::BaseNumber;
+use number_double_float::Decimal;
+
+fn x(string: &str) -> Decimal {
+    string.parse().unwrap()
+}
+
+/// Asserts that the relative error of a result is within the tolerance. The expected values
+/// come from mpmath, with the same f64 inputs.
+#[track_caller]
+fn assert_close(actual: Decimal, expected: &str, tolerance: f64) {
+    let expected = x(expected);
+    let error = ((actual - expected) / expected).abs().to_number();
+    assert!(
+        error <= tolerance,
+        "got {}, expected {} (error {:e})",
+        actual,
+        expected,
+        error
+    );
+}
+
+#[test]
+fn geometric_series_beyond_f64() {
+    let affordable =
+        Decimal::afford_geometric_series(&x("1e1000"), &x("10"), &x("1.15"), &x("100"));
+    assert_eq!(affordable, x("16345"));
+    assert_close(
+        Decimal::sum_geometric_series(&affordable, &x("10"), &x("1.15"), &x("100")),
+        "9.99754473464326223466871250349e999",
+        1e-12,
+    );
+
+    // The price of the first item alone is past 1e434.
+    assert_eq!(
+        Decimal::afford_geometric_series(&x("1e9000000"), &x("1"), &x("1.0001"), &x("1e7")),
+        x("207232927721")
+    );
+}
+
+#[test]
+fn geometric_series_with_a_ratio_close_to_1() {
+    assert_eq!(
+        Decimal::afford_geometric_series(&x("1e300"), &x("1"), &x("1.000001"), &x("5")),
+        x("676960350")
+    );
+    assert_close(
+        Decimal::sum_geometric_series(&x("676960350"), &x("1"), &x("1.000001"), &x("5")),
+        "9.99999124190175603145135052224e299",
+        1e-12,
+    );
+}
+
+#[test]
+fn arithmetic_series_with_many_items() {
+    let affordable =
+        Decimal::afford_arithmetic_series(&x("1e30"), &x("10"), &x("1"), &Decimal::ZERO);
+    assert_eq!(affordable, x("1414213562373085"));
+    assert!(
+        Decimal::sum_arithmetic_series(&affordable, &x("10"), &x("1"), &Decimal::ZERO)
+            .lte(&x("1e30"))
+    );
+}
+
+#[test]
+fn affordable_counts_are_integers() {
+    // 116 is stored as 1.16e2, whose product rounds to just below 116.
+    for count in [116.0, 1005.0, 4.35e15] {
+        assert_eq!(Decimal::new(count).to_number(), count);
+        assert_eq!(Decimal::new(count).floor(), Decimal::new(count));
+    }
+
+    for resources in 1..200 {
+        let resources = Decimal::new(resources as f64);
+        let affordable =
+            Decimal::afford_geometric_series(&resources, &x("1"), &x("1.1"), &Decimal::ZERO);
+        assert_eq!(affordable.floor(), affordable);
+    }
+}
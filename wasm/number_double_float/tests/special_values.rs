@@ -0,0 +1,163 @@
+use number_base::BaseNumber;
+use number_double_float::Decimal;
+
+fn d(value: f64) -> Decimal {
+    Decimal::new(value)
+}
+
+#[test]
+fn constants_match_f64_conversions() {
+    assert!(Decimal::NAN.is_nan());
+    assert_eq!(d(f64::INFINITY), Decimal::INFINITY);
+    assert_eq!(d(f64::NEG_INFINITY), Decimal::NEG_INFINITY);
+    assert_eq!(d(0.0), Decimal::ZERO);
+    assert_eq!(d(-0.0), Decimal::ZERO);
+    assert_eq!(d(1.0), Decimal::ONE);
+
+    assert!(Decimal::INFINITY.is_infinite() && !Decimal::INFINITY.is_finite());
+    assert!(!Decimal::NAN.is_infinite() && !Decimal::NAN.is_finite());
+    assert!(d(1e300).is_finite());
+}
+
+#[test]
+fn nan_is_unordered_and_unequal() {
+    let nan = Decimal::NAN;
+
+    assert_ne!(nan, nan);
+    assert_eq!(nan.partial_cmp(&nan), None);
+    assert_eq!(nan.partial_cmp(&Decimal::ONE), None);
+    assert!(!BaseNumber::lt(&nan, &Decimal::ONE) && !BaseNumber::gt(&nan, &Decimal::ONE));
+    assert!(nan.neq(&nan));
+}
+
+#[test]
+fn infinities_compare_beyond_every_finite_number() {
+    let huge = "9.99e9000000".parse::<Decimal>().unwrap();
+
+    assert!(Decimal::INFINITY > huge);
+    assert!(Decimal::NEG_INFINITY < -huge);
+    assert!(Decimal::NEG_INFINITY < Decimal::INFINITY);
+    assert_eq!(Decimal::INFINITY, Decimal::INFINITY);
+}
+
+#[test]
+fn nan_propagates_through_arithmetic() {
+    let nan = Decimal::NAN;
+
+    for result in [
+        nan + Decimal::ONE,
+        Decimal::ONE - nan,
+        nan * Decimal::ZERO,
+        Decimal::ONE / nan,
+        -nan,
+        nan.sqrt(),
+        nan.ln(),
+        nan.pow(&Decimal::ONE),
+        Decimal::new(2.0).pow(&nan),
+    ] {
+        assert!(result.is_nan(), "{:?}", result);
+    }
+}
+
+#[test]
+fn invalid_operations_produce_nan() {
+    for result in [
+        Decimal::INFINITY - Decimal::INFINITY,
+        Decimal::INFINITY + Decimal::NEG_INFINITY,
+        Decimal::ZERO * Decimal::INFINITY,
+        Decimal::ZERO / Decimal::ZERO,
+        Decimal::INFINITY / Decimal::INFINITY,
+        d(-4.0).sqrt(),
+        d(-4.0).log10(),
+        d(-8.0).pow(&d(0.5)),
+    ] {
+        assert!(result.is_nan(), "{:?}", result);
+    }
+}
+
+#[test]
+fn infinities_follow_ieee_arithmetic() {
+    assert_eq!(Decimal::INFINITY + d(1e300), Decimal::INFINITY);
+    assert_eq!(d(-5.0) + Decimal::NEG_INFINITY, Decimal::NEG_INFINITY);
+    assert_eq!(Decimal::INFINITY + Decimal::INFINITY, Decimal::INFINITY);
+    assert_eq!(Decimal::INFINITY * d(-2.0), Decimal::NEG_INFINITY);
+    assert_eq!(
+        Decimal::NEG_INFINITY * Decimal::NEG_INFINITY,
+        Decimal::INFINITY
+    );
+    assert_eq!(d(5.0) / Decimal::INFINITY, Decimal::ZERO);
+    assert_eq!(Decimal::INFINITY.recip(), Decimal::ZERO);
+    assert_eq!(-Decimal::INFINITY, Decimal::NEG_INFINITY);
+    assert_eq!(Decimal::INFINITY.sqrt(), Decimal::INFINITY);
+    assert_eq!(Decimal::NEG_INFINITY.cbrt(), Decimal::NEG_INFINITY);
+    assert_eq!(Decimal::INFINITY.log10(), Decimal::INFINITY);
+}
+
+#[test]
+fn adding_an_infinity_gives_the_infinity() {
+    // Finite numbers with a mantissa of exactly 1 or -1 share it with the infinities.
+    assert_eq!(d(1e5) + Decimal::INFINITY, Decimal::INFINITY);
+    assert_eq!(Decimal::INFINITY + d(1e5), Decimal::INFINITY);
+    assert_eq!(d(1.0) - Decimal::NEG_INFINITY, Decimal::INFINITY);
+    assert_eq!(d(-1.0) + Decimal::NEG_INFINITY, Decimal::NEG_INFINITY);
+    assert_eq!(d(-1e-5) - Decimal::INFINITY, Decimal::NEG_INFINITY);
+    assert_eq!(d(1.0) + Decimal::NEG_INFINITY, Decimal::NEG_INFINITY);
+}
+
+#[test]
+fn overflow_and_underflow_saturate() {
+    let big = Decimal::new(10.0).pow(&d(1e308));
+    assert!(big.is_finite());
+    assert_eq!(big * big, Decimal::INFINITY);
+    assert_eq!(-big * big, Decimal::NEG_INFINITY);
+    assert_eq!(big.recip() * big.recip(), Decimal::ZERO);
+}
+
+#[test]
+fn zero_is_unsigned() {
+    assert_eq!(Decimal::ZERO.sign(), 0);
+    assert_eq!((-Decimal::ZERO).sign(), 0);
+    assert_eq!(-Decimal::ZERO, Decimal::ZERO);
+    assert_eq!(d(-0.0).to_bytes(), Decimal::ZERO.to_bytes());
+    assert_eq!(d(3.0) - d(3.0), Decimal::ZERO);
+
+    assert_eq!(d(2.0) / Decimal::ZERO, Decimal::INFINITY);
+    assert_eq!(d(-2.0) / Decimal::ZERO, Decimal::NEG_INFINITY);
+    assert_eq!(Decimal::ZERO.recip(), Decimal::INFINITY);
+}
+
+#[test]
+fn pow_special_cases() {
+    assert_eq!(Decimal::NAN.pow(&Decimal::ZERO), Decimal::ONE);
+    assert_eq!(Decimal::ONE.pow(&Decimal::NAN), Decimal::ONE);
+    assert_eq!(Decimal::ZERO.pow(&d(2.0)), Decimal::ZERO);
+    assert_eq!(Decimal::ZERO.pow(&d(-2.0)), Decimal::INFINITY);
+    assert_eq!(Decimal::INFINITY.pow(&d(-1.0)), Decimal::ZERO);
+    assert_eq!(d(2.0).pow(&Decimal::INFINITY), Decimal::INFINITY);
+    assert_eq!(d(0.5).pow(&Decimal::INFINITY), Decimal::ZERO);
+    assert_eq!(d(2.0).pow(&Decimal::NEG_INFINITY), Decimal::ZERO);
+    assert_eq!(d(-2.0).pow(&d(3.0)), d(-8.0));
+    assert_eq!(d(-2.0).pow(&d(2.0)), d(4.0));
+}
+
+#[test]
+fn bounds_and_totals() {
+    let huge = Decimal::from_mantissa_exponent(1.0, 1e300);
+    let incomes = [huge, d(5.0), huge];
+    assert_eq!(incomes.iter().sum::<Decimal>(), huge * d(2.0));
+    assert_eq!(
+        incomes.iter().product::<Decimal>(),
+        Decimal::from_mantissa_exponent(5.0, 2e300)
+    );
+
+    assert_eq!(huge.max(&d(1e300)), huge);
+    assert_eq!((-huge).clamp(&d(0.0), &huge), Decimal::ZERO);
+    assert!(d(1.0).min(&Decimal::NAN).is_nan());
+    assert!(Decimal::NAN.clamp_min(&d(0.0)).is_nan());
+}
+
+#[test]
+#[should_panic(expected = "clamp with a minimum of 3 above the maximum of 2")]
+fn clamp_rejects_an_empty_range() {
+    d(1.0).clamp(&d(3.0), &d(2.0));
+}
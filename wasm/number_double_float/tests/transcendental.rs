@@ -0,0 +1,155 @@
+use number_base::BaseNumber;
+use number_double_float::Decimal;
+
+fn x(string: &str) -> Decimal {
+    string.parse().unwrap()
+}
+
+/// Asserts that the relative error of a result is within the tolerance. The expected values
+/// come from mpmath at 30 digits.
+#[track_caller]
+fn assert_close(actual: Decimal, expected: &str, tolerance: f64) {
+    let expected = x(expected);
+    let error = ((actual - expected) / expected).abs().to_number();
+    assert!(
+        error <= tolerance,
+        "got {}, expected {} (error {:e})",
+        actual,
+        expected,
+        error
+    );
+}
+
+#[test]
+fn exponentials_beyond_f64() {
+    assert_close(
+        x("1000").exp(),
+        "1.97007111401704699388887935224e434",
+        1e-15,
+    );
+    assert_close(
+        x("-1000").exp(),
+        "5.07595889754945676529180947957e-435",
+        1e-15,
+    );
+    assert_close(
+        x("1e6").exp(),
+        "3.03321539680208754508640214142e434294",
+        1e-15,
+    );
+    assert_eq!(x("1e-400").exp(), Decimal::ONE);
+    assert_eq!(x("1e400").exp(), Decimal::INFINITY);
+    assert_eq!(x("-1e400").exp(), Decimal::ZERO);
+
+    assert_close(x("2.5").pow10(), "316.227766016837933199889354443", 1e-15);
+    assert_eq!(
+        x("1e10").pow10(),
+        Decimal::from_mantissa_exponent(1.0, 1e10)
+    );
+    assert_eq!(x("-1e400").pow10(), Decimal::ZERO);
+}
+
+#[test]
+fn logarithms_and_roots() {
+    assert_close(
+        x("1e400").log(&x("7")),
+        "473.317864981975330727171424659",
+        1e-15,
+    );
+    assert_close(x("1e400").log(&x("10")), "400", 0.0);
+
+    assert_eq!(x("1e-400").root(&x("2")), x("1e-200"));
+    assert_close(x("-8e300").root(&x("3")), "-2e100", 1e-15);
+    assert_close(
+        x("2e301").root(&x("5")),
+        "1.82056420302608026437942105471e60",
+        1e-15,
+    );
+    assert_close(x("1e-400").root(&x("-2")), "1e200", 1e-15);
+    assert_close(x("1e9000").root(&x("0.5")), "1e18000", 1e-12);
+    assert!(x("-8").root(&x("2")).is_nan());
+}
+
+#[test]
+fn hyperbolic_and_trigonometric_functions() {
+    assert_close(
+        x("1000").sinh(),
+        "9.85035557008523496944439676122e433",
+        1e-15,
+    );
+    assert_close(
+        x("-1000").sinh(),
+        "-9.85035557008523496944439676122e433",
+        1e-15,
+    );
+    assert_close(
+        x("-1000").cosh(),
+        "9.85035557008523496944439676122e433",
+        1e-15,
+    );
+    assert_eq!(x("1e400").tanh(), Decimal::ONE);
+    assert_eq!(x("-1e400").tanh(), -Decimal::ONE);
+
+    // Numbers too small for an f64 keep their digits.
+    assert_eq!(x("1e-400").sinh(), x("1e-400"));
+    assert_eq!(x("-3e-400").tanh(), x("-3e-400"));
+    assert_eq!(x("1e-400").sin(), x("1e-400"));
+    assert_eq!(x("1e-400").tan(), x("1e-400"));
+    assert_eq!(x("1e-400").cos(), Decimal::ONE);
+    assert!(x("1e400").sin().is_nan());
+    assert!(Decimal::INFINITY.cos().is_nan());
+}
+
+#[test]
+fn gamma_and_factorial() {
+    assert_eq!(x("5").factorial(), x("120"));
+    assert_eq!(x("20").factorial(), x("2432902008176640000"));
+    assert_close(x("0.5").gamma(), "1.77245385090551602729816748334", 4e-15);
+    assert_close(
+        x("-2.5").gamma(),
+        "-0.945308720482941881225689324449",
+        4e-15,
+    );
+    assert_close(
+        x("171.5").gamma(),
+        "9.4833675668247993362534054692e307",
+        1e-12,
+    );
+    assert_close(
+        x("-200.25").gamma(),
+        "-1.49685544911694886603024777859e-375",
+        1e-12,
+    );
+    assert_close(
+        x("1000").factorial(),
+        "4.02387260077093773543702433923e2567",
+        1e-12,
+    );
+    assert_close(x("1e-400").gamma(), "1e400", 1e-15);
+    assert_eq!(Decimal::ZERO.gamma(), Decimal::INFINITY);
+    assert!(x("-3").gamma().is_nan());
+    assert_eq!(x("1e400").gamma(), Decimal::INFINITY);
+}
+
+#[test]
+fn lambert_w() {
+    assert_close(x("1").lambert_w(), "0.56714329040978387299996866221", 1e-15);
+    assert_close(
+        x("1e100").lambert_w(),
+        "224.843106445118501539373134338",
+        1e-15,
+    );
+    assert_close(
+        x("1e400").lambert_w(),
+        "914.215970362651325255830960756",
+        1e-15,
+    );
+    assert_close(Decimal::new(std::f64::consts::E).lambert_w(), "1", 1e-15);
+    assert_eq!(x("1e-400").lambert_w(), x("1e-400"));
+    assert_close(
+        Decimal::new(-1.0 / std::f64::consts::E).lambert_w(),
+        "-1",
+        1e-7,
+    );
+    assert!(x("-1").lambert_w().is_nan());
+}
@@ -0,0 +1,82 @@
+use number_base::BaseNumber;
+use number_double_float::Decimal as DoubleFloat;
+
+use crate::{signum, Decimal};
+
+impl From<DoubleFloat> for Decimal {
+    fn from(decimal: DoubleFloat) -> Decimal {
+        if decimal.is_nan() {
+            Decimal::NAN
+        } else if decimal.is_infinite() {
+            if decimal.mantissa() > 0.0 {
+                Decimal::INFINITY
+            } else {
+                Decimal::NEG_INFINITY
+            }
+        } else if decimal.exponent().abs() < 300.0 {
+            Decimal::from(decimal.to_number())
+        } else {
+            Decimal::from_components(
+                signum(decimal.mantissa()),
+                1.0,
+                decimal.exponent() + decimal.mantissa().abs().log10(),
+            )
+        }
+    }
+}
+
+impl From<Decimal> for DoubleFloat {
+    /// Converts the number into a [`number_double_float::Decimal`], saturating to ±Infinity
+    /// and zero outside of its range.
+    fn from(decimal: Decimal) -> DoubleFloat {
+        decimal.saturating_double_float()
+    }
+}
+
+impl Decimal {
+    /// Returns the number as a [`number_double_float::Decimal`], or `None` if it overflows or
+    /// underflows its range.
+    pub fn to_double_float(&self) -> Option<DoubleFloat> {
+        let decimal = self.saturating_double_float();
+
+        if decimal.is_infinite() != self.is_infinite() || (decimal.sign() == 0) != (self.sign == 0)
+        {
+            None
+        } else {
+            Some(decimal)
+        }
+    }
+
+    fn saturating_double_float(&self) -> DoubleFloat {
+        if self.is_nan() {
+            return DoubleFloat::NAN;
+        } else if self.layer == 0.0 {
+            return DoubleFloat::new(self.sign as f64 * self.mag);
+        }
+
+        // The base 10 logarithm of the absolute value, which is the exponent of the result.
+        let log10 = if self.layer == 1.0 {
+            self.mag
+        } else if self.layer == 2.0 {
+            self.mag.signum() * 10.0_f64.powf(self.mag.abs())
+        } else {
+            self.mag.signum() * f64::INFINITY
+        };
+
+        if log10 == f64::INFINITY {
+            if self.sign > 0 {
+                DoubleFloat::INFINITY
+            } else {
+                DoubleFloat::NEG_INFINITY
+            }
+        } else if log10 == f64::NEG_INFINITY {
+            DoubleFloat::ZERO
+        } else {
+            let exponent = log10.floor();
+            DoubleFloat::from_mantissa_exponent(
+                self.sign as f64 * 10.0_f64.powf(log10 - exponent),
+                exponent,
+            )
+        }
+    }
+}
@@ -0,0 +1,284 @@
+//! Hyper-operators: tetration and its inverses.
+//!
+//! Fractional heights use the linear approximation of break_eternity.js, which defines
+//! `b^^x` as `b^x` for `0 <= x < 1`. Under it [`Decimal::tetrate`] and [`Decimal::slog`] are
+//! exact inverses of each other.
+
+use std::f64::consts::E;
+
+use number_base::BaseNumber;
+
+use crate::Decimal;
+
+/// The largest base for which an infinitely tall power tower converges, `e^(1/e)`.
+const MAX_CONVERGENT_BASE: f64 = 1.444_667_861_009_766;
+
+/// The smallest base for which an infinitely tall power tower converges, `e^-e`.
+const MIN_CONVERGENT_BASE: f64 = 0.065_988_035_845_312_54;
+
+/// The number of iterations after which tetration and the iterated logarithm give up on
+/// reaching their height.
+const MAX_ITERATIONS: f64 = 10000.0;
+
+impl Decimal {
+    /// Raises the number to itself `height` times, on top of `payload`: `self^self^...^payload`.
+    ///
+    /// With a payload of one this is tetration, `self^^height`. Negative heights take the
+    /// iterated logarithm of the payload instead, and an infinite height gives the value the
+    /// power tower converges to, or NaN if it does not.
+    pub fn tetrate(&self, height: f64, payload: &Decimal) -> Decimal {
+        if height == 1.0 {
+            return self.pow(payload);
+        } else if height == 0.0 {
+            return *payload;
+        } else if self.is_nan() || payload.is_nan() || height.is_nan() {
+            return Decimal::NAN;
+        } else if *self == Decimal::ONE {
+            return Decimal::ONE;
+        } else if *self == -Decimal::ONE {
+            return self.pow(payload);
+        } else if height == f64::INFINITY {
+            return self.infinite_tower();
+        } else if self.sign == 0 {
+            // 0^0 is 1, so the tower flips between 0 and 1.
+            return if height.rem_euclid(2.0) < 1.0 {
+                Decimal::ONE
+            } else {
+                Decimal::ZERO
+            };
+        } else if height < 0.0 {
+            return payload.iterated_log(self, -height);
+        }
+
+        let whole = height.trunc();
+        let fraction = height - whole;
+        let mut payload = *payload;
+
+        if self.sign > 0 && self.to_number() <= MAX_CONVERGENT_BASE {
+            // The tower converges or oscillates, so stop as soon as it settles.
+            for _ in 0..whole.min(MAX_ITERATIONS) as u32 {
+                let previous = payload;
+                payload = self.pow(&payload);
+
+                if previous == payload {
+                    return payload;
+                }
+            }
+
+            if fraction != 0.0 {
+                let next = self.pow(&payload);
+                return payload * Decimal::from(1.0 - fraction) + next * Decimal::from(fraction);
+            }
+
+            return payload;
+        }
+
+        if fraction != 0.0 {
+            payload = if payload == Decimal::ONE {
+                self.pow(&Decimal::from(fraction))
+            } else {
+                payload.layer_add(fraction, self)
+            };
+        }
+
+        let mut iteration = 0.0;
+        while iteration < whole {
+            payload = self.pow(&payload);
+            iteration += 1.0;
+
+            if !payload.is_finite() {
+                return payload;
+            } else if payload.layer - self.layer > 3.0 {
+                // Every further power only adds a layer.
+                return Decimal::from_components_no_normalize(
+                    payload.sign,
+                    payload.layer + (whole - iteration),
+                    payload.mag,
+                );
+            } else if iteration > MAX_ITERATIONS {
+                return payload;
+            }
+        }
+
+        payload
+    }
+
+    /// Returns the value an infinitely tall power tower of the number converges to.
+    fn infinite_tower(&self) -> Decimal {
+        let base = self.to_number();
+
+        if base > MAX_CONVERGENT_BASE {
+            Decimal::INFINITY
+        } else if base < MIN_CONVERGENT_BASE {
+            // The tower oscillates between two values, or becomes complex for negative bases.
+            Decimal::NAN
+        } else {
+            // x = b^x is solved by x = W(-ln b) / -ln b.
+            let neg_ln = -base.ln();
+            Decimal::from(lambert_w(neg_ln) / neg_ln)
+        }
+    }
+
+    /// Takes the logarithm of the number in the given base `times` times.
+    ///
+    /// Negative counts tetrate the base on top of the number instead.
+    pub fn iterated_log(&self, base: &Decimal, times: f64) -> Decimal {
+        if times < 0.0 {
+            return base.tetrate(-times, self);
+        }
+
+        let mut whole = times.trunc();
+        let fraction = times - whole;
+        let mut result = *self;
+
+        if result.sign > 0 && result.mag > 0.0 && result.layer - base.layer > 3.0 {
+            // Far above the base, every logarithm only removes a layer.
+            let skipped = whole.min(result.layer - base.layer - 3.0);
+            whole -= skipped;
+            result.layer -= skipped;
+        }
+
+        let mut iteration = 0.0;
+        while iteration < whole {
+            result = result.log(base);
+            iteration += 1.0;
+
+            if !result.is_finite() || iteration > MAX_ITERATIONS {
+                return result;
+            }
+        }
+
+        if fraction > 0.0 {
+            result = result.layer_add(-fraction, base);
+        }
+
+        result
+    }
+
+    /// Returns the super-logarithm of the number in the given base: the height `x` for which
+    /// `base^^x` is the number.
+    pub fn slog(&self, base: &Decimal) -> Decimal {
+        if self.is_nan() || base.is_nan() || base.sign <= 0 || *base == Decimal::ONE {
+            return Decimal::NAN;
+        } else if *base < Decimal::ONE {
+            // Towers of small bases oscillate, so only their first two values are unambiguous.
+            return if *self == Decimal::ONE {
+                Decimal::ZERO
+            } else if self.sign == 0 {
+                -Decimal::ONE
+            } else {
+                Decimal::NAN
+            };
+        } else if *self == Decimal::INFINITY {
+            return Decimal::INFINITY;
+        }
+
+        let mut result = 0.0;
+        let mut copy = *self;
+
+        if copy.sign > 0 && copy.mag > 0.0 && copy.layer - base.layer > 3.0 {
+            // Far above the base, every logarithm only removes a layer.
+            let skipped = copy.layer - base.layer - 3.0;
+            result += skipped;
+            copy.layer -= skipped;
+        }
+
+        for _ in 0..100 {
+            if copy.sign < 0 {
+                copy = base.pow(&copy);
+                result -= 1.0;
+            } else if copy <= Decimal::ONE {
+                return Decimal::from(result + copy.to_number() - 1.0);
+            } else {
+                copy = copy.log(base);
+                result += 1.0;
+            }
+        }
+
+        Decimal::from(result)
+    }
+
+    /// Returns the number `x` for which `x^^degree` is the number, for `degree >= 1`.
+    ///
+    /// Only numbers of at least one have a super-root, as `x^^degree` is ambiguous or complex
+    /// below that.
+    pub fn super_root(&self, degree: f64) -> Decimal {
+        if self.is_nan() || degree.is_nan() || degree < 1.0 || *self < Decimal::ONE {
+            return Decimal::NAN;
+        } else if degree == 1.0 || *self == Decimal::ONE || self.is_infinite() {
+            return *self;
+        }
+
+        // x^^degree grows with x for x >= 1, and so does the super-logarithm of x, which lets
+        // the search run over heights instead of over numbers of any size.
+        let mut low = 0.0;
+        let mut high = self.slog(&Decimal::TEN).to_number();
+
+        for _ in 0..100 {
+            let middle = (low + high) / 2.0;
+            let candidate = Decimal::TEN.tetrate(middle, &Decimal::ONE);
+
+            if candidate.tetrate(degree, &Decimal::ONE) > *self {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+
+        Decimal::TEN.tetrate((low + high) / 2.0, &Decimal::ONE)
+    }
+
+    /// Adds `diff` to the super-logarithm of the number in the given base, moving it up or
+    /// down the power tower by a possibly fractional number of layers.
+    pub fn layer_add(&self, diff: f64, base: &Decimal) -> Decimal {
+        let height = self.slog(base).to_number() + diff;
+
+        if height.is_nan() {
+            Decimal::NAN
+        } else if height >= 0.0 {
+            base.tetrate(height, &Decimal::ONE)
+        } else if height >= -1.0 {
+            base.tetrate(height + 1.0, &Decimal::ONE).log(base)
+        } else {
+            base.tetrate(height + 2.0, &Decimal::ONE)
+                .log(base)
+                .log(base)
+        }
+    }
+}
+
+/// Returns the principal branch of the Lambert W function, the `w` for which `w * e^w = z`.
+fn lambert_w(z: f64) -> f64 {
+    if z.is_nan() || z < -1.0 / E {
+        return f64::NAN;
+    } else if z == 0.0 || z.is_infinite() {
+        return z;
+    } else if z == -1.0 / E {
+        return -1.0;
+    }
+
+    let mut w = if z < -0.25 {
+        // Series around the branch point.
+        let p = (2.0 * (E * z + 1.0)).sqrt();
+        -1.0 + p - p * p / 3.0
+    } else if z < 3.0 {
+        z.ln_1p()
+    } else {
+        z.ln() - z.ln().ln()
+    };
+
+    // Halley's method.
+    for _ in 0..100 {
+        let ew = w.exp();
+        let f = w * ew - z;
+        let next = w - f / (ew * (w + 1.0) - (w + 2.0) * f / (2.0 * w + 2.0));
+
+        if (next - w).abs() <= f64::EPSILON * (1.0 + next.abs()) {
+            return next;
+        }
+
+        w = next;
+    }
+
+    w
+}
@@ -0,0 +1,598 @@
+mod convert;
+mod hyper;
+mod math;
+mod parse;
+
+use std::{
+    cmp::Ordering,
+    f64::consts::{LN_10, LOG2_10},
+    fmt::{self, Display, Formatter},
+};
+
+pub use number_base::{ArithmeticPolicy, DecimalError, ParseDecimalError};
+
+use number_base::BaseNumber;
+
+/// The largest magnitude a layer can hold before the number moves up a layer.
+pub const EXP_LIMIT: f64 = 9e15;
+
+/// The magnitude below which a number moves down a layer, `log10(EXP_LIMIT)`.
+pub const LAYER_DOWN: f64 = 15.954242509439325;
+
+/// The smallest magnitude on layer 0; smaller numbers are stored as negative magnitudes on
+/// layer 1.
+pub const FIRST_NEG_LAYER: f64 = 1.0 / EXP_LIMIT;
+
+pub const MAX_SIGNIFICANT_DIGITS: u32 = 17;
+
+/// The number of layers which are written out as repeated `e`s before switching to `(e^N)`.
+pub const MAX_ES_IN_A_ROW: f64 = 5.0;
+
+/// A number stored as `sign * 10^10^...^mag`, with `layer` tens in the power tower, in the
+/// style of break_eternity.js.
+///
+/// On layer 0 the magnitude is the absolute value itself. On every higher layer the number is
+/// ten raised to the number one layer down, whose sign is the sign of the magnitude; a
+/// negative magnitude on layer 1 is therefore a number smaller than [`FIRST_NEG_LAYER`].
+/// This reaches far beyond [`number_double_float::Decimal`], up to numbers like `10^^1e308`.
+///
+/// Special values follow the same model as [`number_double_float::Decimal`]: NaN propagates
+/// and is unordered, ±Infinity is produced by overflow and by dividing by zero, and zero is
+/// unsigned.
+#[derive(Clone, Copy, Debug)]
+pub struct Decimal {
+    sign: i8,
+    layer: f64,
+    mag: f64,
+}
+
+macro_rules! impl_from {
+    ($t:ty) => {
+        impl From<$t> for Decimal {
+            fn from(value: $t) -> Self {
+                Decimal::from(value as f64)
+            }
+        }
+    };
+}
+
+impl_from!(i8);
+impl_from!(i16);
+impl_from!(i32);
+impl_from!(i64);
+impl_from!(i128);
+impl_from!(isize);
+impl_from!(u8);
+impl_from!(u16);
+impl_from!(u32);
+impl_from!(u64);
+impl_from!(u128);
+impl_from!(usize);
+impl_from!(f32);
+
+impl From<f64> for Decimal {
+    fn from(value: f64) -> Self {
+        if f64::is_nan(value) {
+            return Decimal::NAN;
+        }
+
+        Decimal::from_components(signum(value), 0.0, value.abs())
+    }
+}
+
+impl From<String> for Decimal {
+    fn from(string: String) -> Self {
+        string.parse().unwrap_or(Decimal::NAN)
+    }
+}
+
+impl From<Decimal> for String {
+    fn from(decimal: Decimal) -> String {
+        decimal.to_string()
+    }
+}
+
+impl PartialEq<Decimal> for Decimal {
+    fn eq(&self, decimal: &Decimal) -> bool {
+        self.partial_cmp(decimal) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, decimal: &Self) -> Option<Ordering> {
+        if self.is_nan() || decimal.is_nan() {
+            None
+        } else if self.sign != decimal.sign {
+            Some(self.sign.cmp(&decimal.sign))
+        } else if self.sign < 0 {
+            Some(decimal.cmp_abs(self))
+        } else {
+            Some(self.cmp_abs(decimal))
+        }
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.to_double_float() {
+            Some(decimal) => Display::fmt(&decimal, f),
+            None => write!(
+                f,
+                "{}",
+                self.layer_string(|mag| match f.precision() {
+                    Some(places) => format!("{:.*}", places, mag),
+                    None => mag.to_string(),
+                })
+            ),
+        }
+    }
+}
+
+impl BaseNumber for Decimal {
+    const ZERO: Decimal = Decimal::ZERO;
+    const ONE: Decimal = Decimal::ONE;
+    const NAN: Decimal = Decimal::NAN;
+    const INFINITY: Decimal = Decimal::INFINITY;
+    const NEG_INFINITY: Decimal = Decimal::NEG_INFINITY;
+
+    fn to_number(&self) -> f64 {
+        if self.is_nan() {
+            f64::NAN
+        } else if self.layer == 0.0 {
+            self.sign as f64 * self.mag
+        } else if self.layer == 1.0 {
+            self.sign as f64 * 10.0_f64.powf(self.mag)
+        } else if self.mag > 0.0 {
+            self.sign as f64 * f64::INFINITY
+        } else {
+            0.0
+        }
+    }
+
+    fn to_canonical_string(&self) -> String {
+        self.to_canonical_string()
+    }
+
+    /// Formats the number like [`number_double_float::Decimal::to_exponential`] while it fits
+    /// into one, and as a power tower with `places` decimal places in the magnitude otherwise.
+    fn to_exponential(&self, places: u32) -> String {
+        match self.to_double_float() {
+            Some(decimal) => decimal.to_exponential(places),
+            None => self.layer_string(|mag| format!("{:.*}", places as usize, mag)),
+        }
+    }
+
+    /// Formats the number like [`number_double_float::Decimal::to_fixed`] while it fits into
+    /// one, and as a power tower with `places` decimal places in the magnitude otherwise.
+    fn to_fixed(&self, places: u32) -> String {
+        match self.to_double_float() {
+            Some(decimal) => decimal.to_fixed(places),
+            None => self.layer_string(|mag| format!("{:.*}", places as usize, mag)),
+        }
+    }
+
+    /// Formats the number like [`number_double_float::Decimal::to_precision`] while it fits
+    /// into one, and as a power tower with `places` significant digits in the magnitude
+    /// otherwise.
+    fn to_precision(&self, places: u32) -> String {
+        match self.to_double_float() {
+            Some(decimal) => decimal.to_precision(places),
+            None => self.layer_string(|mag| {
+                let digits = mag.abs().log10().floor().max(0.0) as usize + 1;
+                format!("{:.*}", (places as usize).saturating_sub(digits), mag)
+            }),
+        }
+    }
+
+    fn abs(&self) -> Decimal {
+        Decimal {
+            sign: self.sign.abs(),
+            ..*self
+        }
+    }
+
+    fn round(&self) -> Decimal {
+        self.map_small(f64::round)
+    }
+
+    fn trunc(&self) -> Decimal {
+        self.map_small(f64::trunc)
+    }
+
+    fn floor(&self) -> Decimal {
+        self.map_small(f64::floor)
+    }
+
+    fn ceil(&self) -> Decimal {
+        self.map_small(f64::ceil)
+    }
+
+    fn sqrt(&self) -> Decimal {
+        if self.sign < 0 {
+            Decimal::NAN
+        } else if !self.is_finite() || self.sign == 0 {
+            *self
+        } else if self.layer == 0.0 {
+            Decimal::from(self.mag.sqrt())
+        } else {
+            (self.log10() / Decimal::from(2)).pow10()
+        }
+    }
+
+    fn recip(&self) -> Decimal {
+        if self.is_nan() {
+            Decimal::NAN
+        } else if self.is_infinite() {
+            Decimal::ZERO
+        } else if self.sign == 0 {
+            Decimal::INFINITY
+        } else if self.layer == 0.0 {
+            Decimal::from_components(self.sign, 0.0, 1.0 / self.mag)
+        } else {
+            Decimal::from_components(self.sign, self.layer, -self.mag)
+        }
+    }
+
+    fn cbrt(&self) -> Decimal {
+        if !self.is_finite() || self.sign == 0 {
+            *self
+        } else if self.sign < 0 {
+            -self.abs().cbrt()
+        } else if self.layer == 0.0 {
+            Decimal::from(self.mag.cbrt())
+        } else {
+            (self.log10() / Decimal::from(3)).pow10()
+        }
+    }
+
+    fn ln(&self) -> Decimal {
+        self.log10() * Decimal::from(LN_10)
+    }
+
+    fn log10(&self) -> Decimal {
+        if self.is_nan() || self.sign < 0 {
+            Decimal::NAN
+        } else if self.sign == 0 {
+            Decimal::NEG_INFINITY
+        } else if self.is_infinite() {
+            Decimal::INFINITY
+        } else if self.layer == 0.0 {
+            Decimal::from(self.mag.log10())
+        } else {
+            // The logarithm of a number on a higher layer is the number one layer down.
+            Decimal::from_components(signum(self.mag), self.layer - 1.0, self.mag.abs())
+        }
+    }
+
+    fn log2(&self) -> Decimal {
+        self.log10() * Decimal::from(LOG2_10)
+    }
+
+    fn pow(&self, decimal: &Decimal) -> Decimal {
+        if (decimal.sign == 0 && !decimal.is_nan()) || *self == Decimal::ONE {
+            return Decimal::ONE;
+        } else if self.is_nan() || decimal.is_nan() {
+            return Decimal::NAN;
+        } else if self.sign < 0 {
+            // A negative number can only be raised to an integer power,
+            // and the result is negative for odd powers.
+            if !decimal.is_integer() {
+                return Decimal::NAN;
+            }
+
+            let result = self.abs().pow(decimal);
+            return if decimal.layer == 0.0 && decimal.mag % 2.0 == 1.0 {
+                -result
+            } else {
+                result
+            };
+        } else if self.sign == 0 {
+            return if decimal.sign > 0 {
+                Decimal::ZERO
+            } else {
+                Decimal::INFINITY
+            };
+        } else if self.is_infinite() {
+            return if decimal.sign > 0 {
+                Decimal::INFINITY
+            } else {
+                Decimal::ZERO
+            };
+        } else if decimal.is_infinite() {
+            return if (*self > Decimal::ONE) == (decimal.sign > 0) {
+                Decimal::INFINITY
+            } else {
+                Decimal::ZERO
+            };
+        }
+
+        // Fast track: both numbers fit in an f64, and so does the result.
+        if self.layer == 0.0 && decimal.layer == 0.0 {
+            let result = self.mag.powf(decimal.sign as f64 * decimal.mag);
+            if result.is_finite() && result >= FIRST_NEG_LAYER {
+                return Decimal::from(result);
+            }
+        }
+
+        (self.log10() * decimal).pow10()
+    }
+
+    fn log(&self, base: &Decimal) -> Decimal {
+        self.log10() / base.log10()
+    }
+
+    fn pow10(&self) -> Decimal {
+        if self.is_nan() {
+            return Decimal::NAN;
+        } else if self.is_infinite() {
+            return if self.sign > 0 {
+                Decimal::INFINITY
+            } else {
+                Decimal::ZERO
+            };
+        }
+
+        if self.layer > 0.0 && self.mag < 0.0 {
+            // 10 raised to a number which rounds to zero.
+            Decimal::ONE
+        } else {
+            Decimal::from_components(1, self.layer + 1.0, self.sign as f64 * self.mag)
+        }
+    }
+
+    fn sign(&self) -> i32 {
+        self.sign as i32
+    }
+}
+
+impl Decimal {
+    /// Not a Number.
+    pub const NAN: Decimal = Decimal {
+        sign: 0,
+        layer: f64::NAN,
+        mag: f64::NAN,
+    };
+
+    /// Positive infinity.
+    pub const INFINITY: Decimal = Decimal {
+        sign: 1,
+        layer: f64::INFINITY,
+        mag: f64::INFINITY,
+    };
+
+    /// Negative infinity.
+    pub const NEG_INFINITY: Decimal = Decimal {
+        sign: -1,
+        layer: f64::INFINITY,
+        mag: f64::INFINITY,
+    };
+
+    /// Zero.
+    pub const ZERO: Decimal = Decimal {
+        sign: 0,
+        layer: 0.0,
+        mag: 0.0,
+    };
+
+    /// One.
+    pub const ONE: Decimal = Decimal {
+        sign: 1,
+        layer: 0.0,
+        mag: 1.0,
+    };
+
+    /// Ten, the base of every layer.
+    pub const TEN: Decimal = Decimal {
+        sign: 1,
+        layer: 0.0,
+        mag: 10.0,
+    };
+
+    /// Creates a new instance of Decimal with the given sign, layer and magnitude,
+    /// normalizing them.
+    pub fn from_components(sign: i8, layer: f64, mag: f64) -> Decimal {
+        Decimal::from_components_no_normalize(sign, layer, mag).normalize()
+    }
+
+    /// Creates a new instance of Decimal with the given sign, layer and magnitude,
+    /// without normalizing them.
+    pub fn from_components_no_normalize(sign: i8, layer: f64, mag: f64) -> Decimal {
+        Decimal { sign, layer, mag }
+    }
+
+    /// Returns the number of tens in the power tower.
+    pub fn layer(&self) -> f64 {
+        self.layer
+    }
+
+    /// Returns the magnitude at the top of the power tower.
+    pub fn mag(&self) -> f64 {
+        self.mag
+    }
+
+    /// Returns whether the number is NaN.
+    pub fn is_nan(&self) -> bool {
+        f64::is_nan(self.layer) || f64::is_nan(self.mag)
+    }
+
+    /// Returns whether the number is positive or negative infinity.
+    pub fn is_infinite(&self) -> bool {
+        !self.is_nan() && (self.layer == f64::INFINITY || self.mag == f64::INFINITY)
+    }
+
+    /// Returns whether the number is neither NaN nor infinite.
+    pub fn is_finite(&self) -> bool {
+        !self.is_nan() && !self.is_infinite()
+    }
+
+    /// Returns whether the number is an integer. Every number beyond [`EXP_LIMIT`] is.
+    pub fn is_integer(&self) -> bool {
+        if self.layer == 0.0 {
+            self.mag.fract() == 0.0
+        } else {
+            self.is_finite() && self.mag > 0.0
+        }
+    }
+
+    /// Returns the ordering between the absolute values of the number and another one.
+    fn cmp_abs(&self, decimal: &Decimal) -> Ordering {
+        // Zero is on layer 0, but smaller than the negative magnitudes of higher layers.
+        let layer = |decimal: &Decimal| match decimal.sign {
+            0 => f64::NEG_INFINITY,
+            _ if decimal.mag < 0.0 => -decimal.layer,
+            _ => decimal.layer,
+        };
+
+        layer(self)
+            .partial_cmp(&layer(decimal))
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| {
+                self.mag
+                    .partial_cmp(&decimal.mag)
+                    .unwrap_or(Ordering::Equal)
+            })
+    }
+
+    /// Applies a rounding function to numbers which fit in an f64. Every number beyond
+    /// [`EXP_LIMIT`] is already an integer, and every number below [`FIRST_NEG_LAYER`] rounds
+    /// like the smallest positive f64 of the same sign.
+    fn map_small(&self, function: fn(f64) -> f64) -> Decimal {
+        if self.is_nan() {
+            Decimal::NAN
+        } else if self.layer == 0.0 {
+            Decimal::from(function(self.sign as f64 * self.mag))
+        } else if self.mag < 0.0 {
+            Decimal::from(function(self.sign as f64 * f64::MIN_POSITIVE))
+        } else {
+            *self
+        }
+    }
+
+    /// Returns the canonical string form of the number, which parses back into exactly the
+    /// same number.
+    ///
+    /// Numbers on layer 0 are written in scientific notation, e.g. `1.5e300`, and numbers on
+    /// higher layers as `(e^<layer>)<mag>`, e.g. `(e^2)1234.5`, in both cases with the
+    /// shortest digits that round-trip to the same f64. Zero is `0`, and the special values are
+    /// `NaN`, `Infinity` and `-Infinity`.
+    pub fn to_canonical_string(&self) -> String {
+        if self.is_nan() || self.is_infinite() {
+            self.layer_string(|mag| mag.to_string())
+        } else if self.sign == 0 {
+            String::from("0")
+        } else if self.layer == 0.0 {
+            format!("{:e}", self.sign as f64 * self.mag)
+        } else {
+            format!(
+                "{}(e^{}){}",
+                if self.sign < 0 { "-" } else { "" },
+                self.layer,
+                self.mag
+            )
+        }
+    }
+
+    /// Writes the number as a power tower, formatting the magnitude at its top with the given
+    /// function.
+    ///
+    /// Each `e` raises 10 to the power of what follows it, so `-ee20` is `-(10^10^20)` and
+    /// `e-e20` is `10^-(10^20)`. Towers of more than [`MAX_ES_IN_A_ROW`] positive layers are
+    /// shortened to `(e^<layer>)<mag>`.
+    fn layer_string(&self, format_mag: impl Fn(f64) -> String) -> String {
+        if self.is_nan() {
+            return String::from("NaN");
+        } else if self.is_infinite() {
+            return String::from(if self.sign > 0 {
+                "Infinity"
+            } else {
+                "-Infinity"
+            });
+        }
+
+        let sign = if self.sign < 0 { "-" } else { "" };
+        if self.layer == 0.0 {
+            format!("{}{}", sign, format_mag(self.mag))
+        } else if self.mag < 0.0 {
+            let inner = Decimal::from_components_no_normalize(-1, self.layer - 1.0, -self.mag);
+            format!("{}e{}", sign, inner.layer_string(format_mag))
+        } else if self.layer <= MAX_ES_IN_A_ROW {
+            format!(
+                "{}{}{}",
+                sign,
+                "e".repeat(self.layer as usize),
+                format_mag(self.mag)
+            )
+        } else {
+            format!("{}(e^{}){}", sign, self.layer, format_mag(self.mag))
+        }
+    }
+
+    /// Normalizes the layer and magnitude, moving the number up or down layers until its
+    /// magnitude is within range.
+    fn normalize(&self) -> Decimal {
+        let Decimal {
+            mut sign,
+            mut layer,
+            mut mag,
+        } = *self;
+
+        if self.is_nan() {
+            return Decimal::NAN;
+        } else if sign == 0
+            || (layer == 0.0 && mag == 0.0)
+            || (layer > 0.0 && layer.is_finite() && mag == f64::NEG_INFINITY)
+        {
+            return Decimal::ZERO;
+        } else if layer == 0.0 && mag < 0.0 {
+            mag = -mag;
+            sign = -sign;
+        }
+
+        if layer.is_infinite() || mag.is_infinite() {
+            return if sign > 0 {
+                Decimal::INFINITY
+            } else {
+                Decimal::NEG_INFINITY
+            };
+        } else if layer == 0.0 && mag < FIRST_NEG_LAYER {
+            return Decimal {
+                sign,
+                layer: 1.0,
+                mag: mag.log10(),
+            };
+        } else if mag.abs() >= EXP_LIMIT {
+            return Decimal {
+                sign,
+                layer: layer + 1.0,
+                mag: mag.signum() * mag.abs().log10(),
+            };
+        }
+
+        while mag.abs() < LAYER_DOWN && layer > 0.0 {
+            layer -= 1.0;
+            mag = if layer == 0.0 {
+                10.0_f64.powf(mag)
+            } else {
+                mag.signum() * 10.0_f64.powf(mag.abs())
+            };
+        }
+
+        if layer == 0.0 && mag == 0.0 {
+            // Moving down a layer can round tiny numbers to zero.
+            return Decimal::ZERO;
+        }
+
+        Decimal { sign, layer, mag }
+    }
+}
+
+/// Returns the sign of the number as -1, 0 or 1.
+fn signum(value: f64) -> i8 {
+    if value > 0.0 {
+        1
+    } else if value < 0.0 {
+        -1
+    } else {
+        0
+    }
+}
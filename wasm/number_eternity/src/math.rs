@@ -0,0 +1,301 @@
+use number_base::BaseNumber;
+
+use crate::{signum, Decimal, MAX_SIGNIFICANT_DIGITS};
+use std::{
+    iter::{Product, Sum},
+    ops::*,
+};
+
+impl Add<Decimal> for Decimal {
+    type Output = Decimal;
+
+    fn add(self, decimal: Decimal) -> Decimal {
+        if self.is_nan() || decimal.is_nan() {
+            return Decimal::NAN;
+        } else if self.is_infinite() || decimal.is_infinite() {
+            // Infinities of opposite signs cancel out into NaN.
+            return if !decimal.is_infinite() {
+                self
+            } else if !self.is_infinite() || self.sign == decimal.sign {
+                decimal
+            } else {
+                Decimal::NAN
+            };
+        } else if self.sign == 0 {
+            return decimal;
+        } else if decimal.sign == 0 {
+            return self;
+        } else if self.sign == -decimal.sign
+            && self.layer == decimal.layer
+            && self.mag == decimal.mag
+        {
+            return Decimal::ZERO;
+        }
+
+        let (bigger, smaller) = if self.cmp_abs(&decimal).is_ge() {
+            (self, decimal)
+        } else {
+            (decimal, self)
+        };
+
+        if bigger.layer == 0.0 && smaller.layer == 0.0 {
+            return Decimal::from(
+                bigger.sign as f64 * bigger.mag + smaller.sign as f64 * smaller.mag,
+            );
+        } else if bigger.layer >= 2.0 || smaller.layer >= 2.0 {
+            // A number on layer 2 dwarfs anything it can be added to.
+            return bigger;
+        }
+
+        // Both numbers are on layer 0 or 1, so their logarithms fit in an f64.
+        let log10 = |decimal: &Decimal| {
+            if decimal.layer == 0.0 {
+                decimal.mag.log10()
+            } else {
+                decimal.mag
+            }
+        };
+        let bigger_log = log10(&bigger);
+        let smaller_log = log10(&smaller);
+
+        if bigger_log - smaller_log > MAX_SIGNIFICANT_DIGITS as f64 {
+            return bigger;
+        }
+
+        let mantissa =
+            bigger.sign as f64 + smaller.sign as f64 * 10.0_f64.powf(smaller_log - bigger_log);
+        Decimal::from_components(signum(mantissa), 1.0, bigger_log + mantissa.abs().log10())
+    }
+}
+
+impl Add<&Decimal> for Decimal {
+    type Output = Decimal;
+
+    fn add(self, decimal: &Decimal) -> Decimal {
+        self + *decimal
+    }
+}
+
+impl Add<Decimal> for &Decimal {
+    type Output = Decimal;
+
+    fn add(self, decimal: Decimal) -> Decimal {
+        *self + decimal
+    }
+}
+
+impl Add<&Decimal> for &Decimal {
+    type Output = Decimal;
+
+    fn add(self, decimal: &Decimal) -> Decimal {
+        *self + *decimal
+    }
+}
+
+impl AddAssign<&Decimal> for Decimal {
+    fn add_assign(&mut self, rhs: &Decimal) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<Decimal> for Decimal {
+    fn add_assign(&mut self, rhs: Decimal) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<&Decimal> for &Decimal {
+    type Output = Decimal;
+
+    fn sub(self, decimal: &Decimal) -> Decimal {
+        *self - *decimal
+    }
+}
+
+impl Sub<&Decimal> for Decimal {
+    type Output = Decimal;
+
+    fn sub(self, decimal: &Decimal) -> Decimal {
+        self - *decimal
+    }
+}
+
+impl Sub<Decimal> for &Decimal {
+    type Output = Decimal;
+
+    fn sub(self, decimal: Decimal) -> Decimal {
+        *self - decimal
+    }
+}
+
+impl Sub<Decimal> for Decimal {
+    type Output = Decimal;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, decimal: Decimal) -> Decimal {
+        self + decimal.neg()
+    }
+}
+
+impl SubAssign<&Decimal> for Decimal {
+    fn sub_assign(&mut self, rhs: &Decimal) {
+        *self = *self - rhs;
+    }
+}
+
+impl SubAssign<Decimal> for Decimal {
+    fn sub_assign(&mut self, rhs: Decimal) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<Decimal> for Decimal {
+    type Output = Decimal;
+
+    fn mul(self, decimal: Decimal) -> Decimal {
+        let sign = self.sign * decimal.sign;
+
+        if self.is_nan() || decimal.is_nan() {
+            return Decimal::NAN;
+        } else if self.is_infinite() || decimal.is_infinite() {
+            return match sign {
+                1 => Decimal::INFINITY,
+                -1 => Decimal::NEG_INFINITY,
+                _ => Decimal::NAN,
+            };
+        } else if sign == 0 {
+            return Decimal::ZERO;
+        } else if self.layer == 0.0 && decimal.layer == 0.0 {
+            return Decimal::from(sign as f64 * self.mag * decimal.mag);
+        }
+
+        // Multiply by adding the logarithms, which are one layer down.
+        let product = (self.abs().log10() + decimal.abs().log10()).pow10();
+        if sign < 0 {
+            -product
+        } else {
+            product
+        }
+    }
+}
+
+impl Mul<&Decimal> for Decimal {
+    type Output = Decimal;
+
+    fn mul(self, decimal: &Decimal) -> Decimal {
+        self * *decimal
+    }
+}
+
+impl Mul<Decimal> for &Decimal {
+    type Output = Decimal;
+
+    fn mul(self, decimal: Decimal) -> Decimal {
+        *self * decimal
+    }
+}
+
+impl Mul<&Decimal> for &Decimal {
+    type Output = Decimal;
+
+    fn mul(self, decimal: &Decimal) -> Decimal {
+        *self * *decimal
+    }
+}
+
+impl MulAssign<&Decimal> for Decimal {
+    fn mul_assign(&mut self, rhs: &Decimal) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<Decimal> for Decimal {
+    fn mul_assign(&mut self, rhs: Decimal) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<Decimal> for Decimal {
+    type Output = Decimal;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, decimal: Decimal) -> Decimal {
+        self * decimal.recip()
+    }
+}
+
+impl Div<&Decimal> for Decimal {
+    type Output = Decimal;
+
+    fn div(self, decimal: &Decimal) -> Decimal {
+        self / *decimal
+    }
+}
+
+impl Div<Decimal> for &Decimal {
+    type Output = Decimal;
+
+    fn div(self, decimal: Decimal) -> Decimal {
+        *self / decimal
+    }
+}
+
+impl Div<&Decimal> for &Decimal {
+    type Output = Decimal;
+
+    fn div(self, decimal: &Decimal) -> Decimal {
+        *self / *decimal
+    }
+}
+
+impl DivAssign<&Decimal> for Decimal {
+    fn div_assign(&mut self, rhs: &Decimal) {
+        *self = *self / rhs;
+    }
+}
+
+impl DivAssign<Decimal> for Decimal {
+    fn div_assign(&mut self, rhs: Decimal) {
+        *self = *self / rhs;
+    }
+}
+
+impl Neg for &Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Decimal {
+        (*self).neg()
+    }
+}
+
+impl Neg for Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Decimal {
+        Decimal::from_components_no_normalize(-self.sign, self.layer, self.mag)
+    }
+}
+
+impl Sum for Decimal {
+    fn sum<I: Iterator<Item = Decimal>>(iter: I) -> Decimal {
+        iter.fold(Decimal::ZERO, |sum, number| sum + number)
+    }
+}
+
+impl<'a> Sum<&'a Decimal> for Decimal {
+    fn sum<I: Iterator<Item = &'a Decimal>>(iter: I) -> Decimal {
+        iter.fold(Decimal::ZERO, |sum, number| sum + number)
+    }
+}
+
+impl Product for Decimal {
+    fn product<I: Iterator<Item = Decimal>>(iter: I) -> Decimal {
+        iter.fold(Decimal::ONE, |product, number| product * number)
+    }
+}
+
+impl<'a> Product<&'a Decimal> for Decimal {
+    fn product<I: Iterator<Item = &'a Decimal>>(iter: I) -> Decimal {
+        iter.fold(Decimal::ONE, |product, number| product * number)
+    }
+}
@@ -0,0 +1,107 @@
+use std::str::FromStr;
+
+use number_base::BaseNumber;
+use number_double_float::Decimal as DoubleFloat;
+
+use crate::{Decimal, ParseDecimalError};
+
+/// Parses a number without a leading sign.
+fn parse_unsigned(string: &str) -> Result<Decimal, ParseDecimalError> {
+    if let Some(rest) = string.strip_prefix("(e^") {
+        let (layer, mag) = rest
+            .split_once(')')
+            .ok_or(ParseDecimalError::InvalidExponent)?;
+        let layer = layer
+            .parse::<f64>()
+            .ok()
+            .filter(|layer| *layer >= 0.0 && layer.fract() == 0.0)
+            .ok_or(ParseDecimalError::InvalidExponent)?;
+        let mag = mag
+            .parse::<f64>()
+            .map_err(|_| ParseDecimalError::InvalidMantissa)?;
+
+        return Ok(Decimal::from_components(1, layer, mag));
+    } else if let Some((base, height)) = string.split_once("^^") {
+        let base = base.parse::<Decimal>()?;
+        let height = height
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| ParseDecimalError::InvalidExponent)?;
+
+        return Ok(base.tetrate(height, &Decimal::ONE));
+    } else if let Some(rest) = string.strip_prefix(['e', 'E']) {
+        return Ok(rest.parse::<Decimal>()?.pow10());
+    }
+
+    let error = match string.parse::<DoubleFloat>() {
+        // Only overflow makes a Decimal infinite, unless it was asked for.
+        Ok(decimal) if !decimal.is_infinite() || string.eq_ignore_ascii_case("Infinity") => {
+            return Ok(Decimal::from(decimal));
+        }
+        Ok(_) => ParseDecimalError::InvalidExponent,
+        Err(error) => error,
+    };
+
+    // The exponent is too large for a double-float Decimal, or is a power tower itself.
+    match string.find(['e', 'E']) {
+        Some(index) => {
+            let mantissa = string[..index]
+                .parse::<DoubleFloat>()
+                .map_err(|_| ParseDecimalError::InvalidMantissa)?;
+            let exponent = string[index + 1..]
+                .parse::<Decimal>()
+                .map_err(|_| ParseDecimalError::InvalidExponent)?;
+
+            Ok(Decimal::from(mantissa) * exponent.pow10())
+        }
+        None => Err(error),
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ParseDecimalError;
+
+    /// Parses a Decimal from a string.
+    ///
+    /// Besides everything [`number_double_float::Decimal`] parses, the string may contain
+    /// exponents of any size (`"1e1e20"`), power towers written with leading `e`s (`"ee20"`,
+    /// `"e-e20"` for `10^-(10^20)`) or as `(e^<layer>)<mag>` (`"(e^7)15.5"`), and tetration
+    /// (`"10^^5"`). Surrounding whitespace is ignored.
+    fn from_str(string: &str) -> Result<Decimal, ParseDecimalError> {
+        let string = string.trim();
+
+        if string.is_empty() {
+            return Err(ParseDecimalError::Empty);
+        }
+
+        // Plain numbers parse straight into an f64, which keeps canonical strings exact.
+        if string
+            .bytes()
+            .all(|byte| byte.is_ascii_digit() || matches!(byte, b'+' | b'-' | b'.' | b'e' | b'E'))
+        {
+            // Numbers which overflow or underflow an f64 are left to the slower path.
+            let zero = !string
+                .split(['e', 'E'])
+                .next()
+                .is_some_and(|mantissa| mantissa.bytes().any(|byte| matches!(byte, b'1'..=b'9')));
+
+            if let Ok(value) = string.parse::<f64>() {
+                if value.is_finite() && (value != 0.0 || zero) {
+                    return Ok(Decimal::from(value));
+                }
+            }
+        }
+
+        let unsigned = string.strip_prefix(['+', '-']).unwrap_or(string);
+        if unsigned.starts_with(['+', '-']) {
+            return Err(ParseDecimalError::InvalidMantissa);
+        }
+
+        let decimal = parse_unsigned(unsigned)?;
+        Ok(if string.starts_with('-') {
+            -decimal
+        } else {
+            decimal
+        })
+    }
+}
@@ -0,0 +1,178 @@
+use number_base::BaseNumber;
+use number_eternity::Decimal;
+
+fn d(value: f64) -> Decimal {
+    Decimal::new(value)
+}
+
+fn e(string: &str) -> Decimal {
+    string.parse().unwrap()
+}
+
+fn assert_close(actual: Decimal, expected: Decimal) {
+    let difference = ((actual - expected) / expected).abs();
+    assert!(
+        difference < d(1e-9) || actual == expected,
+        "{} is not close to {}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn normalizes_across_layers() {
+    let big = d(1e20);
+    assert_eq!((big.layer(), big.mag()), (1.0, 20.0));
+
+    let small = d(1e-20);
+    assert_eq!((small.layer(), small.mag()), (1.0, -20.0));
+
+    let huge = e("1e1e20");
+    assert_eq!((huge.layer(), huge.mag()), (2.0, 20.0));
+
+    assert_eq!(d(12345.0).layer(), 0.0);
+    assert_eq!(Decimal::from_components(1, 2.0, 1.0), d(1e10));
+}
+
+#[test]
+fn matches_f64_arithmetic_on_layer_zero() {
+    assert_eq!(d(1.5) + d(2.25), d(3.75));
+    assert_eq!(d(1.5) - d(2.25), d(-0.75));
+    assert_eq!(d(1.5) * d(-4.0), d(-6.0));
+    assert_eq!(d(9.0) / d(4.0), d(2.25));
+    assert_eq!(d(2.0).pow(&d(10.0)), d(1024.0));
+    assert_eq!(d(-2.0).pow(&d(3.0)), d(-8.0));
+    assert_eq!(d(16.0).sqrt(), d(4.0));
+    assert_eq!(d(-27.0).cbrt(), d(-3.0));
+    assert_eq!(d(-2.5).floor(), d(-3.0));
+    assert_eq!(d(1000.0).log10(), d(3.0));
+}
+
+#[test]
+fn carries_arithmetic_across_layers() {
+    assert_close(d(1e15) * d(1e15), d(1e30));
+    assert_close(d(1e300) * d(1e300), e("1e600"));
+    assert_close(e("1e600") / d(1e300), d(1e300));
+    assert_close(e("1e600") + e("1e600"), e("2e600"));
+    assert_close(e("3e600") - e("1e600"), e("2e600"));
+    assert_close(d(1e20) + d(1e19), d(1.1e20));
+    assert_close(d(1e-20) * d(1e20), d(1.0));
+    assert_close(e("1e1000").sqrt(), e("1e500"));
+    assert_close(e("1e1e20").log10(), d(1e20));
+    assert_close(d(10.0).pow(&d(1e20)), e("1e1e20"));
+}
+
+#[test]
+fn compares_across_layers() {
+    let values = [
+        Decimal::NEG_INFINITY,
+        -e("ee20"),
+        -d(1e300),
+        d(-1.0),
+        d(-1e-300),
+        Decimal::ZERO,
+        e("e-e20"),
+        d(1e-300),
+        d(1.0),
+        d(1e300),
+        e("ee20"),
+        e("(e^10)20"),
+        Decimal::INFINITY,
+    ];
+
+    for window in values.windows(2) {
+        assert!(window[0] < window[1], "{} < {}", window[0], window[1]);
+    }
+}
+
+#[test]
+fn special_values_follow_ieee() {
+    assert!((Decimal::INFINITY - Decimal::INFINITY).is_nan());
+    assert!((Decimal::ZERO * Decimal::INFINITY).is_nan());
+    assert!((Decimal::ZERO / Decimal::ZERO).is_nan());
+    assert!(d(-4.0).sqrt().is_nan());
+    assert_ne!(Decimal::NAN, Decimal::NAN);
+    assert_eq!(d(2.0) / Decimal::ZERO, Decimal::INFINITY);
+    assert_eq!(d(-2.0) / Decimal::ZERO, Decimal::NEG_INFINITY);
+    assert_eq!(Decimal::INFINITY + e("ee300"), Decimal::INFINITY);
+    assert_eq!(d(5.0) + Decimal::INFINITY, Decimal::INFINITY);
+    assert_eq!(e("ee300") - Decimal::NEG_INFINITY, Decimal::INFINITY);
+    assert_eq!(d(-5.0) + Decimal::NEG_INFINITY, Decimal::NEG_INFINITY);
+    assert_eq!(d(5.0) + Decimal::NEG_INFINITY, Decimal::NEG_INFINITY);
+    assert_eq!(Decimal::ZERO.sign(), 0);
+    assert_eq!(-Decimal::ZERO, Decimal::ZERO);
+}
+
+#[test]
+fn converts_to_and_from_double_float() {
+    type DoubleFloat = number_double_float::Decimal;
+
+    for string in ["0", "1.5", "-2.5e-7", "3e300", "4.5e123456", "-1e-9000000"] {
+        let double_float: DoubleFloat = string.parse().unwrap();
+        let eternity = Decimal::from(double_float);
+        assert_close(eternity, e(string));
+
+        let back = DoubleFloat::from(eternity);
+        assert!(
+            ((back - double_float) / double_float).abs() < DoubleFloat::new(1e-9)
+                || back == double_float,
+            "{} round-tripped into {}",
+            double_float,
+            back
+        );
+    }
+
+    assert_eq!(DoubleFloat::from(e("ee400")), DoubleFloat::INFINITY);
+    assert_eq!(DoubleFloat::from(-e("ee400")), DoubleFloat::NEG_INFINITY);
+    assert_eq!(DoubleFloat::from(e("e-e400")), DoubleFloat::ZERO);
+    assert!(e("ee400").to_double_float().is_none());
+    assert!(Decimal::from(DoubleFloat::NAN).is_nan());
+}
+
+#[test]
+fn parses_and_formats_power_towers() {
+    assert_eq!(d(1500.0).to_string(), "1500");
+    assert_eq!(
+        e("1.5e400").to_string(),
+        e("1.5e400").to_double_float().unwrap().to_string()
+    );
+    assert_eq!(e("ee400").to_string(), "ee400");
+    assert_eq!(e("-eee400").to_string(), "-eee400");
+    assert_eq!(e("e-e400").to_string(), "e-e400");
+    assert_eq!(e("(e^9)123.5").to_string(), "(e^9)123.5");
+    assert_eq!(e("(e^9)123.5").to_exponential(2), "(e^9)123.50");
+    assert_eq!(e("1e1e400"), e("ee400"));
+    assert_eq!(e("10^^2"), d(1e10));
+    assert_eq!(e("+1.5K"), d(1500.0));
+    assert!(e("NaN").is_nan());
+    assert_eq!(e("-Infinity"), Decimal::NEG_INFINITY);
+    assert!("--1".parse::<Decimal>().is_err());
+    assert!("ee".parse::<Decimal>().is_err());
+    assert!("1e1ex".parse::<Decimal>().is_err());
+}
+
+#[test]
+fn canonical_strings_round_trip() {
+    for decimal in [
+        d(0.0),
+        d(1.0 / 3.0),
+        d(-123456.789),
+        d(1e-300),
+        e("1.2345678901234567e1234567"),
+        e("e-e123.456"),
+        -e("(e^12)34.567"),
+        Decimal::INFINITY,
+        Decimal::NEG_INFINITY,
+    ] {
+        let string = decimal.to_canonical_string();
+        let parsed = string.parse::<Decimal>().unwrap();
+        assert!(
+            parsed.layer() == decimal.layer()
+                && parsed.mag() == decimal.mag()
+                && parsed.sign() == decimal.sign(),
+            "{} parsed back into {:?}",
+            string,
+            parsed
+        );
+    }
+}
@@ -0,0 +1,3 @@
+use number_eternity::Decimal;
+
+number_base::conformance_tests!(Decimal);
@@ -0,0 +1,99 @@
+use number_base::BaseNumber;
+use number_eternity::Decimal;
+
+fn d(value: f64) -> Decimal {
+    Decimal::new(value)
+}
+
+fn assert_close(actual: Decimal, expected: Decimal) {
+    let difference = ((actual - expected) / expected).abs();
+    assert!(
+        difference < d(1e-9),
+        "{} is not close to {}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn tetrates_integer_heights() {
+    assert_eq!(d(2.0).tetrate(3.0, &Decimal::ONE), d(16.0));
+    assert_eq!(d(2.0).tetrate(4.0, &Decimal::ONE), d(65536.0));
+    assert_eq!(d(3.0).tetrate(0.0, &Decimal::ONE), Decimal::ONE);
+    assert_eq!(d(3.0).tetrate(1.0, &Decimal::ONE), d(3.0));
+    assert_close(d(10.0).tetrate(2.0, &Decimal::ONE), d(1e10));
+
+    let tower = Decimal::TEN.tetrate(10.0, &Decimal::ONE);
+    assert_eq!(tower.layer(), 8.0);
+    assert_eq!(tower.mag(), 1e10);
+
+    let tall = Decimal::TEN.tetrate(1e6, &Decimal::ONE);
+    assert!(tall.is_finite());
+    assert!(tall > tower);
+}
+
+#[test]
+fn tetrates_special_bases() {
+    assert_eq!(Decimal::ONE.tetrate(100.0, &Decimal::ONE), Decimal::ONE);
+    assert_eq!(Decimal::ZERO.tetrate(3.0, &Decimal::ONE), Decimal::ZERO);
+    assert_eq!(Decimal::ZERO.tetrate(4.0, &Decimal::ONE), Decimal::ONE);
+    assert_close(
+        d(2.0_f64.sqrt()).tetrate(f64::INFINITY, &Decimal::ONE),
+        d(2.0),
+    );
+    assert_close(
+        d(1.2).tetrate(10000.0, &Decimal::ONE),
+        d(1.2).tetrate(f64::INFINITY, &Decimal::ONE),
+    );
+    assert_eq!(
+        d(2.0).tetrate(f64::INFINITY, &Decimal::ONE),
+        Decimal::INFINITY
+    );
+    assert!(d(0.01).tetrate(f64::INFINITY, &Decimal::ONE).is_nan());
+}
+
+#[test]
+fn slog_inverts_tetration() {
+    for base in [2.0, 3.5, 10.0] {
+        for height in [0.25, 1.0, 2.5, 3.75, 5.0, 20.5] {
+            let tower = d(base).tetrate(height, &Decimal::ONE);
+            let slog = tower.slog(&d(base)).to_number();
+            assert!(
+                (slog - height).abs() < 1e-9,
+                "slog of {}^^{} is {}",
+                base,
+                height,
+                slog
+            );
+        }
+    }
+
+    assert_eq!(Decimal::ONE.slog(&Decimal::TEN), Decimal::ZERO);
+    assert_eq!(Decimal::ZERO.slog(&Decimal::TEN), -Decimal::ONE);
+    assert!(d(5.0).slog(&Decimal::ONE).is_nan());
+}
+
+#[test]
+fn iterated_log_undoes_tetration() {
+    let tower = Decimal::TEN.tetrate(6.0, &Decimal::ONE);
+    assert_close(tower.iterated_log(&Decimal::TEN, 4.0), d(1e10));
+    assert_close(d(1e10).iterated_log(&Decimal::TEN, 2.0), d(1.0));
+    assert_close(
+        d(1e10).iterated_log(&Decimal::TEN, -1.0),
+        Decimal::TEN.pow(&d(1e10)),
+    );
+
+    let deep = Decimal::TEN.tetrate(1000.0, &d(5.0));
+    assert_close(deep.iterated_log(&Decimal::TEN, 1000.0), d(5.0));
+}
+
+#[test]
+fn super_root_inverts_tetration() {
+    assert_close(d(27.0).tetrate(1.0, &Decimal::ONE).super_root(1.0), d(27.0));
+    assert_close(d(256.0).super_root(2.0), d(4.0));
+    assert_close(d(16.0).super_root(3.0), d(2.0));
+    assert_close(d(3.0).tetrate(4.0, &Decimal::ONE).super_root(4.0), d(3.0));
+
+    assert_eq!(Decimal::ONE.super_root(5.0), Decimal::ONE);
+    assert!(d(0.5).super_root(2.0).is_nan());
+}
@@ -0,0 +1,108 @@
+use num_bigint::BigInt;
+
+use number_base::BaseNumber;
+use number_double_float::Decimal;
+
+use crate::ExactDecimal;
+
+macro_rules! impl_from_int {
+    ($t:ty) => {
+        impl From<$t> for ExactDecimal {
+            fn from(value: $t) -> Self {
+                ExactDecimal::from_coefficient_exponent(BigInt::from(value), 0)
+            }
+        }
+    };
+}
+
+impl_from_int!(i8);
+impl_from_int!(i16);
+impl_from_int!(i32);
+impl_from_int!(i64);
+impl_from_int!(i128);
+impl_from_int!(isize);
+impl_from_int!(u8);
+impl_from_int!(u16);
+impl_from_int!(u32);
+impl_from_int!(u64);
+impl_from_int!(u128);
+impl_from_int!(usize);
+
+impl From<BigInt> for ExactDecimal {
+    fn from(value: BigInt) -> Self {
+        ExactDecimal::from_coefficient_exponent(value, 0)
+    }
+}
+
+macro_rules! impl_from_float {
+    ($t:ty) => {
+        impl From<$t> for ExactDecimal {
+            /// Converts the float into the shortest decimal which rounds back to it, so `0.1`
+            /// becomes exactly 0.1 rather than the binary fraction closest to it.
+            fn from(value: $t) -> Self {
+                if value.is_nan() {
+                    ExactDecimal::NAN
+                } else if value == <$t>::INFINITY {
+                    ExactDecimal::INFINITY
+                } else if value == <$t>::NEG_INFINITY {
+                    ExactDecimal::NEG_INFINITY
+                } else {
+                    format!("{:e}", value).parse().unwrap_or(ExactDecimal::NAN)
+                }
+            }
+        }
+    };
+}
+
+impl_from_float!(f32);
+impl_from_float!(f64);
+
+impl From<String> for ExactDecimal {
+    fn from(string: String) -> Self {
+        string.parse().unwrap_or(ExactDecimal::NAN)
+    }
+}
+
+impl From<ExactDecimal> for String {
+    fn from(decimal: ExactDecimal) -> String {
+        decimal.to_string()
+    }
+}
+
+impl From<Decimal> for ExactDecimal {
+    /// Converts the Decimal into the decimal its canonical string denotes, which is the
+    /// shortest one that rounds back to it. Decimals above `10^MAX_EXPONENT` become
+    /// ±Infinity.
+    fn from(decimal: Decimal) -> ExactDecimal {
+        decimal
+            .to_canonical_string()
+            .parse()
+            .unwrap_or(ExactDecimal::NAN)
+    }
+}
+
+impl From<ExactDecimal> for Decimal {
+    /// Rounds the number to the closest Decimal.
+    fn from(decimal: ExactDecimal) -> Decimal {
+        Decimal::from(&decimal)
+    }
+}
+
+impl From<&ExactDecimal> for Decimal {
+    /// Rounds the number to the closest Decimal.
+    fn from(decimal: &ExactDecimal) -> Decimal {
+        match (
+            decimal.to_coefficient_exponent(),
+            decimal.adjusted_exponent(),
+        ) {
+            (Some((coefficient, exponent)), Some(adjusted)) => {
+                // The mantissa is parsed from every digit, so it is rounded only once.
+                let mantissa =
+                    ExactDecimal::from_coefficient_exponent(coefficient, exponent - adjusted);
+                Decimal::from_mantissa_exponent(mantissa.to_number(), adjusted as f64)
+            }
+            (Some(_), None) => Decimal::ZERO,
+            _ => Decimal::new(decimal.to_number()),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use std::fmt::{self, Display, Formatter};
+
+use num_traits::Signed;
+
+use number_base::BaseNumber;
+
+use crate::{round::Rounding, ExactDecimal};
+
+/// Numbers whose most significant digit is within this many powers of ten of the decimal
+/// point are displayed without an exponent, like [`number_double_float::Decimal`] does.
+const PLAIN_RANGE: std::ops::Range<i64> = -6..21;
+
+impl ExactDecimal {
+    /// Returns the sign, the digits of the coefficient and the exponent of a finite number.
+    fn digits(&self) -> Option<(bool, String, i64)> {
+        self.to_coefficient_exponent()
+            .map(|(coefficient, exponent)| {
+                (
+                    coefficient.is_negative(),
+                    coefficient.magnitude().to_string(),
+                    exponent,
+                )
+            })
+    }
+
+    /// Writes the exact value without an exponent, with at least `places` decimal places.
+    fn plain(&self, places: u32) -> String {
+        let Some((negative, digits, exponent)) = self.digits() else {
+            return self.to_string();
+        };
+        let sign = if negative { "-" } else { "" };
+
+        let (integer, fraction) = if exponent >= 0 {
+            (digits + &"0".repeat(exponent as usize), String::new())
+        } else {
+            let fraction_length = -exponent as usize;
+            let padded = format!("{:0>width$}", digits, width = fraction_length + 1);
+            let (integer, fraction) = padded.split_at(padded.len() - fraction_length);
+            (integer.to_string(), fraction.to_string())
+        };
+
+        let fraction = format!("{:0<width$}", fraction, width = places as usize);
+        if fraction.is_empty() {
+            format!("{}{}", sign, integer)
+        } else {
+            format!("{}{}.{}", sign, integer, fraction)
+        }
+    }
+
+    /// Writes the exact value as `d.ddde+N`, with at least `places` digits after the point.
+    fn scientific(&self, places: u32) -> String {
+        let Some((negative, digits, exponent)) = self.digits() else {
+            return self.to_string();
+        };
+        let sign = if negative { "-" } else { "" };
+        let adjusted = exponent + digits.len() as i64 - 1;
+        let exponent_sign = if adjusted < 0 { "-" } else { "+" };
+
+        let (first, rest) = digits.split_at(1);
+        let rest = format!("{:0<width$}", rest, width = places as usize);
+        if rest.is_empty() {
+            format!("{}{}e{}{}", sign, first, exponent_sign, adjusted.abs())
+        } else {
+            format!(
+                "{}{}.{}e{}{}",
+                sign,
+                first,
+                rest,
+                exponent_sign,
+                adjusted.abs()
+            )
+        }
+    }
+
+    /// Writes a sign for negative numbers which rounded to zero, as JavaScript does.
+    fn with_sign_of(&self, rounded: &ExactDecimal, string: String) -> String {
+        if self.sign() < 0 && rounded.sign() == 0 {
+            format!("-{}", string)
+        } else {
+            string
+        }
+    }
+
+    pub(crate) fn format_fixed(&self, places: u32) -> String {
+        if !self.is_finite() {
+            return self.to_string();
+        }
+
+        let rounded = self.rounded_to_exponent(-(places as i64), Rounding::HalfAwayFromZero);
+        self.with_sign_of(&rounded, rounded.plain(places))
+    }
+
+    pub(crate) fn format_exponential(&self, places: u32) -> String {
+        if !self.is_finite() {
+            return self.to_string();
+        } else if self.sign() == 0 {
+            return ExactDecimal::ZERO.scientific(places);
+        }
+
+        self.rounded_to_significant(places + 1, Rounding::HalfAwayFromZero)
+            .scientific(places)
+    }
+
+    pub(crate) fn format_precision(&self, places: u32) -> String {
+        let places = places.max(1);
+        let rounded = self.rounded_to_significant(places, Rounding::HalfAwayFromZero);
+
+        match rounded.adjusted_exponent() {
+            Some(adjusted) if adjusted < -6 || adjusted >= places as i64 => {
+                self.format_exponential(places - 1)
+            }
+            Some(adjusted) => self.format_fixed((places as i64 - 1 - adjusted) as u32),
+            None => self.format_fixed(places - 1),
+        }
+    }
+}
+
+impl Display for ExactDecimal {
+    /// Writes every digit of the number, in scientific notation if it is very large or very
+    /// small. A precision rounds to that many decimal places, or to that many places after
+    /// the first digit in scientific notation.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_nan() {
+            return write!(f, "NaN");
+        } else if self.is_infinite() {
+            return write!(f, "{}Infinity", if self.sign() < 0 { "-" } else { "" });
+        }
+
+        let plain = PLAIN_RANGE.contains(&self.adjusted_exponent().unwrap_or(0));
+        match (f.precision(), plain) {
+            (Some(places), true) => write!(f, "{}", self.format_fixed(places as u32)),
+            (Some(places), false) => write!(f, "{}", self.format_exponential(places as u32)),
+            (None, true) => write!(f, "{}", self.plain(0)),
+            (None, false) => write!(f, "{}", self.scientific(0)),
+        }
+    }
+}
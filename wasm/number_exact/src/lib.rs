@@ -0,0 +1,359 @@
+mod convert;
+mod format;
+mod math;
+mod parse;
+mod round;
+mod transcendental;
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Signed, Zero};
+
+pub use number_base::{ArithmeticPolicy, DecimalError, ParseDecimalError};
+
+use number_base::{BaseNumber, RoundingMode};
+
+use crate::round::{div_rounded, pow10, Rounding};
+
+/// The number of significant digits that results which can not be represented exactly are
+/// rounded to.
+pub const PRECISION: u32 = 50;
+
+/// The largest power of ten a number can reach before it overflows to ±Infinity. Numbers
+/// with digits below `10^-MAX_EXPONENT` are rounded to that digit.
+pub const MAX_EXPONENT: i64 = 100_000;
+
+/// An arbitrary-precision decimal number, for currencies which must never lose a unit.
+///
+/// A finite number is an integer coefficient of any size times a power of ten. Addition,
+/// subtraction, multiplication and rounding to integers are always exact, as is division
+/// whenever the quotient has a finite decimal expansion (`1 / 8` is exactly `0.125`).
+///
+/// Everything else is rounded to [`PRECISION`] significant digits, half to even: quotients
+/// such as `1 / 3`, square and cube roots, logarithms and powers. Roots, logarithms and
+/// integer powers stay exact when the result is representable, so `sqrt(0.25)` is `0.5` and
+/// `log10(1000)` is `3`.
+///
+/// # Special values
+///
+/// Like [`number_double_float::Decimal`] it has NaN, ±Infinity and a single, unsigned zero,
+/// and numbers above `10^MAX_EXPONENT` overflow to ±Infinity.
+#[derive(Clone, Debug)]
+pub struct ExactDecimal(Repr);
+
+#[derive(Clone, Debug)]
+enum Repr {
+    NaN,
+    Infinity {
+        negative: bool,
+    },
+    /// `coefficient * 10^exponent`, where the coefficient has no trailing zeros and zero has
+    /// an exponent of 0.
+    Finite {
+        coefficient: Coefficient,
+        exponent: i64,
+    },
+}
+
+/// The coefficient of a finite number. Coefficients which fit in an i64 are stored inline,
+/// which also lets constants be created without allocating.
+#[derive(Clone, Debug)]
+enum Coefficient {
+    Small(i64),
+    Big(BigInt),
+}
+
+impl Coefficient {
+    fn new(value: BigInt) -> Coefficient {
+        match i64::try_from(&value) {
+            Ok(value) => Coefficient::Small(value),
+            Err(_) => Coefficient::Big(value),
+        }
+    }
+
+    fn to_big(&self) -> BigInt {
+        match self {
+            Coefficient::Small(value) => BigInt::from(*value),
+            Coefficient::Big(value) => value.clone(),
+        }
+    }
+}
+
+/// Returns the number of decimal digits of the integer, or 0 for zero.
+pub(crate) fn digit_count(value: &BigInt) -> i64 {
+    if value.is_zero() {
+        0
+    } else {
+        value.magnitude().to_string().len() as i64
+    }
+}
+
+impl ExactDecimal {
+    pub const NAN: ExactDecimal = ExactDecimal(Repr::NaN);
+    pub const INFINITY: ExactDecimal = ExactDecimal(Repr::Infinity { negative: false });
+    pub const NEG_INFINITY: ExactDecimal = ExactDecimal(Repr::Infinity { negative: true });
+    pub const ZERO: ExactDecimal = ExactDecimal(Repr::Finite {
+        coefficient: Coefficient::Small(0),
+        exponent: 0,
+    });
+    pub const ONE: ExactDecimal = ExactDecimal(Repr::Finite {
+        coefficient: Coefficient::Small(1),
+        exponent: 0,
+    });
+
+    /// Creates the number `coefficient * 10^exponent`, e.g. `(12345, -2)` for 123.45.
+    ///
+    /// The number overflows to ±Infinity above `10^MAX_EXPONENT`, and digits below
+    /// `10^-MAX_EXPONENT` are rounded half to even.
+    pub fn from_coefficient_exponent(coefficient: impl Into<BigInt>, exponent: i64) -> Self {
+        let mut coefficient = coefficient.into();
+        let mut exponent = exponent;
+
+        if exponent.saturating_add(digit_count(&coefficient)) < -MAX_EXPONENT {
+            // Less than half of the smallest digit.
+            return ExactDecimal::ZERO;
+        } else if exponent < -MAX_EXPONENT {
+            coefficient = div_rounded(
+                &coefficient,
+                &pow10((-MAX_EXPONENT - exponent) as u64),
+                Rounding::HalfEven,
+            );
+            exponent = -MAX_EXPONENT;
+        }
+
+        if coefficient.is_zero() {
+            return ExactDecimal::ZERO;
+        }
+
+        let ten = BigInt::from(10);
+        loop {
+            let (quotient, remainder) = coefficient.div_rem(&ten);
+            if !remainder.is_zero() {
+                break;
+            }
+            coefficient = quotient;
+            exponent += 1;
+        }
+
+        if exponent.saturating_add(digit_count(&coefficient) - 1) > MAX_EXPONENT {
+            return if coefficient.is_negative() {
+                ExactDecimal::NEG_INFINITY
+            } else {
+                ExactDecimal::INFINITY
+            };
+        }
+
+        ExactDecimal(Repr::Finite {
+            coefficient: Coefficient::new(coefficient),
+            exponent,
+        })
+    }
+
+    /// Returns the coefficient and exponent of a finite number, with no trailing zeros in the
+    /// coefficient, or `None` for NaN and ±Infinity.
+    pub fn to_coefficient_exponent(&self) -> Option<(BigInt, i64)> {
+        match &self.0 {
+            Repr::Finite {
+                coefficient,
+                exponent,
+            } => Some((coefficient.to_big(), *exponent)),
+            _ => None,
+        }
+    }
+
+    /// Returns if the number is NaN.
+    pub fn is_nan(&self) -> bool {
+        matches!(self.0, Repr::NaN)
+    }
+
+    /// Returns if the number is positive or negative infinity.
+    pub fn is_infinite(&self) -> bool {
+        matches!(self.0, Repr::Infinity { .. })
+    }
+
+    /// Returns if the number is neither NaN nor infinite.
+    pub fn is_finite(&self) -> bool {
+        matches!(self.0, Repr::Finite { .. })
+    }
+
+    /// Returns if the number is finite and has no fractional part.
+    pub fn is_integer(&self) -> bool {
+        matches!(self.0, Repr::Finite { exponent, .. } if exponent >= 0)
+    }
+
+    /// Returns the power of ten of the most significant digit of a finite, nonzero number.
+    pub(crate) fn adjusted_exponent(&self) -> Option<i64> {
+        match self.to_coefficient_exponent() {
+            Some((coefficient, exponent)) if !coefficient.is_zero() => {
+                Some(exponent + digit_count(&coefficient) - 1)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Default for ExactDecimal {
+    fn default() -> Self {
+        ExactDecimal::ZERO
+    }
+}
+
+impl BaseNumber for ExactDecimal {
+    const ZERO: ExactDecimal = ExactDecimal::ZERO;
+    const ONE: ExactDecimal = ExactDecimal::ONE;
+    const NAN: ExactDecimal = ExactDecimal::NAN;
+    const INFINITY: ExactDecimal = ExactDecimal::INFINITY;
+    const NEG_INFINITY: ExactDecimal = ExactDecimal::NEG_INFINITY;
+
+    /// Returns the closest f64, which is ±Infinity or zero outside of its range.
+    fn to_number(&self) -> f64 {
+        match &self.0 {
+            Repr::NaN => f64::NAN,
+            Repr::Infinity { negative: false } => f64::INFINITY,
+            Repr::Infinity { negative: true } => f64::NEG_INFINITY,
+            Repr::Finite {
+                coefficient,
+                exponent,
+            } => format!("{}e{}", coefficient.to_big(), exponent)
+                .parse()
+                .unwrap_or(f64::NAN),
+        }
+    }
+
+    /// Writes the exact coefficient and exponent, e.g. `12345e-2` for 123.45. Integers have
+    /// no exponent, zero is `0`, and the special values are `NaN`, `Infinity` and
+    /// `-Infinity`.
+    fn to_canonical_string(&self) -> String {
+        match self.to_coefficient_exponent() {
+            Some((coefficient, 0)) => coefficient.to_string(),
+            Some((coefficient, exponent)) => format!("{}e{}", coefficient, exponent),
+            None => self.to_string(),
+        }
+    }
+
+    fn to_exponential(&self, places: u32) -> String {
+        self.format_exponential(places)
+    }
+
+    fn to_fixed(&self, places: u32) -> String {
+        self.format_fixed(places)
+    }
+
+    fn to_precision(&self, places: u32) -> String {
+        self.format_precision(places)
+    }
+
+    fn abs(&self) -> ExactDecimal {
+        if self.sign() < 0 {
+            -self
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Rounds to the nearest integer, half away from zero.
+    fn round(&self) -> ExactDecimal {
+        self.rounded_to_exponent(0, Rounding::HalfAwayFromZero)
+    }
+
+    fn trunc(&self) -> ExactDecimal {
+        self.rounded_to_exponent(0, Rounding::TowardZero)
+    }
+
+    fn floor(&self) -> ExactDecimal {
+        self.rounded_to_exponent(0, Rounding::Floor)
+    }
+
+    fn ceil(&self) -> ExactDecimal {
+        self.rounded_to_exponent(0, Rounding::Ceiling)
+    }
+
+    fn sqrt(&self) -> ExactDecimal {
+        self.nth_root(2)
+    }
+
+    fn recip(&self) -> ExactDecimal {
+        ExactDecimal::ONE / self
+    }
+
+    fn cbrt(&self) -> ExactDecimal {
+        self.nth_root(3)
+    }
+
+    fn ln(&self) -> ExactDecimal {
+        self.logarithm(None)
+    }
+
+    fn log10(&self) -> ExactDecimal {
+        self.logarithm(Some(10))
+    }
+
+    fn log2(&self) -> ExactDecimal {
+        self.logarithm(Some(2))
+    }
+
+    fn pow(&self, exponent: &ExactDecimal) -> ExactDecimal {
+        self.power(exponent)
+    }
+
+    fn sign(&self) -> i32 {
+        match &self.0 {
+            Repr::NaN => 0,
+            Repr::Infinity { negative } => {
+                if *negative {
+                    -1
+                } else {
+                    1
+                }
+            }
+            Repr::Finite { coefficient, .. } => match coefficient {
+                Coefficient::Small(value) => value.signum() as i32,
+                Coefficient::Big(value) => {
+                    if value.is_negative() {
+                        -1
+                    } else {
+                        1
+                    }
+                }
+            },
+        }
+    }
+
+    fn round_to(&self, places: i64, mode: RoundingMode) -> ExactDecimal {
+        self.rounded_to_exponent(places.saturating_neg(), mode.into())
+    }
+
+    fn round_to_significant(&self, digits: u32, mode: RoundingMode) -> ExactDecimal {
+        self.rounded_to_significant(digits.max(1), mode.into())
+    }
+
+    fn exp(&self) -> ExactDecimal {
+        self.exponential()
+    }
+
+    fn log(&self, base: &ExactDecimal) -> ExactDecimal {
+        match base.to_coefficient_exponent() {
+            Some((coefficient, 1)) if coefficient.is_one() => self.logarithm(Some(10)),
+            Some((coefficient, 0)) if coefficient == BigInt::from(2) => self.logarithm(Some(2)),
+            _ => self.logarithm(None) / base.logarithm(None),
+        }
+    }
+
+    fn root(&self, degree: &ExactDecimal) -> ExactDecimal {
+        match degree.to_coefficient_exponent() {
+            Some((coefficient, 0)) if coefficient.is_negative() => {
+                self.root(&-degree.clone()).recip()
+            }
+            Some((coefficient, 0)) => match u32::try_from(&coefficient) {
+                Ok(degree) if degree > 0 => self.nth_root(degree),
+                _ => self.power(&degree.recip()),
+            },
+            _ => self.power(&degree.recip()),
+        }
+    }
+
+    fn factorial(&self) -> ExactDecimal {
+        self.exact_factorial()
+            .unwrap_or_else(|| (self.clone() + ExactDecimal::ONE).gamma())
+    }
+}
@@ -0,0 +1,307 @@
+use std::{
+    cmp::Ordering,
+    iter::{Product, Sum},
+    ops::*,
+};
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Signed, Zero};
+
+use number_base::BaseNumber;
+
+use crate::{digit_count, round::pow10, ExactDecimal, Repr, PRECISION};
+
+/// Brings two coefficients to the smaller of their exponents, so that they can be added or
+/// compared.
+fn align(left: (BigInt, i64), right: (BigInt, i64)) -> (BigInt, BigInt, i64) {
+    let exponent = left.1.min(right.1);
+    (
+        left.0 * pow10((left.1 - exponent) as u64),
+        right.0 * pow10((right.1 - exponent) as u64),
+        exponent,
+    )
+}
+
+/// Removes every factor of `prime` from the value and returns how many there were.
+fn remove_factor(value: &mut BigInt, prime: u32) -> u64 {
+    let prime = BigInt::from(prime);
+    let mut count = 0;
+
+    loop {
+        let (quotient, remainder) = value.div_rem(&prime);
+        if !remainder.is_zero() {
+            return count;
+        }
+        *value = quotient;
+        count += 1;
+    }
+}
+
+impl Add<&ExactDecimal> for &ExactDecimal {
+    type Output = ExactDecimal;
+
+    fn add(self, decimal: &ExactDecimal) -> ExactDecimal {
+        match (&self.0, &decimal.0) {
+            (Repr::NaN, _) | (_, Repr::NaN) => ExactDecimal::NAN,
+            // Infinities of opposite signs cancel out into NaN.
+            (Repr::Infinity { negative: left }, Repr::Infinity { negative: right }) => {
+                if left == right {
+                    self.clone()
+                } else {
+                    ExactDecimal::NAN
+                }
+            }
+            (Repr::Infinity { .. }, _) => self.clone(),
+            (_, Repr::Infinity { .. }) => decimal.clone(),
+            _ => {
+                let (left, right) = match (
+                    self.to_coefficient_exponent(),
+                    decimal.to_coefficient_exponent(),
+                ) {
+                    (Some(left), Some(right)) => (left, right),
+                    _ => unreachable!("both numbers are finite"),
+                };
+                let (left, right, exponent) = align(left, right);
+                ExactDecimal::from_coefficient_exponent(left + right, exponent)
+            }
+        }
+    }
+}
+
+impl Mul<&ExactDecimal> for &ExactDecimal {
+    type Output = ExactDecimal;
+
+    fn mul(self, decimal: &ExactDecimal) -> ExactDecimal {
+        let sign = self.sign() * decimal.sign();
+
+        if self.is_nan() || decimal.is_nan() {
+            return ExactDecimal::NAN;
+        } else if self.is_infinite() || decimal.is_infinite() {
+            return match sign {
+                1 => ExactDecimal::INFINITY,
+                -1 => ExactDecimal::NEG_INFINITY,
+                _ => ExactDecimal::NAN,
+            };
+        }
+
+        match (
+            self.to_coefficient_exponent(),
+            decimal.to_coefficient_exponent(),
+        ) {
+            (Some((left, left_exponent)), Some((right, right_exponent))) => {
+                ExactDecimal::from_coefficient_exponent(
+                    left * right,
+                    left_exponent + right_exponent,
+                )
+            }
+            _ => unreachable!("both numbers are finite"),
+        }
+    }
+}
+
+impl Div<&ExactDecimal> for &ExactDecimal {
+    type Output = ExactDecimal;
+
+    /// Divides exactly if the quotient has a finite decimal expansion, and rounds it to
+    /// [`PRECISION`] significant digits otherwise.
+    fn div(self, decimal: &ExactDecimal) -> ExactDecimal {
+        let sign = self.sign() * decimal.sign();
+
+        if self.is_nan() || decimal.is_nan() || (self.is_infinite() && decimal.is_infinite()) {
+            return ExactDecimal::NAN;
+        } else if self.is_infinite() || (decimal.sign() == 0 && self.sign() != 0) {
+            // Zero is unsigned, so dividing by it keeps the sign of the dividend.
+            return if sign < 0 || (decimal.sign() == 0 && self.sign() < 0) {
+                ExactDecimal::NEG_INFINITY
+            } else {
+                ExactDecimal::INFINITY
+            };
+        } else if decimal.is_infinite() {
+            return ExactDecimal::ZERO;
+        } else if decimal.sign() == 0 {
+            return ExactDecimal::NAN;
+        }
+
+        let ((numerator, numerator_exponent), (denominator, denominator_exponent)) = match (
+            self.to_coefficient_exponent(),
+            decimal.to_coefficient_exponent(),
+        ) {
+            (Some(left), Some(right)) => (left, right),
+            _ => unreachable!("both numbers are finite"),
+        };
+        let exponent = numerator_exponent - denominator_exponent;
+
+        // The quotient terminates if the reduced denominator only has the factors 2 and 5,
+        // in which case scaling it up to a power of ten makes the division exact.
+        let divisor = numerator.gcd(&denominator);
+        let mut reduced = &denominator / &divisor;
+        let twos = remove_factor(&mut reduced, 2);
+        let fives = remove_factor(&mut reduced, 5);
+
+        if reduced.abs().is_one() {
+            let shift = twos.max(fives);
+            let coefficient = &numerator / &divisor
+                * num_traits::pow(BigInt::from(2), (shift - twos) as usize)
+                * num_traits::pow(BigInt::from(5), (shift - fives) as usize)
+                * reduced.signum();
+            return ExactDecimal::from_coefficient_exponent(coefficient, exponent - shift as i64);
+        }
+
+        // Scale the numerator so that the quotient has more digits than are kept.
+        let shift =
+            (PRECISION as i64 + 1 + digit_count(&denominator) - digit_count(&numerator)).max(0);
+        let (quotient, remainder) = (numerator * pow10(shift as u64)).div_rem(&denominator);
+        ExactDecimal::from_truncated(quotient, exponent - shift, !remainder.is_zero(), sign < 0)
+    }
+}
+
+impl Neg for &ExactDecimal {
+    type Output = ExactDecimal;
+
+    fn neg(self) -> ExactDecimal {
+        match &self.0 {
+            Repr::NaN => ExactDecimal::NAN,
+            Repr::Infinity { negative } => ExactDecimal(Repr::Infinity {
+                negative: !negative,
+            }),
+            Repr::Finite { .. } => match self.to_coefficient_exponent() {
+                Some((coefficient, exponent)) => {
+                    ExactDecimal::from_coefficient_exponent(-coefficient, exponent)
+                }
+                None => unreachable!("the number is finite"),
+            },
+        }
+    }
+}
+
+impl Neg for ExactDecimal {
+    type Output = ExactDecimal;
+
+    fn neg(self) -> ExactDecimal {
+        -&self
+    }
+}
+
+impl Sub<&ExactDecimal> for &ExactDecimal {
+    type Output = ExactDecimal;
+
+    fn sub(self, decimal: &ExactDecimal) -> ExactDecimal {
+        self + &-decimal
+    }
+}
+
+/// Implements an operator for every combination of owned and borrowed operands, and its
+/// assigning form, on top of the implementation for two references.
+macro_rules! impl_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl $trait<ExactDecimal> for ExactDecimal {
+            type Output = ExactDecimal;
+
+            fn $method(self, decimal: ExactDecimal) -> ExactDecimal {
+                (&self).$method(&decimal)
+            }
+        }
+
+        impl $trait<&ExactDecimal> for ExactDecimal {
+            type Output = ExactDecimal;
+
+            fn $method(self, decimal: &ExactDecimal) -> ExactDecimal {
+                (&self).$method(decimal)
+            }
+        }
+
+        impl $trait<ExactDecimal> for &ExactDecimal {
+            type Output = ExactDecimal;
+
+            fn $method(self, decimal: ExactDecimal) -> ExactDecimal {
+                self.$method(&decimal)
+            }
+        }
+
+        impl $assign_trait<ExactDecimal> for ExactDecimal {
+            fn $assign_method(&mut self, decimal: ExactDecimal) {
+                *self = (&*self).$method(&decimal);
+            }
+        }
+
+        impl $assign_trait<&ExactDecimal> for ExactDecimal {
+            fn $assign_method(&mut self, decimal: &ExactDecimal) {
+                *self = (&*self).$method(decimal);
+            }
+        }
+    };
+}
+
+impl_op!(Add, add, AddAssign, add_assign);
+impl_op!(Sub, sub, SubAssign, sub_assign);
+impl_op!(Mul, mul, MulAssign, mul_assign);
+impl_op!(Div, div, DivAssign, div_assign);
+
+impl PartialEq for ExactDecimal {
+    fn eq(&self, other: &ExactDecimal) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for ExactDecimal {
+    fn partial_cmp(&self, other: &ExactDecimal) -> Option<Ordering> {
+        match (&self.0, &other.0) {
+            (Repr::NaN, _) | (_, Repr::NaN) => None,
+            (Repr::Infinity { negative: left }, Repr::Infinity { negative: right }) => {
+                Some(right.cmp(left))
+            }
+            (Repr::Infinity { negative }, _) => Some(if *negative {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }),
+            (_, Repr::Infinity { negative }) => Some(if *negative {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            }),
+            _ => {
+                let sign = self.sign().cmp(&other.sign());
+                if sign.is_ne() || self.sign() == 0 {
+                    return Some(sign);
+                }
+
+                match (
+                    self.to_coefficient_exponent(),
+                    other.to_coefficient_exponent(),
+                ) {
+                    (Some(left), Some(right)) => {
+                        let (left, right, _) = align(left, right);
+                        Some(left.cmp(&right))
+                    }
+                    _ => unreachable!("both numbers are finite"),
+                }
+            }
+        }
+    }
+}
+
+impl Sum for ExactDecimal {
+    fn sum<I: Iterator<Item = ExactDecimal>>(iter: I) -> ExactDecimal {
+        iter.fold(ExactDecimal::ZERO, |sum, number| sum + number)
+    }
+}
+
+impl<'a> Sum<&'a ExactDecimal> for ExactDecimal {
+    fn sum<I: Iterator<Item = &'a ExactDecimal>>(iter: I) -> ExactDecimal {
+        iter.fold(ExactDecimal::ZERO, |sum, number| sum + number)
+    }
+}
+
+impl Product for ExactDecimal {
+    fn product<I: Iterator<Item = ExactDecimal>>(iter: I) -> ExactDecimal {
+        iter.fold(ExactDecimal::ONE, |product, number| product * number)
+    }
+}
+
+impl<'a> Product<&'a ExactDecimal> for ExactDecimal {
+    fn product<I: Iterator<Item = &'a ExactDecimal>>(iter: I) -> ExactDecimal {
+        iter.fold(ExactDecimal::ONE, |product, number| product * number)
+    }
+}
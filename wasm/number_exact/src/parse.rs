@@ -0,0 +1,123 @@
+use std::str::FromStr;
+
+use num_bigint::BigInt;
+
+use number_double_float::suffix::short_scale_index;
+
+use crate::{ExactDecimal, ParseDecimalError};
+
+/// Splits a leading sign off the string, returning if it was a minus sign.
+fn strip_sign(string: &str) -> (bool, &str) {
+    match string.as_bytes().first() {
+        Some(b'-') => (true, &string[1..]),
+        Some(b'+') => (false, &string[1..]),
+        _ => (false, string),
+    }
+}
+
+/// Reads the digits of an optionally signed mantissa, which may be grouped in threes with `_`
+/// or `,` and may have a fractional part.
+///
+/// Returns the digits with the decimal point removed, the number of fractional digits, and
+/// the rest of the string, or `None` if there are no digits.
+fn read_mantissa(string: &str) -> Option<(String, i64, &str)> {
+    let (negative, unsigned) = strip_sign(string);
+    let bytes = unsigned.as_bytes();
+    let mut digits = String::from(if negative { "-" } else { "" });
+    let mut count = 0;
+    let mut fraction = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if bytes[index].is_ascii_digit() {
+            digits.push(bytes[index] as char);
+            count += 1;
+        } else if !(matches!(bytes[index], b'_' | b',')
+            && count > 0
+            && bytes
+                .get(index + 1..index + 4)
+                .is_some_and(|group| group.iter().all(u8::is_ascii_digit))
+            && !bytes.get(index + 4).is_some_and(u8::is_ascii_digit))
+        {
+            break;
+        }
+
+        index += 1;
+    }
+
+    if bytes.get(index) == Some(&b'.') {
+        index += 1;
+
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            digits.push(bytes[index] as char);
+            count += 1;
+            fraction += 1;
+            index += 1;
+        }
+    }
+
+    (count > 0).then(|| (digits, fraction, &unsigned[index..]))
+}
+
+impl FromStr for ExactDecimal {
+    type Err = ParseDecimalError;
+
+    /// Parses a number from a string, exactly.
+    ///
+    /// It accepts everything [`number_double_float::Decimal`] does: `"NaN"`, `"Infinity"`,
+    /// an optional sign (`"-123.45"`), digits grouped in threes with `_` or `,`
+    /// (`"1,000,000"`), an exponent in either case (`"1.2345E+2"`) and a short-scale suffix
+    /// (`"1.5K"`).
+    /// Surrounding whitespace is ignored.
+    fn from_str(string: &str) -> Result<ExactDecimal, ParseDecimalError> {
+        let string = string.trim();
+
+        if string.is_empty() {
+            return Err(ParseDecimalError::Empty);
+        } else if string == "NaN" {
+            return Ok(ExactDecimal::NAN);
+        }
+
+        let (negative, unsigned) = strip_sign(string);
+        if unsigned.eq_ignore_ascii_case("Infinity") {
+            return Ok(if negative {
+                ExactDecimal::NEG_INFINITY
+            } else {
+                ExactDecimal::INFINITY
+            });
+        }
+
+        let (digits, fraction, mut rest) =
+            read_mantissa(string).ok_or(ParseDecimalError::InvalidMantissa)?;
+        let coefficient: BigInt = digits
+            .parse()
+            .map_err(|_| ParseDecimalError::InvalidMantissa)?;
+        let mut exponent = -fraction;
+
+        if let Some(after) = rest.strip_prefix(['e', 'E']) {
+            let (negative, unsigned) = strip_sign(after);
+            let length = unsigned
+                .find(|character: char| !character.is_ascii_digit())
+                .unwrap_or(unsigned.len());
+            if length == 0 {
+                return Err(ParseDecimalError::InvalidExponent);
+            }
+
+            // Exponents which do not fit in an i64 are far outside of the range anyway.
+            let value = unsigned[..length].parse::<i64>().unwrap_or(i64::MAX);
+            exponent = exponent.saturating_add(if negative { -value } else { value });
+            rest = &unsigned[length..];
+        }
+
+        let suffix = rest.trim_start();
+        if !suffix.is_empty() {
+            let index = short_scale_index(suffix).ok_or(ParseDecimalError::TrailingCharacters)?;
+            exponent = exponent.saturating_add(3 * index as i64);
+        }
+
+        Ok(ExactDecimal::from_coefficient_exponent(
+            coefficient,
+            exponent,
+        ))
+    }
+}
@@ -0,0 +1,128 @@
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{Signed, Zero};
+
+use number_base::RoundingMode;
+
+use crate::{digit_count, ExactDecimal};
+
+/// How a result is rounded to the digits that are kept.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Rounding {
+    HalfEven,
+    HalfAwayFromZero,
+    TowardZero,
+    Floor,
+    Ceiling,
+}
+
+impl From<RoundingMode> for Rounding {
+    fn from(mode: RoundingMode) -> Rounding {
+        match mode {
+            RoundingMode::HalfEven => Rounding::HalfEven,
+            RoundingMode::HalfUp => Rounding::HalfAwayFromZero,
+            RoundingMode::TowardZero => Rounding::TowardZero,
+            RoundingMode::Floor => Rounding::Floor,
+            RoundingMode::Ceil => Rounding::Ceiling,
+        }
+    }
+}
+
+/// Returns `10^exponent`.
+pub(crate) fn pow10(exponent: u64) -> BigInt {
+    num_traits::pow(BigInt::from(10), exponent as usize)
+}
+
+/// Divides the numerator by the denominator and rounds the quotient to an integer.
+pub(crate) fn div_rounded(numerator: &BigInt, denominator: &BigInt, rounding: Rounding) -> BigInt {
+    let (quotient, remainder) = numerator.div_rem(denominator);
+
+    if remainder.is_zero() {
+        return quotient;
+    }
+
+    let negative = numerator.is_negative() != denominator.is_negative();
+    let away_from_zero = match rounding {
+        Rounding::TowardZero => false,
+        Rounding::Floor => negative,
+        Rounding::Ceiling => !negative,
+        Rounding::HalfEven | Rounding::HalfAwayFromZero => {
+            match (remainder.abs() * 2u32).cmp(&denominator.abs()) {
+                std::cmp::Ordering::Less => false,
+                std::cmp::Ordering::Greater => true,
+                std::cmp::Ordering::Equal => {
+                    rounding == Rounding::HalfAwayFromZero || quotient.is_odd()
+                }
+            }
+        }
+    };
+
+    match (away_from_zero, negative) {
+        (false, _) => quotient,
+        (true, false) => quotient + 1u32,
+        (true, true) => quotient - 1u32,
+    }
+}
+
+impl ExactDecimal {
+    /// Rounds the number to a multiple of `10^exponent`.
+    pub(crate) fn rounded_to_exponent(&self, exponent: i64, rounding: Rounding) -> ExactDecimal {
+        match self.to_coefficient_exponent() {
+            Some((coefficient, current)) if current < exponent => {
+                let shift = exponent.saturating_sub(current);
+                if shift > digit_count(&coefficient) {
+                    // Every digit is dropped and they add up to less than half of the kept
+                    // digit, which spares computing a huge power of ten.
+                    let quotient = match rounding {
+                        Rounding::Floor if coefficient.is_negative() => -1,
+                        Rounding::Ceiling if coefficient.is_positive() => 1,
+                        _ => 0,
+                    };
+                    return ExactDecimal::from_coefficient_exponent(quotient, exponent);
+                }
+
+                ExactDecimal::from_coefficient_exponent(
+                    div_rounded(&coefficient, &pow10(shift as u64), rounding),
+                    exponent,
+                )
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Rounds the number to the given number of significant digits.
+    pub(crate) fn rounded_to_significant(&self, digits: u32, rounding: Rounding) -> ExactDecimal {
+        match self.adjusted_exponent() {
+            Some(adjusted) => self.rounded_to_exponent(adjusted - digits as i64 + 1, rounding),
+            None => self.clone(),
+        }
+    }
+
+    /// Creates the number `coefficient * 10^exponent` from a coefficient that is truncated
+    /// if `inexact` is set, rounding it to [`crate::PRECISION`] significant digits.
+    ///
+    /// The coefficient must have more than `PRECISION` digits, so that a truncated digit is
+    /// left for the rounding to look at.
+    pub(crate) fn from_truncated(
+        coefficient: BigInt,
+        exponent: i64,
+        inexact: bool,
+        negative: bool,
+    ) -> ExactDecimal {
+        if !inexact {
+            return ExactDecimal::from_coefficient_exponent(coefficient, exponent);
+        }
+
+        // A trailing one stands in for the discarded remainder, which is never exactly a tie.
+        let sticky = if negative { -1 } else { 1 };
+        ExactDecimal::from_coefficient_exponent(coefficient * 10u32 + sticky, exponent - 1)
+            .rounded_to_significant(crate::PRECISION, Rounding::HalfEven)
+    }
+
+    /// Rounds the number to the given number of decimal places, half to even.
+    ///
+    /// Negative places round to the left of the decimal point, so `-2` rounds to hundreds.
+    pub fn round_to_places(&self, places: i64) -> ExactDecimal {
+        self.rounded_to_exponent(-places, Rounding::HalfEven)
+    }
+}
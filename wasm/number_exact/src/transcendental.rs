@@ -0,0 +1,392 @@
+//! Roots, logarithms and powers, rounded to [`PRECISION`] significant digits unless the
+//! result is representable exactly.
+//!
+//! Logarithms and the exponential function are evaluated as fixed-point integers scaled by
+//! `10^working`, with enough working digits that the guard digits absorb the error of the
+//! series before the result is rounded.
+
+use std::cell::RefCell;
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Signed, Zero};
+
+use number_base::BaseNumber;
+
+use crate::{
+    digit_count,
+    round::{div_rounded, pow10, Rounding},
+    ExactDecimal, MAX_EXPONENT, PRECISION,
+};
+
+/// Digits carried beyond [`PRECISION`] by fixed-point evaluations.
+const GUARD_DIGITS: i64 = 15;
+
+/// The largest number of digits an exact integer power may have before it is rounded
+/// instead.
+const MAX_EXACT_POWER_DIGITS: i64 = 2 * MAX_EXPONENT;
+
+/// The largest integer whose factorial is computed exactly.
+const MAX_EXACT_FACTORIAL: u32 = 1000;
+
+/// Returns `2 * atanh(z)` for the fixed-point `z = (value - one) / (value + one)`, which is
+/// `ln(value / one)` and converges quickly for values between `one` and `2 * one`.
+fn ln_near_one(value: &BigInt, one: &BigInt) -> BigInt {
+    let z = (value - one) * one / (value + one);
+    let z_squared = &z * &z / one;
+    let mut term = z;
+    let mut sum = BigInt::zero();
+    let mut denominator = 1u32;
+
+    while !term.is_zero() {
+        sum += &term / denominator;
+        term = term * &z_squared / one;
+        denominator += 2;
+    }
+
+    sum * 2u32
+}
+
+thread_local! {
+    /// `ln(2)` and `ln(10)` at the highest working precision used so far, which every
+    /// logarithm and exponential needs. Lower precisions truncate them, so only one pair is
+    /// kept however many precisions are used.
+    static CONSTANTS: RefCell<Option<(i64, BigInt, BigInt)>> = const { RefCell::new(None) };
+}
+
+/// Returns `ln(2)` and `ln(10) = 3 ln(2) + ln(1.25)` as fixed-point numbers.
+fn constants(working: i64) -> (BigInt, BigInt) {
+    CONSTANTS.with(|constants| {
+        let mut constants = constants.borrow_mut();
+        if constants
+            .as_ref()
+            .is_none_or(|&(precision, _, _)| precision < working)
+        {
+            let one = pow10(working as u64);
+            let ln2 = ln_near_one(&(&one * 2u32), &one);
+            let ln10 = &ln2 * 3u32 + ln_near_one(&(&one * 5u32 / 4u32), &one);
+            *constants = Some((working, ln2, ln10));
+        }
+
+        let (precision, ln2, ln10) = constants.as_ref().unwrap();
+        let scale = pow10((precision - working) as u64);
+        (ln2 / &scale, ln10 / &scale)
+    })
+}
+
+/// Returns `ln(coefficient * 10^exponent)` as a fixed-point number with the given number of
+/// working digits. The number must be positive.
+fn ln_fixed(coefficient: &BigInt, exponent: i64, working: i64) -> BigInt {
+    let one = pow10(working as u64);
+    let digits = digit_count(coefficient);
+
+    // Split the number into a mantissa in [1, 10) and a power of ten.
+    let shift = working - (digits - 1);
+    let mut mantissa = if shift >= 0 {
+        coefficient * pow10(shift as u64)
+    } else {
+        coefficient / pow10(-shift as u64)
+    };
+    let power = exponent + digits - 1;
+
+    // Halve the mantissa into [1, 2], where the series converges.
+    let mut halvings = 0u32;
+    let two = &one * 2u32;
+    while mantissa > two {
+        mantissa /= 2u32;
+        halvings += 1;
+    }
+
+    let (ln2, ln10) = constants(working);
+    ln_near_one(&mantissa, &one) + ln2 * halvings + ln10 * power
+}
+
+/// Returns `e^value` for a fixed-point value, as a number rounded to [`PRECISION`]
+/// significant digits.
+fn exp_fixed(value: &BigInt, working: i64) -> ExactDecimal {
+    let one = pow10(working as u64);
+
+    // Reduce the value to |r| <= ln(2) / 2, so that e^value = e^r * 2^k.
+    let (ln2, _) = constants(working);
+    let halvings = div_rounded(value, &ln2, Rounding::HalfEven);
+    let reduced = value - &halvings * &ln2;
+
+    let mut term = one.clone();
+    let mut sum = BigInt::zero();
+    let mut index = 1u32;
+
+    while !term.is_zero() {
+        sum += &term;
+        term = term * &reduced / &one / index;
+        index += 1;
+    }
+
+    let halvings = i64::try_from(&halvings).unwrap_or(i64::MAX);
+    let scaled = if halvings >= 0 {
+        ExactDecimal::from_coefficient_exponent(
+            sum * num_traits::pow(BigInt::from(2), halvings as usize),
+            -working,
+        )
+    } else {
+        // Dividing by 2^k is multiplying by 5^k / 10^k.
+        ExactDecimal::from_coefficient_exponent(
+            sum * num_traits::pow(BigInt::from(5), -halvings as usize),
+            -working + halvings,
+        )
+    };
+
+    scaled.rounded_to_significant(PRECISION, Rounding::HalfEven)
+}
+
+impl ExactDecimal {
+    /// Returns the `degree`th root, which is exact if the root is representable. Roots of
+    /// even degree are NaN for negative numbers.
+    pub(crate) fn nth_root(&self, degree: u32) -> ExactDecimal {
+        let negative = self.sign() < 0;
+
+        if self.is_nan() || (negative && degree.is_multiple_of(2)) {
+            return ExactDecimal::NAN;
+        } else if self.is_infinite() || self.sign() == 0 {
+            return self.clone();
+        }
+
+        let (coefficient, exponent) = match self.to_coefficient_exponent() {
+            Some(parts) => parts,
+            None => unreachable!("the number is finite"),
+        };
+        let coefficient = coefficient.abs();
+        let degree_digits = degree as i64;
+
+        // Give the radicand enough digits for the root to have more than PRECISION, and an
+        // exponent which is a multiple of the degree.
+        let mut shift = (degree_digits * (PRECISION as i64 + 1) - digit_count(&coefficient)).max(0);
+        shift += (exponent - shift).rem_euclid(degree_digits);
+        let radicand = coefficient * pow10(shift as u64);
+        let root = radicand.nth_root(degree);
+        let inexact = num_traits::pow(root.clone(), degree as usize) != radicand;
+
+        let root = if negative { -root } else { root };
+        ExactDecimal::from_truncated(root, (exponent - shift) / degree_digits, inexact, negative)
+    }
+
+    /// Returns the logarithm in base 2 or 10, or the natural logarithm for `None`.
+    ///
+    /// The logarithm of an exact power of the base is exact.
+    pub(crate) fn logarithm(&self, base: Option<u32>) -> ExactDecimal {
+        if self.is_nan() || self.sign() < 0 {
+            return ExactDecimal::NAN;
+        } else if self.sign() == 0 {
+            return ExactDecimal::NEG_INFINITY;
+        } else if self.is_infinite() {
+            return ExactDecimal::INFINITY;
+        } else if *self == ExactDecimal::ONE {
+            return ExactDecimal::ZERO;
+        }
+
+        let (coefficient, exponent) = match self.to_coefficient_exponent() {
+            Some(parts) => parts,
+            None => unreachable!("the number is finite"),
+        };
+
+        match base {
+            Some(10) if coefficient.is_one() => return ExactDecimal::from(exponent),
+            Some(2) => {
+                if let Some(power) = power_of_two(&coefficient, exponent) {
+                    return ExactDecimal::from(power);
+                }
+            }
+            _ => {}
+        }
+
+        // Absolute errors grow with the power of ten, and results close to zero need more
+        // working digits for the same number of significant digits.
+        let mut working = PRECISION as i64 + GUARD_DIGITS + digit_count(&BigInt::from(exponent));
+        loop {
+            let one = pow10(working as u64);
+            let mut result = ln_fixed(&coefficient, exponent, working);
+            match base {
+                Some(10) => result = result * &one / constants(working).1,
+                Some(2) => result = result * &one / constants(working).0,
+                _ => {}
+            }
+
+            let missing = PRECISION as i64 + GUARD_DIGITS - digit_count(&result);
+            if missing <= 0 || working > 2 * MAX_EXPONENT {
+                return ExactDecimal::from_coefficient_exponent(result, -working)
+                    .rounded_to_significant(PRECISION, Rounding::HalfEven);
+            }
+
+            working += missing;
+        }
+    }
+
+    /// Returns e raised to the power of the number.
+    pub(crate) fn exponential(&self) -> ExactDecimal {
+        if self.is_nan() {
+            return ExactDecimal::NAN;
+        } else if self.is_infinite() {
+            return if self.sign() > 0 {
+                ExactDecimal::INFINITY
+            } else {
+                ExactDecimal::ZERO
+            };
+        } else if self.sign() == 0 {
+            return ExactDecimal::ONE;
+        }
+
+        // Estimate the size of the result to catch overflow before doing any work.
+        let result_log10 = self.to_number() * std::f64::consts::LOG10_E;
+        if result_log10 > (MAX_EXPONENT + 1) as f64 {
+            return ExactDecimal::INFINITY;
+        } else if result_log10 < -(MAX_EXPONENT + 1) as f64 {
+            return ExactDecimal::ZERO;
+        }
+
+        // The error of the fixed-point argument is multiplied by the size of the result.
+        let working =
+            PRECISION as i64 + GUARD_DIGITS + (result_log10.abs() + 1.0).log10().ceil() as i64;
+        let (coefficient, exponent) = match self.to_coefficient_exponent() {
+            Some(parts) => parts,
+            None => unreachable!("the number is finite"),
+        };
+        let fixed = if working + exponent >= 0 {
+            coefficient * pow10((working + exponent) as u64)
+        } else {
+            div_rounded(
+                &coefficient,
+                &pow10(-(working + exponent) as u64),
+                Rounding::HalfEven,
+            )
+        };
+
+        exp_fixed(&fixed, working)
+    }
+
+    /// Returns the factorial of a non-negative integer up to [`MAX_EXACT_FACTORIAL`], which
+    /// is exact, or `None` for any other number.
+    pub(crate) fn exact_factorial(&self) -> Option<ExactDecimal> {
+        let (coefficient, exponent) = self.to_coefficient_exponent()?;
+        let integer = coefficient * pow10(u64::try_from(exponent).ok()?);
+        let n = u32::try_from(&integer)
+            .ok()
+            .filter(|n| *n <= MAX_EXACT_FACTORIAL)?;
+
+        let product = (2..=n).fold(BigInt::one(), |product, factor| product * factor);
+        Some(ExactDecimal::from_coefficient_exponent(product, 0))
+    }
+
+    /// Raises the number to the given power. Integer powers are exact unless they are
+    /// negative or have too many digits; other powers are rounded.
+    pub(crate) fn power(&self, exponent: &ExactDecimal) -> ExactDecimal {
+        if exponent.sign() == 0 || *self == ExactDecimal::ONE {
+            return ExactDecimal::ONE;
+        } else if !self.is_finite() || !exponent.is_finite() {
+            return ExactDecimal::from(self.to_number().powf(exponent.to_number()));
+        } else if self.sign() == 0 {
+            return if exponent.sign() > 0 {
+                ExactDecimal::ZERO
+            } else {
+                ExactDecimal::INFINITY
+            };
+        }
+
+        let odd = exponent.is_integer()
+            && exponent
+                .to_coefficient_exponent()
+                .is_some_and(|(coefficient, exponent)| exponent == 0 && coefficient.is_odd());
+        let negative = self.sign() < 0 && odd;
+
+        if self.sign() < 0 && !exponent.is_integer() {
+            return ExactDecimal::NAN;
+        }
+
+        // Estimate the size of the result to catch overflow before doing any work.
+        let log10 = self.log10_estimate();
+        let result_log10 = log10 * exponent.to_number();
+        if result_log10 > (MAX_EXPONENT + 1) as f64 {
+            return if negative {
+                ExactDecimal::NEG_INFINITY
+            } else {
+                ExactDecimal::INFINITY
+            };
+        } else if result_log10 < -(MAX_EXPONENT + 1) as f64 {
+            return ExactDecimal::ZERO;
+        }
+
+        let (coefficient, power) = match self.to_coefficient_exponent() {
+            Some(parts) => parts,
+            None => unreachable!("the number is finite"),
+        };
+
+        if exponent.is_integer() {
+            let times = exponent.to_number().abs();
+            if times * digit_count(&coefficient) as f64 <= MAX_EXACT_POWER_DIGITS as f64 {
+                let result = ExactDecimal::from_coefficient_exponent(
+                    num_traits::pow(coefficient, times as usize),
+                    power * times as i64,
+                );
+                return if exponent.sign() < 0 {
+                    result.recip()
+                } else {
+                    result
+                };
+            }
+        }
+
+        // e^(exponent * ln|self|), with enough working digits for the integer part of the
+        // product and for the error of the logarithm multiplied by the exponent.
+        let working = PRECISION as i64
+            + GUARD_DIGITS
+            + (result_log10.abs() + 1.0).log10().ceil() as i64
+            + (exponent.to_number().abs() + 1.0).log10().ceil() as i64;
+        let logarithm = ln_fixed(&coefficient.abs(), power, working);
+        let product = match exponent.to_coefficient_exponent() {
+            Some((multiplier, scale)) if scale >= 0 => logarithm * multiplier * pow10(scale as u64),
+            Some((multiplier, scale)) => div_rounded(
+                &(logarithm * multiplier),
+                &pow10(-scale as u64),
+                Rounding::HalfEven,
+            ),
+            None => unreachable!("the exponent is finite"),
+        };
+
+        let result = exp_fixed(&product, working);
+        if negative {
+            -result
+        } else {
+            result
+        }
+    }
+
+    /// Returns an estimate of the base 10 logarithm of the absolute value of a finite,
+    /// nonzero number.
+    fn log10_estimate(&self) -> f64 {
+        match (self.to_coefficient_exponent(), self.adjusted_exponent()) {
+            (Some((coefficient, exponent)), Some(adjusted)) => {
+                let leading =
+                    ExactDecimal::from_coefficient_exponent(coefficient.abs(), exponent - adjusted);
+                adjusted as f64 + leading.to_number().log10()
+            }
+            _ => f64::NAN,
+        }
+    }
+}
+
+/// Returns `k` if `coefficient * 10^exponent` is exactly `2^k`.
+fn power_of_two(coefficient: &BigInt, exponent: i64) -> Option<i64> {
+    if !coefficient.is_positive() {
+        return None;
+    } else if exponent > 0 {
+        // A power of ten has a factor of five.
+        return None;
+    }
+
+    // 2^-k = 5^k / 10^k, so negative powers have a coefficient of 5^-exponent * 2^j.
+    let fives = num_traits::pow(BigInt::from(5), -exponent as usize);
+    let (twos, remainder) = coefficient.div_rem(&fives);
+    if !remainder.is_zero() || (&twos & (&twos - 1u32)) != BigInt::zero() {
+        return None;
+    }
+
+    Some(twos.bits() as i64 - 1 + exponent)
+}
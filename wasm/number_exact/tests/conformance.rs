@@ -0,0 +1,3 @@
+use number_exact::ExactDecimal;
+
+number_base::conformance_tests!(ExactDecimal);
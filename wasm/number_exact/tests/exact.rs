@@ -0,0 +1,218 @@
+use number_base::{BaseNumber, RefNumber};
+use number_double_float::Decimal;
+use number_exact::{ExactDecimal, MAX_EXPONENT, PRECISION};
+
+fn x(string: &str) -> ExactDecimal {
+    string.parse().unwrap()
+}
+
+#[test]
+fn arithmetic_is_exact() {
+    assert_eq!(x("0.1") + x("0.2"), x("0.3"));
+    assert_eq!(ExactDecimal::from(0.1) + ExactDecimal::from(0.2), x("0.3"));
+    assert_eq!(x("1e30") + x("1e-30") - x("1e30"), x("1e-30"));
+    assert_eq!(
+        x("123456789.123456789") * x("987654321.987654321"),
+        x("121932631356500531.347203169112635269")
+    );
+    assert_eq!((x("19.99") * ExactDecimal::from(3)).to_string(), "59.97");
+
+    // Adding a cent a million times never drifts.
+    let mut total = ExactDecimal::ZERO;
+    let cent = x("0.01");
+    for _ in 0..100000 {
+        total += &cent;
+    }
+    assert_eq!(total, x("1000"));
+}
+
+#[test]
+fn division_rounds_to_precision() {
+    assert_eq!(x("1") / x("8"), x("0.125"));
+    assert_eq!(x("1") / x("1024"), x("0.0009765625"));
+    assert_eq!(
+        x("123456789012345678901234567890123456789012345678901234567890") / x("5"),
+        x("24691357802469135780246913578024691357802469135780246913578")
+    );
+
+    let third = x("1") / x("3");
+    assert_eq!(
+        third.to_string(),
+        format!("0.{}", "3".repeat(PRECISION as usize))
+    );
+    assert_eq!(
+        (x("2") / x("3")).to_string(),
+        format!("0.{}7", "6".repeat(PRECISION as usize - 1))
+    );
+    assert_eq!(
+        (x("-2") / x("3")).to_string(),
+        format!("-0.{}7", "6".repeat(PRECISION as usize - 1))
+    );
+
+    assert_eq!(x("1") / ExactDecimal::ZERO, ExactDecimal::INFINITY);
+    assert_eq!(x("-1") / ExactDecimal::ZERO, ExactDecimal::NEG_INFINITY);
+    assert!((ExactDecimal::ZERO / ExactDecimal::ZERO).is_nan());
+    assert_eq!(x("1") / ExactDecimal::INFINITY, ExactDecimal::ZERO);
+}
+
+#[test]
+fn rounding() {
+    assert_eq!(x("2.5").round(), x("3"));
+    assert_eq!(x("-2.5").round(), x("-3"));
+    assert_eq!(x("-2.5").trunc(), x("-2"));
+    assert_eq!(x("-2.5").floor(), x("-3"));
+    assert_eq!(x("-2.5").ceil(), x("-2"));
+    assert_eq!(x("2.345").round_to_places(2), x("2.34"));
+    assert_eq!(x("2.355").round_to_places(2), x("2.36"));
+    assert_eq!(x("1250").round_to_places(-2), x("1200"));
+    assert_eq!(x("0.001").round_to_places(-5), ExactDecimal::ZERO);
+    assert_eq!(x("-0.001").floor(), x("-1"));
+}
+
+#[test]
+fn roots_and_logarithms() {
+    // Reference values from Python's decimal module at 70 digits, rounded to 50.
+    assert_eq!(
+        x("2").sqrt(),
+        x("1.4142135623730950488016887242096980785696718753769")
+    );
+    assert_eq!(x("0.25").sqrt(), x("0.5"));
+    assert_eq!(x("1e100").sqrt(), x("1e50"));
+    assert_eq!(x("-27").cbrt(), x("-3"));
+    assert!(x("-1").sqrt().is_nan());
+    assert_eq!(
+        x("10").ln(),
+        x("2.3025850929940456840179914546843642076011014886288")
+    );
+    assert_eq!(
+        x("3").log2(),
+        x("1.5849625007211561814537389439478165087598144076925")
+    );
+    assert_eq!(
+        x("1.000000000000000000000000000000000000000000000000000000001").ln(),
+        x("1e-57")
+    );
+    assert_eq!(x("1000").log10(), x("3"));
+    assert_eq!(x("0.125").log2(), x("-3"));
+    assert_eq!(x("1").ln(), ExactDecimal::ZERO);
+    assert_eq!(ExactDecimal::ZERO.ln(), ExactDecimal::NEG_INFINITY);
+
+    // Constants kept for a higher working precision give the same digits.
+    let ln3 = x("3").ln();
+    assert!(x("1e100000").ln().is_finite());
+    assert_eq!(x("3").ln(), ln3);
+}
+
+#[test]
+fn powers() {
+    assert_eq!(x("1.1").pow(&x("3")), x("1.331"));
+    assert_eq!(x("-2").pow(&x("3")), x("-8"));
+    assert_eq!(x("2").pow(&x("-2")), x("0.25"));
+    assert_eq!(x("2").pow(&x("0.5")), x("2").sqrt());
+    assert_eq!(
+        x("10").pow(&x(&(MAX_EXPONENT + 1).to_string())),
+        ExactDecimal::INFINITY
+    );
+    assert_eq!(x("0.1").pow(&x("1e9")), ExactDecimal::ZERO);
+    assert!(x("-2").pow(&x("0.5")).is_nan());
+}
+
+#[test]
+fn limits() {
+    let largest = ExactDecimal::from_coefficient_exponent(9, MAX_EXPONENT);
+    assert!(largest.is_finite());
+    assert_eq!(largest * x("10"), ExactDecimal::INFINITY);
+    assert_eq!(
+        ExactDecimal::from_coefficient_exponent(5, -MAX_EXPONENT - 1),
+        ExactDecimal::ZERO
+    );
+    assert_eq!(
+        ExactDecimal::from_coefficient_exponent(15, -MAX_EXPONENT - 1),
+        ExactDecimal::from_coefficient_exponent(2, -MAX_EXPONENT)
+    );
+}
+
+#[test]
+fn formats() {
+    let number = x("-1234.5678");
+
+    assert_eq!(number.to_string(), "-1234.5678");
+    assert_eq!(format!("{:.2}", number), "-1234.57");
+    assert_eq!(number.to_fixed(6), "-1234.567800");
+    assert_eq!(number.to_exponential(2), "-1.23e+3");
+    assert_eq!(number.to_precision(6), "-1234.57");
+    assert_eq!(x("-0.001").to_fixed(2), "-0.00");
+    assert_eq!(x("1.5e300").to_string(), "1.5e+300");
+    assert_eq!(x("1e-7").to_string(), "1e-7");
+    assert_eq!(x("0.000001").to_string(), "0.000001");
+    assert_eq!(x("2.5").to_fixed(0), "3");
+    assert_eq!(ExactDecimal::ZERO.to_exponential(2), "0.00e+0");
+
+    for string in ["0", "12345e-2", "-7e400", "1e-99999", "NaN", "Infinity"] {
+        let number = x(string);
+        assert_eq!(number.to_canonical_string(), string);
+        assert_eq!(
+            ExactDecimal::try_from_str(&number.to_canonical_string())
+                .unwrap()
+                .to_canonical_string(),
+            string
+        );
+    }
+}
+
+#[test]
+fn parses() {
+    assert_eq!(x("1,234.5"), x("1234.5"));
+    assert_eq!(x("1_000_000"), x("1e6"));
+    assert_eq!(x("1.5K"), x("1500"));
+    assert_eq!(x(" +2E-3 "), x("0.002"));
+    assert_eq!(x("-infinity"), ExactDecimal::NEG_INFINITY);
+    assert!(ExactDecimal::try_from_str("").is_err());
+    assert!(ExactDecimal::try_from_str("1e").is_err());
+    assert!(ExactDecimal::try_from_str("1.2.3").is_err());
+    assert!(ExactDecimal::try_from_str("--1").is_err());
+    assert!(ExactDecimal::try_from_str("1,5").is_err());
+    assert!(ExactDecimal::try_from_str("1_5000").is_err());
+}
+
+#[test]
+fn converts_with_decimal() {
+    for value in [0.1, -123.456, 1.5e300, 2.5e-300, 0.0] {
+        let decimal = Decimal::new(value);
+        let exact = ExactDecimal::from(decimal);
+
+        assert_eq!(exact, ExactDecimal::from(value));
+        assert_eq!(Decimal::from(&exact), decimal);
+        assert_eq!(exact.to_number(), value);
+    }
+
+    let decimal: Decimal = "1.5e1000".parse().unwrap();
+    assert_eq!(ExactDecimal::from(decimal), x("1.5e1000"));
+    assert_eq!(Decimal::from(x("1.5e1000")), decimal);
+    let largest = Decimal::from(ExactDecimal::from(u128::MAX)).to_number();
+    assert!((largest / u128::MAX as f64 - 1.0).abs() < 1e-15);
+    assert_eq!(ExactDecimal::from(Decimal::NAN).to_string(), "NaN");
+    assert_eq!(
+        ExactDecimal::from(Decimal::INFINITY),
+        ExactDecimal::INFINITY
+    );
+}
+
+fn apply_ref_operators<N: BaseNumber>(a: &N, b: &N) -> N
+where
+    for<'a> &'a N: RefNumber<N>,
+{
+    -(a + b) * (a - b) / b
+}
+
+#[test]
+fn special_values_and_operators() {
+    assert_eq!(apply_ref_operators(&x("3"), &x("2")), x("-2.5"));
+    assert!((ExactDecimal::INFINITY - ExactDecimal::INFINITY).is_nan());
+    assert!((ExactDecimal::INFINITY * ExactDecimal::ZERO).is_nan());
+    assert!(ExactDecimal::NAN != ExactDecimal::NAN);
+    assert!(x("-1e100") < x("1e-100"));
+    assert!(ExactDecimal::NEG_INFINITY < x("-1e100000"));
+    assert_eq!((-ExactDecimal::ZERO).sign(), 0);
+    assert_eq!(x("-5").abs(), x("5"));
+}
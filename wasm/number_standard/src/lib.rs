@@ -0,0 +1,319 @@
+mod math;
+
+use std::{
+    fmt::{self, Display, Formatter, LowerExp, UpperExp},
+    str::FromStr,
+};
+
+pub use number_base::{ArithmeticPolicy, DecimalError, ParseDecimalError};
+
+use number_base::BaseNumber;
+use number_double_float::{locale, Decimal};
+
+/// A plain f64 implementing [`BaseNumber`], the counterpart of `StandardNumber` on the
+/// TypeScript side.
+///
+/// It is cheap enough for early-game numbers and for testing engine code. It parses exactly
+/// like [`number_double_float::Decimal`] and formats in the same shapes, so that the two can be
+/// swapped and compared, but with the digits of the f64 itself. Like Decimal it has a single,
+/// unsigned zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct StandardNumber(f64);
+
+impl StandardNumber {
+    /// Creates a number from an f64. A negative zero becomes zero.
+    pub fn new(value: f64) -> StandardNumber {
+        // Adding zero turns -0 into 0.
+        StandardNumber(value + 0.0)
+    }
+
+    /// Returns the number as an f64.
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+macro_rules! impl_from {
+    ($t:ty) => {
+        impl From<$t> for StandardNumber {
+            fn from(value: $t) -> Self {
+                StandardNumber::from(value as f64)
+            }
+        }
+    };
+}
+
+impl_from!(i8);
+impl_from!(i16);
+impl_from!(i32);
+impl_from!(i64);
+impl_from!(i128);
+impl_from!(isize);
+impl_from!(u8);
+impl_from!(u16);
+impl_from!(u32);
+impl_from!(u64);
+impl_from!(u128);
+impl_from!(usize);
+impl_from!(f32);
+
+impl From<f64> for StandardNumber {
+    fn from(value: f64) -> Self {
+        StandardNumber::new(value)
+    }
+}
+
+impl From<String> for StandardNumber {
+    fn from(string: String) -> Self {
+        string.parse().unwrap_or(StandardNumber::NAN)
+    }
+}
+
+impl From<StandardNumber> for String {
+    fn from(number: StandardNumber) -> String {
+        number.to_string()
+    }
+}
+
+impl From<StandardNumber> for f64 {
+    fn from(number: StandardNumber) -> f64 {
+        number.0
+    }
+}
+
+impl From<Decimal> for StandardNumber {
+    fn from(decimal: Decimal) -> StandardNumber {
+        StandardNumber::from(decimal.to_number())
+    }
+}
+
+impl From<StandardNumber> for Decimal {
+    fn from(number: StandardNumber) -> Decimal {
+        Decimal::new(number.0)
+    }
+}
+
+impl FromStr for StandardNumber {
+    type Err = ParseDecimalError;
+
+    /// Parses a number from a string, accepting everything [`number_double_float::Decimal`]
+    /// accepts. Numbers outside of the range of an f64 become ±Infinity or zero.
+    fn from_str(string: &str) -> Result<StandardNumber, ParseDecimalError> {
+        let trimmed = string.trim();
+
+        // Plain numbers parse straight into an f64, which keeps canonical strings exact.
+        if trimmed
+            .bytes()
+            .all(|byte| byte.is_ascii_digit() || matches!(byte, b'+' | b'-' | b'.' | b'e' | b'E'))
+        {
+            if let Ok(value) = trimmed.parse::<f64>() {
+                return Ok(StandardNumber::from(value));
+            }
+        }
+
+        Ok(StandardNumber::from(string.parse::<Decimal>()?))
+    }
+}
+
+/// Writes the number as an f64 does, with the shortest digits that read back the same or as
+/// many places as the precision. As for Decimal, numbers from 1e21 on and below 1e-6 are
+/// written in exponential notation, and the alternate form `{:#}` groups the digits.
+impl Display for StandardNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if !self.0.is_finite() {
+            return Display::fmt(&Decimal::from(*self), f);
+        }
+
+        let magnitude = self.0.abs();
+        let body = if magnitude == 0.0 || (1e-6..1e21).contains(&magnitude) {
+            match f.precision() {
+                Some(places) => format!("{:.*}", places, magnitude),
+                None => magnitude.to_string(),
+            }
+        } else {
+            sign_exponent(match f.precision() {
+                Some(places) => format!("{:.*e}", places, magnitude),
+                None => format!("{:e}", magnitude),
+            })
+        };
+
+        if f.alternate() {
+            f.pad_integral(self.0 >= 0.0, "", &locale::EN_US.localize(&body))
+        } else {
+            f.pad_integral(self.0 >= 0.0, "", &body)
+        }
+    }
+}
+
+/// Writes the number in exponential notation as an f64 does. The alternate form `{:#e}`
+/// always signs the exponent, as for Decimal.
+impl LowerExp for StandardNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_exponential(f, 'e')
+    }
+}
+
+/// Writes the number as [`LowerExp`] does, with an `E` in place of the `e`.
+impl UpperExp for StandardNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_exponential(f, 'E')
+    }
+}
+
+impl StandardNumber {
+    fn fmt_exponential(&self, f: &mut Formatter<'_>, marker: char) -> fmt::Result {
+        if !self.0.is_finite() {
+            return LowerExp::fmt(&Decimal::from(*self), f);
+        }
+
+        let magnitude = self.0.abs();
+        let mut body = match f.precision() {
+            Some(places) => format!("{:.*e}", places, magnitude),
+            None => format!("{:e}", magnitude),
+        };
+        if f.alternate() {
+            body = sign_exponent(body);
+        }
+
+        f.pad_integral(self.0 >= 0.0, "", &body.replace('e', &marker.to_string()))
+    }
+}
+
+/// Signs the exponent of a number which Rust wrote in exponential notation, as JavaScript
+/// does, so that `1.5e3` becomes `1.5e+3`.
+fn sign_exponent(formatted: String) -> String {
+    match formatted.split_once('e') {
+        Some((mantissa, exponent)) if !exponent.starts_with('-') => {
+            format!("{}e+{}", mantissa, exponent)
+        }
+        _ => formatted,
+    }
+}
+
+impl BaseNumber for StandardNumber {
+    const ZERO: StandardNumber = StandardNumber(0.0);
+    const ONE: StandardNumber = StandardNumber(1.0);
+    const NAN: StandardNumber = StandardNumber(f64::NAN);
+    const INFINITY: StandardNumber = StandardNumber(f64::INFINITY);
+    const NEG_INFINITY: StandardNumber = StandardNumber(f64::NEG_INFINITY);
+
+    fn to_number(&self) -> f64 {
+        self.0
+    }
+
+    /// Writes the number in scientific notation with the shortest digits that round-trip to
+    /// the same f64, e.g. `1.5e300`. Zero is `0`, and the special values are `NaN`,
+    /// `Infinity` and `-Infinity`.
+    fn to_canonical_string(&self) -> String {
+        if self.0.is_finite() && self.0 != 0.0 {
+            format!("{:e}", self.0)
+        } else {
+            Decimal::from(*self).to_canonical_string()
+        }
+    }
+
+    fn to_exponential(&self, places: u32) -> String {
+        if !self.0.is_finite() {
+            return Decimal::from(*self).to_exponential(places);
+        }
+
+        sign_exponent(format!("{:.*e}", places as usize, self.0))
+    }
+
+    fn to_fixed(&self, places: u32) -> String {
+        if !self.0.is_finite() {
+            return Decimal::from(*self).to_fixed(places);
+        }
+
+        format!("{:.*}", places as usize, self.0)
+    }
+
+    fn to_precision(&self, places: u32) -> String {
+        let places = places.max(1);
+        if !self.0.is_finite() {
+            return Decimal::from(*self).to_precision(places);
+        }
+
+        // As in JavaScript, the exponent after rounding picks the notation.
+        let exponential = format!("{:.*e}", places as usize - 1, self.0);
+        let exponent: i32 = exponential
+            .split_once('e')
+            .and_then(|(_, exponent)| exponent.parse().ok())
+            .unwrap_or(0);
+        if exponent < -6 || exponent >= places as i32 {
+            sign_exponent(exponential)
+        } else {
+            format!("{:.*}", (places as i32 - 1 - exponent) as usize, self.0)
+        }
+    }
+
+    fn abs(&self) -> StandardNumber {
+        StandardNumber::from(self.0.abs())
+    }
+
+    fn round(&self) -> StandardNumber {
+        StandardNumber::from(self.0.round())
+    }
+
+    fn trunc(&self) -> StandardNumber {
+        StandardNumber::from(self.0.trunc())
+    }
+
+    fn floor(&self) -> StandardNumber {
+        StandardNumber::from(self.0.floor())
+    }
+
+    fn ceil(&self) -> StandardNumber {
+        StandardNumber::from(self.0.ceil())
+    }
+
+    fn sqrt(&self) -> StandardNumber {
+        StandardNumber::from(self.0.sqrt())
+    }
+
+    fn recip(&self) -> StandardNumber {
+        StandardNumber::from(self.0.recip())
+    }
+
+    fn cbrt(&self) -> StandardNumber {
+        StandardNumber::from(self.0.cbrt())
+    }
+
+    fn ln(&self) -> StandardNumber {
+        StandardNumber::from(self.0.ln())
+    }
+
+    fn log10(&self) -> StandardNumber {
+        StandardNumber::from(self.0.log10())
+    }
+
+    fn log2(&self) -> StandardNumber {
+        StandardNumber::from(self.0.log2())
+    }
+
+    fn pow(&self, exponent: &StandardNumber) -> StandardNumber {
+        StandardNumber::from(self.0.powf(exponent.0))
+    }
+
+    fn exp(&self) -> StandardNumber {
+        StandardNumber::from(self.0.exp())
+    }
+
+    fn log(&self, base: &StandardNumber) -> StandardNumber {
+        StandardNumber::from(self.0.log(base.0))
+    }
+
+    fn pow10(&self) -> StandardNumber {
+        StandardNumber::from(10.0_f64.powf(self.0))
+    }
+
+    fn sign(&self) -> i32 {
+        if self.0 > 0.0 {
+            1
+        } else if self.0 < 0.0 {
+            -1
+        } else {
+            0
+        }
+    }
+}
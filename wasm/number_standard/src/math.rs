@@ -0,0 +1,101 @@
+use std::{
+    iter::{Product, Sum},
+    ops::*,
+};
+
+use crate::StandardNumber;
+
+/// Implements an arithmetic operator and its assigning form for every combination of owned
+/// and borrowed operands.
+macro_rules! impl_op {
+    ($op:ident, $method:ident, $assign_op:ident, $assign_method:ident) => {
+        impl $op<StandardNumber> for StandardNumber {
+            type Output = StandardNumber;
+
+            fn $method(self, number: StandardNumber) -> StandardNumber {
+                StandardNumber::from(self.0.$method(number.0))
+            }
+        }
+
+        impl $op<&StandardNumber> for StandardNumber {
+            type Output = StandardNumber;
+
+            fn $method(self, number: &StandardNumber) -> StandardNumber {
+                self.$method(*number)
+            }
+        }
+
+        impl $op<StandardNumber> for &StandardNumber {
+            type Output = StandardNumber;
+
+            fn $method(self, number: StandardNumber) -> StandardNumber {
+                (*self).$method(number)
+            }
+        }
+
+        impl $op<&StandardNumber> for &StandardNumber {
+            type Output = StandardNumber;
+
+            fn $method(self, number: &StandardNumber) -> StandardNumber {
+                (*self).$method(*number)
+            }
+        }
+
+        impl $assign_op<StandardNumber> for StandardNumber {
+            fn $assign_method(&mut self, rhs: StandardNumber) {
+                *self = (*self).$method(rhs);
+            }
+        }
+
+        impl $assign_op<&StandardNumber> for StandardNumber {
+            fn $assign_method(&mut self, rhs: &StandardNumber) {
+                *self = (*self).$method(*rhs);
+            }
+        }
+    };
+}
+
+impl_op!(Add, add, AddAssign, add_assign);
+impl_op!(Sub, sub, SubAssign, sub_assign);
+impl_op!(Mul, mul, MulAssign, mul_assign);
+impl_op!(Div, div, DivAssign, div_assign);
+
+impl Neg for StandardNumber {
+    type Output = StandardNumber;
+
+    fn neg(self) -> StandardNumber {
+        StandardNumber::from(-self.0)
+    }
+}
+
+impl Neg for &StandardNumber {
+    type Output = StandardNumber;
+
+    fn neg(self) -> StandardNumber {
+        (*self).neg()
+    }
+}
+
+impl Sum for StandardNumber {
+    fn sum<I: Iterator<Item = StandardNumber>>(iter: I) -> StandardNumber {
+        iter.fold(StandardNumber(0.0), |sum, number| sum + number)
+    }
+}
+
+impl<'a> Sum<&'a StandardNumber> for StandardNumber {
+    fn sum<I: Iterator<Item = &'a StandardNumber>>(iter: I) -> StandardNumber {
+        iter.fold(StandardNumber(0.0), |sum, number| sum + number)
+    }
+}
+
+impl Product for StandardNumber {
+    fn product<I: Iterator<Item = StandardNumber>>(iter: I) -> StandardNumber {
+        iter.fold(StandardNumber(1.0), |product, number| product * number)
+    }
+}
+
+impl<'a> Product<&'a StandardNumber> for StandardNumber {
+    fn product<I: Iterator<Item = &'a StandardNumber>>(iter: I) -> StandardNumber {
+        iter.fold(StandardNumber(1.0), |product, number| product * number)
+    }
+}
@@ -0,0 +1,3 @@
+use number_standard::StandardNumber;
+
+number_base::conformance_tests!(StandardNumber);
@@ -0,0 +1,171 @@
+use number_base::{BaseNumber, RefNumber, RoundingMode};
+use number_double_float::Decimal;
+use number_standard::StandardNumber;
+
+/// A xorshift generator, so the corpus is the same on every run.
+struct Xorshift(u64);
+
+impl Xorshift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a number with a random mantissa and a decimal exponent between -30 and 30.
+    fn next_number(&mut self) -> f64 {
+        let mantissa = (self.next() >> 11) as f64 / (1u64 << 53) as f64 * 20.0 - 10.0;
+        let exponent = (self.next() % 61) as i32 - 30;
+        mantissa * 10.0_f64.powi(exponent)
+    }
+}
+
+fn s(value: f64) -> StandardNumber {
+    StandardNumber::new(value)
+}
+
+#[test]
+fn formats_with_the_digits_of_the_f64() {
+    let mut rng = Xorshift(0x2545_F491_4F6C_DD1D);
+    let specials = [0.0, -0.0, 1.0, 0.5, 1e21, 1e-7, 123.456, f64::MAX];
+
+    for value in specials
+        .into_iter()
+        .chain((0..10000).map(|_| rng.next_number()))
+    {
+        let number = s(value);
+        let value = value + 0.0;
+
+        assert_eq!(number.to_string().parse::<f64>(), Ok(value));
+        assert_eq!(format!("{:e}", number), format!("{:e}", value));
+        for places in [0, 2, 5] {
+            assert_eq!(
+                number.to_fixed(places),
+                format!("{:.*}", places as usize, value)
+            );
+            assert_eq!(
+                number.to_exponential(places).replace("e+", "e"),
+                format!("{:.*e}", places as usize, value)
+            );
+        }
+    }
+
+    assert_eq!(s(123.456).to_string(), "123.456");
+    assert_eq!(format!("{:.2}", s(-123.456)), "-123.46");
+    assert_eq!(format!("{:#}", s(1234567.5)), "1,234,567.5");
+    assert_eq!(s(1e21).to_string(), "1e+21");
+    assert_eq!(s(-1.5e-7).to_string(), "-1.5e-7");
+    assert_eq!(format!("{:e}", s(123.456)), "1.23456e2");
+    assert_eq!(format!("{:#e}", s(123.456)), "1.23456e+2");
+    assert_eq!(format!("{:>12.3E}", s(-1500.0)), "    -1.500E3");
+
+    assert_eq!(s(123.456).to_fixed(4), "123.4560");
+    assert_eq!(s(123.456).to_exponential(2), "1.23e+2");
+    assert_eq!(s(0.000123).to_exponential(1), "1.2e-4");
+    assert_eq!(s(123.456).to_precision(2), "1.2e+2");
+    assert_eq!(s(123.456).to_precision(8), "123.45600");
+    assert_eq!(s(999.96).to_precision(4), "1000");
+    assert_eq!(s(0.000123).to_precision(2), "0.00012");
+    assert_eq!(s(1.5e-7).to_precision(2), "1.5e-7");
+    assert_eq!(s(0.0).to_precision(3), "0.00");
+
+    for (number, decimal) in [
+        (StandardNumber::NAN, Decimal::NAN),
+        (StandardNumber::INFINITY, Decimal::INFINITY),
+        (StandardNumber::NEG_INFINITY, Decimal::NEG_INFINITY),
+    ] {
+        assert_eq!(number.to_string(), decimal.to_string());
+        assert_eq!(format!("{:e}", number), format!("{:e}", decimal));
+        assert_eq!(number.to_fixed(2), decimal.to_fixed(2));
+        assert_eq!(number.to_precision(2), decimal.to_precision(2));
+    }
+}
+
+#[test]
+fn parses_like_decimal() {
+    for (string, expected) in [
+        ("123.45", "123.45"),
+        ("-1.5e3", "-1500"),
+        ("1,000,000", "1000000"),
+        ("2.5K", "2500"),
+        (" 3E-2 ", "0.03"),
+        ("Infinity", "Infinity"),
+        ("NaN", "NaN"),
+    ] {
+        let number = string.parse::<StandardNumber>().unwrap();
+        assert_eq!(number.to_string(), expected, "{}", string);
+        assert!(string.parse::<Decimal>().is_ok(), "{}", string);
+    }
+
+    assert_eq!("1e400".parse(), Ok(StandardNumber::INFINITY));
+    assert_eq!("1e-400".parse(), Ok(StandardNumber::ZERO));
+    assert_eq!(
+        "1e".parse::<StandardNumber>(),
+        "1e".parse::<Decimal>().map(StandardNumber::from)
+    );
+}
+
+#[test]
+fn canonical_strings_round_trip() {
+    let mut rng = Xorshift(0x9E37_79B9_7F4A_7C15);
+
+    for _ in 0..10000 {
+        let number = StandardNumber::new(f64::from_bits(rng.next()));
+        if number.value().is_nan() {
+            continue;
+        }
+
+        let string = number.to_canonical_string();
+        assert_eq!(string.parse(), Ok(number), "{}", string);
+    }
+}
+
+#[test]
+fn has_a_single_zero() {
+    assert_eq!((-StandardNumber::ZERO).value().to_bits(), 0);
+    assert_eq!(StandardNumber::new(-0.0).value().to_bits(), 0);
+    assert_eq!((s(0.0) * s(-1.0)).recip(), StandardNumber::INFINITY);
+    assert_eq!(s(-0.0).sign(), 0);
+}
+
+#[test]
+fn rounds_past_the_range_of_an_f64() {
+    use RoundingMode::*;
+
+    for mode in [HalfEven, HalfUp, TowardZero, Floor, Ceil] {
+        assert_eq!(s(1.5).round_to(400, mode), s(1.5));
+        assert_eq!(s(1e300).round_to(10, mode), s(1e300));
+        assert_eq!(s(1.5e-300).round_to(310, mode), s(1.5e-300));
+        assert_eq!(s(1.5e-300).round_to_significant(20, mode), s(1.5e-300));
+    }
+}
+
+/// Applies every operator to references, as generic engine code does.
+fn apply_ref_operators<N: BaseNumber>(a: &N, b: &N) -> [N; 5]
+where
+    for<'a> &'a N: RefNumber<N>,
+{
+    [a + b, a - b, a * b, a / b, -a]
+}
+
+#[test]
+fn operators_match_f64() {
+    let (a, b) = (s(7.5), s(-2.0));
+
+    assert_eq!(a + b, s(5.5));
+    assert_eq!(a - b, s(9.5));
+    assert_eq!(a * b, s(-15.0));
+    assert_eq!(a / b, s(-3.75));
+    assert_eq!(-a, s(-7.5));
+    assert_eq!(b.pow(&s(3.0)), s(-8.0));
+    assert_eq!(
+        apply_ref_operators(&a, &b),
+        [s(5.5), s(9.5), s(-15.0), s(-3.75), s(-7.5)]
+    );
+
+    let mut c = a;
+    c += b;
+    c *= &b;
+    assert_eq!(c, s(-11.0));
+}